@@ -2,33 +2,39 @@
 //!
 //! An `Alias` instance is created via the main `client.alias("alias_name")` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
+use ::std::borrow::Cow;
 use typesense_codegen::{apis::collections_api, models};
 
 /// Provides methods for interacting with a specific Typesense collection alias.
 ///
 /// This struct is created by calling `client.alias("alias_name")`.
 pub struct Alias<'a> {
-    pub(super) client: &'a Client,
-    pub(super) alias_name: &'a str,
+    pub(super) client: ClientRef<'a>,
+    pub(super) alias_name: Cow<'a, str>,
 }
 
 impl<'a> Alias<'a> {
     /// Creates a new `Alias` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client, alias_name: &'a str) -> Self {
-        Self { client, alias_name }
+    pub(super) fn new(client: ClientRef<'a>, alias_name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            client,
+            alias_name: alias_name.into(),
+        }
     }
 
+    with_options!();
+
     /// Retrieves the details of a collection alias, including the collection it points to.
     pub async fn retrieve(
         &self,
     ) -> Result<models::CollectionAlias, Error<collections_api::GetAliasError>> {
         let params = collections_api::GetAliasParams {
-            alias_name: self.alias_name.into(),
+            alias_name: self.alias_name.as_ref().into(),
         };
 
-        execute_wrapper!(self, collections_api::get_alias, params)
+        execute_wrapper!(self, read collections_api::get_alias, params)
     }
 
     /// Deletes a collection alias.
@@ -36,8 +42,8 @@ impl<'a> Alias<'a> {
         &self,
     ) -> Result<models::CollectionAlias, Error<collections_api::DeleteAliasError>> {
         let params = collections_api::DeleteAliasParams {
-            alias_name: self.alias_name.into(),
+            alias_name: self.alias_name.as_ref().into(),
         };
-        execute_wrapper!(self, collections_api::delete_alias, params)
+        execute_wrapper!(self, write collections_api::delete_alias, params)
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! An `Aliases` instance is created via the main `client.aliases()` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use ::std::borrow::Cow;
 use typesense_codegen::{apis::collections_api, models};
 
@@ -10,16 +10,18 @@ use typesense_codegen::{apis::collections_api, models};
 ///
 /// This struct is created by calling `client.aliases()`.
 pub struct Aliases<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Aliases<'a> {
     /// Creates a new `Aliases` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Creates or updates a collection alias.
     ///
     /// An alias is a virtual collection name that points to a real collection.
@@ -38,13 +40,13 @@ impl<'a> Aliases<'a> {
             alias_name: alias_name.into(),
             collection_alias_schema: Some(schema),
         };
-        execute_wrapper!(self, collections_api::upsert_alias, params)
+        execute_wrapper!(self, write collections_api::upsert_alias, params)
     }
 
     /// Lists all aliases and the corresponding collections that they map to.
     pub async fn retrieve(
         &self,
     ) -> Result<models::CollectionAliasesResponse, Error<collections_api::GetAliasesError>> {
-        execute_wrapper!(self, collections_api::get_aliases)
+        execute_wrapper!(self, read collections_api::get_aliases)
     }
 }
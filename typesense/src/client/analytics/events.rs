@@ -2,23 +2,25 @@
 //!
 //! An `Events` instance is created via the `client.analytics().events()` method.
 
-use crate::{Client, Error, execute_wrapper, models};
+use crate::{Error, client::ClientRef, execute_wrapper, models};
 use typesense_codegen::apis::analytics_api;
 
 /// Provides methods for interacting with analytics events.
 ///
 /// This struct is created by calling `client.analytics().events()`.
 pub struct Events<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Events<'a> {
     /// Creates a new `Events` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Posts an analytics event for tracking user behavior.
     ///
     /// # Arguments
@@ -31,7 +33,7 @@ impl<'a> Events<'a> {
         let params = analytics_api::CreateAnalyticsEventParams {
             analytics_event: schema,
         };
-        execute_wrapper!(self, analytics_api::create_analytics_event, params)
+        execute_wrapper!(self, write analytics_api::create_analytics_event, params)
     }
 
     /// Retrieve the most recent analytics events for a specific user and analytics rule name.
@@ -43,6 +45,6 @@ impl<'a> Events<'a> {
         params: models::GetAnalyticsEventsParams<'_>,
     ) -> Result<models::AnalyticsEventsResponse, Error<analytics_api::GetAnalyticsEventsError>>
     {
-        execute_wrapper!(self, analytics_api::get_analytics_events, params)
+        execute_wrapper!(self, read analytics_api::get_analytics_events, params)
     }
 }
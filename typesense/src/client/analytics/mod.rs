@@ -1,32 +1,37 @@
-//! Provides access to the analytics API endpoints for managing rules and posting events.
+//! Provides access to the analytics API endpoints for managing rules, posting events and
+//! inspecting the analytics queue.
 //!
 //! An `Analytics` instance is created via the main `client.analytics()` method.
 mod events;
 mod rule;
 mod rules;
-use crate::Client;
+use crate::{Error, client::ClientRef, execute_wrapper, models};
+use ::std::borrow::Cow;
 use events::Events;
 use rule::Rule;
 use rules::Rules;
+use typesense_codegen::apis::analytics_api;
 
 /// Provides methods for interacting with Typesense analytics rules and events.
 ///
 /// This struct is created by calling `client.analytics()`.
 pub struct Analytics<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Analytics<'a> {
     /// Creates a new `Analytics` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Provides access to endpoints for managing a collection of analytics rules.
     #[inline]
     pub fn rules(&self) -> Rules<'a> {
-        Rules::new(self.client)
+        Rules::new(self.client.clone())
     }
 
     /// Provides access to endpoints for managing a single analytics rule.
@@ -34,13 +39,31 @@ impl<'a> Analytics<'a> {
     /// # Arguments
     /// * `rule_name` - The name of the analytics rule to manage.
     #[inline]
-    pub fn rule(&self, rule_name: &'a str) -> Rule<'a> {
-        Rule::new(self.client, rule_name)
+    pub fn rule(&self, rule_name: impl Into<Cow<'a, str>>) -> Rule<'a> {
+        Rule::new(self.client.clone(), rule_name)
     }
 
     /// Provides access to the endpoint for creating analytics events.
     #[inline]
     pub fn events(&self) -> Events<'a> {
-        Events::new(self.client)
+        Events::new(self.client.clone())
+    }
+
+    /// Flushes the in-memory analytics events and aggregations to disk.
+    ///
+    /// Typesense persists analytics data periodically; call this to persist it immediately,
+    /// for example before taking a snapshot or shutting a node down.
+    pub async fn flush(
+        &self,
+    ) -> Result<models::AnalyticsEventCreateResponse, Error<analytics_api::FlushAnalyticsError>>
+    {
+        execute_wrapper!(self, write analytics_api::flush_analytics)
+    }
+
+    /// Retrieves the number of analytics events and aggregations still waiting to be persisted.
+    pub async fn status(
+        &self,
+    ) -> Result<models::AnalyticsStatus, Error<analytics_api::GetAnalyticsStatusError>> {
+        execute_wrapper!(self, read analytics_api::get_analytics_status)
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! An `Rules` instance is created via the `Client::analytics().rules()` method.
 
-use crate::{Client, Error, execute_wrapper, models};
+use crate::{Error, client::ClientRef, execute_wrapper, models};
 use ::std::borrow::Cow;
 use reqwest::StatusCode;
 use serde_json::json;
@@ -12,16 +12,18 @@ use typesense_codegen::apis::{ResponseContent, analytics_api};
 ///
 /// This struct is created by calling `client.analytics().rules()`.
 pub struct Rules<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Rules<'a> {
     /// Creates a new `Rules` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Creates a new analytics rule.
     ///
     /// # Arguments
@@ -35,9 +37,9 @@ impl<'a> Rules<'a> {
                 Box::new(schema),
             ),
         };
-        match execute_wrapper!(self, analytics_api::create_analytics_rule, params)? {
+        match execute_wrapper!(self, write analytics_api::create_analytics_rule, params)? {
             models::CreateAnalyticsRule200Response::AnalyticsRule(rule) => Ok(*rule),
-            _ => Err(Error::Api(typesense_codegen::apis::Error::ResponseError(
+            _ => Err(Error::from(typesense_codegen::apis::Error::ResponseError(
                 ResponseContent {
                     status: StatusCode::OK,
                     content: "Unexpected response type".to_owned(),
@@ -63,9 +65,9 @@ impl<'a> Rules<'a> {
         let params = analytics_api::CreateAnalyticsRuleParams {
             create_analytics_rule_request: models::CreateAnalyticsRuleRequest::Array(schema),
         };
-        match execute_wrapper!(self, analytics_api::create_analytics_rule, params)? {
+        match execute_wrapper!(self, write analytics_api::create_analytics_rule, params)? {
             models::CreateAnalyticsRule200Response::Array(rules) => Ok(rules),
-            _ => Err(Error::Api(typesense_codegen::apis::Error::ResponseError(
+            _ => Err(Error::from(typesense_codegen::apis::Error::ResponseError(
                 ResponseContent {
                     status: StatusCode::OK,
                     content: "Unexpected response type".to_owned(),
@@ -91,7 +93,7 @@ impl<'a> Rules<'a> {
             rule_name: rule_name.into(),
             analytics_rule_update: schema,
         };
-        execute_wrapper!(self, analytics_api::upsert_analytics_rule, params)
+        execute_wrapper!(self, write analytics_api::upsert_analytics_rule, params)
     }
 
     /// Retrieves the details of all analytics rules.
@@ -101,6 +103,6 @@ impl<'a> Rules<'a> {
     ) -> Result<Vec<models::AnalyticsRule>, Error<analytics_api::RetrieveAnalyticsRulesError>> {
         let params =
             params.unwrap_or(analytics_api::RetrieveAnalyticsRulesParams { rule_tag: None });
-        execute_wrapper!(self, analytics_api::retrieve_analytics_rules, params)
+        execute_wrapper!(self, read analytics_api::retrieve_analytics_rules, params)
     }
 }
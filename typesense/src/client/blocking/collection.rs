@@ -0,0 +1,252 @@
+//! Blocking access to the collection, document and search API endpoints.
+
+use super::iter;
+use crate::{
+    Error,
+    client::{self, collection::document, collection::documents},
+    models::{DocumentIndexParameters, ImportResult, SearchResult},
+    traits,
+};
+use ::std::borrow::Cow;
+use serde::{Serialize, de::DeserializeOwned};
+use tokio::runtime::Runtime;
+use typesense_codegen::{
+    apis::{collections_api, documents_api},
+    models::{
+        self as raw_models, DeleteDocumentsParameters, ExportDocumentsParameters,
+        ImportDocumentsParameters, SearchResultHit, UpdateDocumentsParameters,
+    },
+};
+
+/// Provides methods for interacting with a Typesense collection.
+///
+/// This struct is created by calling `client.collection()`.
+pub struct Collection<'c, D = serde_json::Value>
+where
+    D: DeserializeOwned + Serialize,
+{
+    inner: client::Collection<'c, D>,
+    runtime: &'c Runtime,
+}
+
+impl<'c, D> Collection<'c, D>
+where
+    D: DeserializeOwned + Serialize,
+{
+    pub(super) fn new(inner: client::Collection<'c, D>, runtime: &'c Runtime) -> Self {
+        Self { inner, runtime }
+    }
+
+    delegate_with_options!();
+
+    /// Provides access to the document-related API endpoints of the collection.
+    pub fn documents(&self) -> Documents<'_, D> {
+        Documents {
+            inner: self.inner.documents(),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Provides access to the API endpoints for a single document of the collection.
+    pub fn document<'d>(&'d self, document_id: impl Into<Cow<'d, str>>) -> Document<'d, D> {
+        Document {
+            inner: self.inner.document(document_id),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Retrieves the details of the collection.
+    pub fn retrieve(
+        &self,
+    ) -> Result<raw_models::CollectionResponse, Error<collections_api::GetCollectionError>> {
+        self.runtime.block_on(self.inner.retrieve())
+    }
+
+    /// Permanently drops the collection.
+    pub fn delete(
+        &self,
+    ) -> Result<raw_models::CollectionResponse, Error<collections_api::DeleteCollectionError>> {
+        self.runtime.block_on(self.inner.delete())
+    }
+
+    /// Updates the schema of the collection.
+    pub fn update(
+        &self,
+        update_schema: raw_models::CollectionUpdateSchema,
+    ) -> Result<raw_models::CollectionUpdateSchema, Error<collections_api::UpdateCollectionError>>
+    {
+        self.runtime.block_on(self.inner.update(update_schema))
+    }
+}
+
+/// Provides methods for interacting with the documents of a collection.
+///
+/// This struct is created by calling `collection.documents()`.
+pub struct Documents<'d, D = serde_json::Value>
+where
+    D: DeserializeOwned + Serialize,
+{
+    inner: documents::Documents<'d, D>,
+    runtime: &'d Runtime,
+}
+
+impl<'d, D> Documents<'d, D>
+where
+    D: DeserializeOwned + Serialize,
+{
+    delegate_with_options!();
+
+    /// Imports a batch of documents in JSONL format, and returns the JSONL results.
+    pub fn import_jsonl<'a>(
+        &self,
+        documents_jsonl: impl Into<Cow<'a, str>>,
+        params: ImportDocumentsParameters,
+    ) -> Result<String, Error<documents_api::ImportDocumentsError>> {
+        self.runtime
+            .block_on(self.inner.import_jsonl(documents_jsonl, params))
+    }
+
+    /// Exports the documents of the collection in JSONL format.
+    pub fn export_jsonl(
+        &self,
+        params: ExportDocumentsParameters<'_>,
+    ) -> Result<String, Error<documents_api::ExportDocumentsError>> {
+        self.runtime.block_on(self.inner.export_jsonl(params))
+    }
+
+    /// Exports the documents of the collection, and returns an iterator that deserializes
+    /// each of them as it is received.
+    pub fn export_stream(
+        &self,
+        params: ExportDocumentsParameters<'_>,
+    ) -> Result<
+        impl Iterator<Item = Result<D, Error<documents_api::ExportDocumentsError>>> + use<'d, D>,
+        Error<documents_api::ExportDocumentsError>,
+    > {
+        let stream = self.runtime.block_on(self.inner.export_stream(params))?;
+        Ok(iter(self.runtime, stream))
+    }
+
+    /// Deletes the documents that match a filter.
+    pub fn delete(
+        &self,
+        params: DeleteDocumentsParameters<'_>,
+    ) -> Result<raw_models::DeleteDocuments200Response, Error<documents_api::DeleteDocumentsError>>
+    {
+        self.runtime.block_on(self.inner.delete(params))
+    }
+
+    /// Searches the collection.
+    pub fn search(
+        &self,
+        params: raw_models::SearchParameters<'_>,
+    ) -> Result<SearchResult<D>, Error<documents_api::SearchCollectionError>> {
+        self.runtime.block_on(self.inner.search(params))
+    }
+
+    /// Returns an iterator over the pages of results of a search, each of them fetched when
+    /// the iterator reaches it.
+    pub fn search_pages<'p>(
+        &self,
+        params: raw_models::SearchParameters<'p>,
+    ) -> impl Iterator<Item = Result<SearchResult<D>, Error<documents_api::SearchCollectionError>>>
+    + use<'d, 'p, D> {
+        iter(self.runtime, self.inner.search_pages(params))
+    }
+
+    /// Returns an iterator over the hits of all the pages of results of a search.
+    pub fn search_stream<'p>(
+        &self,
+        params: raw_models::SearchParameters<'p>,
+    ) -> impl Iterator<
+        Item = Result<SearchResultHit<D>, Error<documents_api::SearchCollectionError>>,
+    > + use<'d, 'p, D> {
+        iter(self.runtime, self.inner.search_stream(params))
+    }
+}
+
+impl<'d, D> Documents<'d, D>
+where
+    D: traits::Document,
+{
+    /// Creates a new document in the collection.
+    pub fn create(
+        &self,
+        document: &D,
+        params: Option<DocumentIndexParameters>,
+    ) -> Result<D, Error<documents_api::IndexDocumentError>> {
+        self.runtime.block_on(self.inner.create(document, params))
+    }
+
+    /// Imports a batch of documents, and returns the result of each of them.
+    pub fn import(
+        &self,
+        documents: &[D],
+        params: ImportDocumentsParameters,
+    ) -> Result<Vec<ImportResult<D>>, Error<documents_api::ImportDocumentsError>> {
+        self.runtime.block_on(self.inner.import(documents, params))
+    }
+
+    /// Creates a new document, or replaces the existing document with the same ID.
+    pub fn upsert(
+        &self,
+        document: &D,
+        params: Option<DocumentIndexParameters>,
+    ) -> Result<D, Error<documents_api::IndexDocumentError>> {
+        self.runtime.block_on(self.inner.upsert(document, params))
+    }
+
+    /// Updates the documents that match a filter with the fields of `document`.
+    pub fn update(
+        &self,
+        document: &D::Partial,
+        params: UpdateDocumentsParameters<'_>,
+    ) -> Result<raw_models::UpdateDocuments200Response, Error<documents_api::UpdateDocumentsError>>
+    {
+        self.runtime.block_on(self.inner.update(document, params))
+    }
+}
+
+/// Provides methods for interacting with a single document.
+///
+/// This struct is created by calling `collection.document(id)`.
+pub struct Document<'d, D = serde_json::Value>
+where
+    D: DeserializeOwned + Serialize,
+{
+    inner: document::Document<'d, D>,
+    runtime: &'d Runtime,
+}
+
+impl<D> Document<'_, D>
+where
+    D: DeserializeOwned + Serialize,
+{
+    delegate_with_options!();
+
+    /// Fetches the document.
+    pub fn retrieve(&self) -> Result<D, Error<documents_api::GetDocumentError>> {
+        self.runtime.block_on(self.inner.retrieve())
+    }
+
+    /// Deletes the document, and returns it.
+    pub fn delete(&self) -> Result<D, Error<documents_api::DeleteDocumentError>> {
+        self.runtime.block_on(self.inner.delete())
+    }
+}
+
+impl<D> Document<'_, D>
+where
+    D: traits::Document,
+{
+    /// Updates the document with the fields of `partial_document`, and returns the updated
+    /// document.
+    pub fn update(
+        &self,
+        partial_document: &D::Partial,
+        params: Option<DocumentIndexParameters>,
+    ) -> Result<D, Error<documents_api::UpdateDocumentError>> {
+        self.runtime
+            .block_on(self.inner.update(partial_document, params))
+    }
+}
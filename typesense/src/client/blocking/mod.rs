@@ -0,0 +1,470 @@
+//! A blocking client, for synchronous code such as command-line tools and build scripts.
+//!
+//! The blocking [`Client`] wraps an async [`crate::Client`] and runs each request to completion
+//! on its own Tokio runtime, so it has the same multi-node failover, retries and configuration.
+//! It is available with the `blocking` feature, on native targets only.
+//!
+//! The blocking client must not be used from within an async runtime, where its methods panic.
+//! Use the async client there instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use typesense::{blocking, models::GetCollectionsParameters};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = blocking::Client::new(
+//!     typesense::Client::builder()
+//!         .nodes(vec!["http://node-1:8108", "http://node-2:8108"])
+//!         .api_key("xyz")
+//!         .build()?,
+//! );
+//!
+//! let collections = client.collections().retrieve(GetCollectionsParameters::default())?;
+//! let books = client.collection_schemaless("books").retrieve()?;
+//! println!("{} collections, {:?} books", collections.len(), books.num_documents);
+//! # Ok(())
+//! # }
+//! ```
+
+/// Implements `with_options` on a blocking namespace, by applying the options to its async
+/// namespace.
+macro_rules! delegate_with_options {
+    () => {
+        /// Returns this namespace with `options` applied to all of its requests.
+        #[inline]
+        pub fn with_options(self, options: $crate::RequestOptions) -> Self {
+            Self {
+                inner: self.inner.with_options(options),
+                runtime: self.runtime,
+            }
+        }
+    };
+}
+
+mod collection;
+
+use crate::{
+    ConfigError, Error,
+    client::{
+        self, ClientConfig, alias, aliases, collections, key, keys, multi_search, operations,
+    },
+    models::{self, MultiSearchBody, RuntimeConfig, ScopedKeyParameters, SearchResult},
+    traits,
+};
+use ::std::{borrow::Cow, future::Future};
+pub use collection::{Collection, Document, Documents};
+use futures::{Stream, StreamExt};
+use serde::{Serialize, de::DeserializeOwned};
+use tokio::runtime::Runtime;
+use typesense_codegen::apis::{
+    collections_api, debug_api, documents_api, health_api, keys_api, operations_api,
+};
+
+/// A blocking Typesense client.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug)]
+pub struct Client {
+    inner: client::Client,
+    runtime: Runtime,
+}
+
+impl Client {
+    /// Creates a blocking client from an async client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Tokio runtime cannot be created.
+    pub fn new(client: client::Client) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to create the Tokio runtime of the blocking client");
+        Self {
+            inner: client,
+            runtime,
+        }
+    }
+
+    /// Creates a blocking client from a [`ClientConfig`], see [`crate::Client::from_config`].
+    pub fn from_config(config: ClientConfig) -> Result<Self, ConfigError> {
+        client::Client::from_config(config).map(Self::new)
+    }
+
+    /// Creates a blocking client from environment variables, see [`crate::Client::from_env`].
+    pub fn from_env() -> Result<Self, ConfigError> {
+        client::Client::from_env().map(Self::new)
+    }
+
+    /// Creates a blocking client from a connection URL, see [`crate::Client::from_url`].
+    pub fn from_url(url: &str) -> Result<Self, ConfigError> {
+        client::Client::from_url(url).map(Self::new)
+    }
+
+    /// Returns the async client, e.g. to call an endpoint that has no blocking method with
+    /// [`block_on`](Self::block_on).
+    pub fn as_async(&self) -> &client::Client {
+        &self.inner
+    }
+
+    /// Runs a future of the async client to completion.
+    ///
+    /// ```no_run
+    /// # fn run(client: typesense::blocking::Client) {
+    /// let presets = client.block_on(client.as_async().presets().retrieve());
+    /// # }
+    /// ```
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Returns the health and request statistics of every node, see
+    /// [`crate::Client::node_stats`].
+    pub fn node_stats(&self) -> Vec<crate::NodeStats> {
+        self.inner.node_stats()
+    }
+
+    /// Provides access to the collection aliases-related API endpoints.
+    pub fn aliases(&self) -> Aliases<'_> {
+        Aliases {
+            inner: self.inner.aliases(),
+            runtime: &self.runtime,
+        }
+    }
+
+    /// Provides access to a specific collection alias's-related API endpoints.
+    pub fn alias<'a>(&'a self, alias_name: &'a str) -> Alias<'a> {
+        Alias {
+            inner: self.inner.alias(alias_name),
+            runtime: &self.runtime,
+        }
+    }
+
+    /// Provides access to API endpoints for managing collections.
+    pub fn collections(&self) -> Collections<'_> {
+        Collections {
+            inner: self.inner.collections(),
+            runtime: &self.runtime,
+        }
+    }
+
+    /// Provides access to the API endpoints of a collection, with documents of type `D`.
+    pub fn collection_named<'c, D>(
+        &'c self,
+        collection_name: impl Into<Cow<'c, str>>,
+    ) -> Collection<'c, D>
+    where
+        D: DeserializeOwned + Serialize,
+    {
+        Collection::new(self.inner.collection_named(collection_name), &self.runtime)
+    }
+
+    /// Provides access to the API endpoints of the collection of the document type `D`.
+    pub fn collection<'c, D>(&'c self) -> Collection<'c, D>
+    where
+        D: traits::Document,
+    {
+        Collection::new(self.inner.collection(), &self.runtime)
+    }
+
+    /// Provides access to the API endpoints of a collection, with documents of type
+    /// `serde_json::Value`.
+    pub fn collection_schemaless<'c>(
+        &'c self,
+        collection_name: impl Into<Cow<'c, str>>,
+    ) -> Collection<'c, serde_json::Value> {
+        Collection::new(
+            self.inner.collection_schemaless(collection_name),
+            &self.runtime,
+        )
+    }
+
+    /// Provides access to endpoints for managing the collection of API keys.
+    pub fn keys(&self) -> Keys<'_> {
+        Keys {
+            inner: self.inner.keys(),
+            runtime: &self.runtime,
+        }
+    }
+
+    /// Provides access to endpoints for managing a single API key.
+    pub fn key(&self, key_id: i64) -> Key<'_> {
+        Key {
+            inner: self.inner.key(key_id),
+            runtime: &self.runtime,
+        }
+    }
+
+    /// Provides access to the multi search endpoint.
+    pub fn multi_search(&self) -> MultiSearch<'_> {
+        MultiSearch {
+            inner: self.inner.multi_search(),
+            runtime: &self.runtime,
+        }
+    }
+
+    /// Provides access to top-level, non-namespaced API endpoints.
+    pub fn operations(&self) -> Operations<'_> {
+        Operations {
+            inner: self.inner.operations(),
+            runtime: &self.runtime,
+        }
+    }
+}
+
+/// Returns an iterator over the items of `stream`, each of them awaited on `runtime`.
+fn iter<S: Stream>(runtime: &Runtime, stream: S) -> impl Iterator<Item = S::Item> {
+    let mut stream = Box::pin(stream);
+    ::std::iter::from_fn(move || runtime.block_on(stream.next()))
+}
+
+/// Provides methods for managing collection aliases, created by `client.aliases()`.
+pub struct Aliases<'a> {
+    inner: aliases::Aliases<'a>,
+    runtime: &'a Runtime,
+}
+
+impl Aliases<'_> {
+    delegate_with_options!();
+
+    /// Creates or updates a collection alias.
+    pub fn upsert<'n>(
+        &self,
+        alias_name: impl Into<Cow<'n, str>>,
+        schema: models::CollectionAliasSchema<'_>,
+    ) -> Result<models::CollectionAlias, Error<collections_api::UpsertAliasError>> {
+        self.runtime.block_on(self.inner.upsert(alias_name, schema))
+    }
+
+    /// Lists all aliases and the corresponding collections that they map to.
+    pub fn retrieve(
+        &self,
+    ) -> Result<models::CollectionAliasesResponse, Error<collections_api::GetAliasesError>> {
+        self.runtime.block_on(self.inner.retrieve())
+    }
+}
+
+/// Provides methods for a single collection alias, created by `client.alias(name)`.
+pub struct Alias<'a> {
+    inner: alias::Alias<'a>,
+    runtime: &'a Runtime,
+}
+
+impl Alias<'_> {
+    delegate_with_options!();
+
+    /// Retrieves the details of the alias.
+    pub fn retrieve(
+        &self,
+    ) -> Result<models::CollectionAlias, Error<collections_api::GetAliasError>> {
+        self.runtime.block_on(self.inner.retrieve())
+    }
+
+    /// Deletes the alias.
+    pub fn delete(
+        &self,
+    ) -> Result<models::CollectionAlias, Error<collections_api::DeleteAliasError>> {
+        self.runtime.block_on(self.inner.delete())
+    }
+}
+
+/// Provides methods for managing collections, created by `client.collections()`.
+pub struct Collections<'c> {
+    inner: collections::Collections<'c>,
+    runtime: &'c Runtime,
+}
+
+impl Collections<'_> {
+    delegate_with_options!();
+
+    /// Creates a new collection with the given schema.
+    pub fn create(
+        &self,
+        schema: models::CollectionSchema<'_>,
+    ) -> Result<models::CollectionResponse, Error<collections_api::CreateCollectionError>> {
+        self.runtime.block_on(self.inner.create(schema))
+    }
+
+    /// Lists the existing collections.
+    pub fn retrieve(
+        &self,
+        params: models::GetCollectionsParameters<'_>,
+    ) -> Result<Vec<models::CollectionResponse>, Error<collections_api::GetCollectionsError>> {
+        self.runtime.block_on(self.inner.retrieve(params))
+    }
+}
+
+/// Provides methods for managing API keys, created by `client.keys()`.
+pub struct Keys<'c> {
+    inner: keys::Keys<'c>,
+    runtime: &'c Runtime,
+}
+
+impl Keys<'_> {
+    delegate_with_options!();
+
+    /// Creates a new API key.
+    pub fn create(
+        &self,
+        schema: models::ApiKeySchema<'_>,
+    ) -> Result<models::ApiKey, Error<keys_api::CreateKeyError>> {
+        self.runtime.block_on(self.inner.create(schema))
+    }
+
+    /// Lists all API keys and their metadata.
+    pub fn retrieve(&self) -> Result<models::ApiKeysResponse, Error<keys_api::GetKeysError>> {
+        self.runtime.block_on(self.inner.retrieve())
+    }
+
+    /// Generates a scoped search API key locally, without any request.
+    pub fn generate_scoped_search_key(
+        &self,
+        key: impl AsRef<str>,
+        params: &ScopedKeyParameters<'_>,
+    ) -> anyhow::Result<String> {
+        self.inner.generate_scoped_search_key(key, params)
+    }
+}
+
+/// Provides methods for a single API key, created by `client.key(id)`.
+pub struct Key<'c> {
+    inner: key::Key<'c>,
+    runtime: &'c Runtime,
+}
+
+impl Key<'_> {
+    delegate_with_options!();
+
+    /// Retrieves the metadata of the key.
+    pub fn retrieve(&self) -> Result<models::ApiKey, Error<keys_api::GetKeyError>> {
+        self.runtime.block_on(self.inner.retrieve())
+    }
+
+    /// Deletes the key.
+    pub fn delete(&self) -> Result<models::ApiKeyDeleteResponse, Error<keys_api::DeleteKeyError>> {
+        self.runtime.block_on(self.inner.delete())
+    }
+}
+
+/// Provides methods for multi search, created by `client.multi_search()`.
+pub struct MultiSearch<'c> {
+    inner: multi_search::MultiSearch<'c>,
+    runtime: &'c Runtime,
+}
+
+impl MultiSearch<'_> {
+    delegate_with_options!();
+
+    /// Performs several searches in one request, and returns the result of each search.
+    pub fn perform(
+        &self,
+        search_requests: MultiSearchBody<'_>,
+        common_search_params: models::MultiSearchParameters<'_>,
+    ) -> Result<models::MultiSearchResult<serde_json::Value>, Error<documents_api::MultiSearchError>>
+    {
+        self.runtime
+            .block_on(self.inner.perform(search_requests, common_search_params))
+    }
+
+    /// Performs several searches in one request, and merges their results into a single
+    /// result.
+    pub fn perform_union<D: for<'de> serde::Deserialize<'de>>(
+        &self,
+        search_requests: MultiSearchBody<'_>,
+        common_search_params: models::MultiSearchParameters<'_>,
+    ) -> Result<SearchResult<D>, Error<documents_api::MultiSearchError>> {
+        self.runtime.block_on(
+            self.inner
+                .perform_union(search_requests, common_search_params),
+        )
+    }
+}
+
+/// Provides top-level, non-namespaced operations, created by `client.operations()`.
+pub struct Operations<'a> {
+    inner: operations::Operations<'a>,
+    runtime: &'a Runtime,
+}
+
+impl Operations<'_> {
+    delegate_with_options!();
+
+    /// Returns debug information about the node that answers the request.
+    pub fn debug(&self) -> Result<models::Debug200Response, Error<debug_api::DebugError>> {
+        self.runtime.block_on(self.inner.debug())
+    }
+
+    /// Checks the health of the node that answers the request.
+    pub fn health(&self) -> Result<models::HealthStatus, Error<health_api::HealthError>> {
+        self.runtime.block_on(self.inner.health())
+    }
+
+    /// Returns the metrics of the node that answers the request.
+    pub fn retrieve_metrics(
+        &self,
+    ) -> Result<serde_json::Value, Error<operations_api::RetrieveMetricsError>> {
+        self.runtime.block_on(self.inner.retrieve_metrics())
+    }
+
+    /// Returns the API statistics of the node that answers the request.
+    pub fn retrieve_api_stats(
+        &self,
+    ) -> Result<models::ApiStatsResponse, Error<operations_api::RetrieveApiStatsError>> {
+        self.runtime.block_on(self.inner.retrieve_api_stats())
+    }
+
+    /// Creates a snapshot of the data, in the given directory of the server.
+    pub fn take_snapshot(
+        &self,
+        params: models::TakeSnapshotParams<'_>,
+    ) -> Result<models::SuccessStatus, Error<operations_api::TakeSnapshotError>> {
+        self.runtime.block_on(self.inner.take_snapshot(params))
+    }
+
+    /// Triggers a follower node to initiate the raft voting process.
+    pub fn vote(&self) -> Result<models::SuccessStatus, Error<operations_api::VoteError>> {
+        self.runtime.block_on(self.inner.vote())
+    }
+
+    /// Returns the status of the ongoing schema change operations.
+    pub fn get_schema_changes(
+        &self,
+    ) -> Result<Option<Vec<models::SchemaChangeStatus>>, Error<operations_api::GetSchemaChangesError>>
+    {
+        self.runtime.block_on(self.inner.get_schema_changes())
+    }
+
+    /// Compacts the on-disk database.
+    pub fn compact_db(
+        &self,
+    ) -> Result<models::SuccessStatus, Error<operations_api::CompactDbError>> {
+        self.runtime.block_on(self.inner.compact_db())
+    }
+
+    /// Clears the cache of the search requests.
+    pub fn clear_cache(
+        &self,
+    ) -> Result<models::SuccessStatus, Error<operations_api::ClearCacheError>> {
+        self.runtime.block_on(self.inner.clear_cache())
+    }
+
+    /// Enables logging of the requests that take longer than the given threshold.
+    pub fn toggle_slow_request_log(
+        &self,
+        slow_requests_threshold_ms: i32,
+    ) -> Result<models::SuccessStatus, Error<operations_api::UpdateConfigError>> {
+        self.runtime.block_on(
+            self.inner
+                .toggle_slow_request_log(slow_requests_threshold_ms),
+        )
+    }
+
+    /// Updates the runtime configuration of the server.
+    pub fn update_config(
+        &self,
+        config: &RuntimeConfig,
+    ) -> Result<models::SuccessStatus, Error<operations_api::UpdateConfigError>> {
+        self.runtime.block_on(self.inner.update_config(config))
+    }
+}
@@ -0,0 +1,212 @@
+//! Per-node circuit breakers, which stop sending requests to nodes that fail too often.
+
+use ::std::{collections::VecDeque, sync::Mutex};
+use web_time::{Duration, Instant};
+
+/// The configuration of the circuit breakers of the nodes of a client.
+///
+/// Each node has a circuit, which is initially closed: requests are sent to the node. When the
+/// share of failed requests among the most recent ones reaches `failure_rate`, the circuit
+/// opens and no request is sent to the node for `open_duration`. The circuit is then half-open:
+/// at most `half_open_probes` requests at a time are sent to the node, and the circuit closes
+/// again after `half_open_successes` of them succeeded, or opens again as soon as one fails.
+///
+/// Requests fail with a server or network error. When the circuits of all nodes are open,
+/// requests fail without being sent.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(not(target_family = "wasm"))]
+/// # {
+/// use std::time::Duration;
+/// use typesense::{CircuitBreaker, Client};
+///
+/// let client = Client::builder()
+///     .nodes(vec!["http://node-1:8108", "http://node-2:8108"])
+///     .api_key("xyz")
+///     .circuit_breaker(
+///         CircuitBreaker::builder()
+///             .failure_rate(0.5)
+///             .open_duration(Duration::from_secs(10))
+///             .build(),
+///     )
+///     .build()
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Clone, Debug, bon::Builder)]
+pub struct CircuitBreaker {
+    /// The share of failed requests, between 0 and 1, at which the circuit opens.
+    #[builder(default = 0.5)]
+    failure_rate: f64,
+    /// The number of most recent requests the failure rate is computed from.
+    #[builder(default = 20)]
+    window: usize,
+    /// The minimum number of requests in the window before the circuit can open.
+    #[builder(default = 5)]
+    minimum_requests: usize,
+    /// How long the circuit stays open before requests are sent to the node again.
+    #[builder(default = Duration::from_secs(30))]
+    open_duration: Duration,
+    /// The maximum number of requests sent at the same time to a node whose circuit is
+    /// half-open.
+    #[builder(default = 1)]
+    half_open_probes: usize,
+    /// The number of requests that must succeed to close a half-open circuit.
+    #[builder(default = 2)]
+    half_open_successes: usize,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// The state of the circuit of a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent to the node.
+    Closed,
+    /// No request is sent to the node.
+    Open,
+    /// A limited number of requests are sent to the node, to find out whether it recovered.
+    HalfOpen,
+}
+
+/// The circuit of a node.
+#[derive(Debug)]
+pub(super) struct Circuit {
+    state: State,
+    /// The outcomes of the most recent requests while closed, `true` for the failures.
+    failures: VecDeque<bool>,
+    /// The number of requests in flight while half-open.
+    probes: usize,
+    /// The number of requests that succeeded while half-open.
+    successes: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    Closed,
+    Open { since: Instant },
+    HalfOpen,
+}
+
+impl Default for Circuit {
+    fn default() -> Self {
+        Self {
+            state: State::Closed,
+            failures: VecDeque::new(),
+            probes: 0,
+            successes: 0,
+        }
+    }
+}
+
+impl Circuit {
+    /// Returns the state of the circuit, and the time left until an open circuit is half-open.
+    pub(super) fn state(&self, config: &CircuitBreaker) -> (CircuitState, Option<Duration>) {
+        match self.state {
+            State::Closed => (CircuitState::Closed, None),
+            State::Open { since } => match config.open_duration.checked_sub(since.elapsed()) {
+                Some(remaining) if !remaining.is_zero() => (CircuitState::Open, Some(remaining)),
+                _ => (CircuitState::HalfOpen, None),
+            },
+            State::HalfOpen => (CircuitState::HalfOpen, None),
+        }
+    }
+
+    /// Returns whether a request can be sent to the node.
+    pub(super) fn is_available(&self, config: &CircuitBreaker) -> bool {
+        match self.state(config).0 {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => self.probes < config.half_open_probes,
+        }
+    }
+
+    fn record(&mut self, config: &CircuitBreaker, failed: bool, is_probe: bool) {
+        match self.state {
+            State::Closed => {
+                if self.failures.len() == config.window.max(1) {
+                    self.failures.pop_front();
+                }
+                self.failures.push_back(failed);
+                let count = self.failures.len();
+                let failures = self.failures.iter().filter(|&&failed| failed).count();
+                if count >= config.minimum_requests
+                    && failures as f64 >= config.failure_rate * count as f64
+                {
+                    self.open();
+                }
+            }
+            // Requests sent before the circuit opened.
+            State::Open { .. } => {}
+            State::HalfOpen if !is_probe => {}
+            State::HalfOpen if failed => self.open(),
+            State::HalfOpen => {
+                self.successes += 1;
+                if self.successes >= config.half_open_successes {
+                    self.state = State::Closed;
+                    self.failures.clear();
+                }
+            }
+        }
+    }
+
+    fn open(&mut self) {
+        self.state = State::Open {
+            since: Instant::now(),
+        };
+        self.failures.clear();
+    }
+}
+
+/// Allows a request to be sent to a node, and records its outcome in the node's circuit.
+pub(super) struct Permit<'a> {
+    circuit: &'a Mutex<Circuit>,
+    config: &'a CircuitBreaker,
+    is_probe: bool,
+}
+
+impl<'a> Permit<'a> {
+    /// Returns a permit if the circuit allows a request to be sent.
+    pub(super) fn acquire(circuit: &'a Mutex<Circuit>, config: &'a CircuitBreaker) -> Option<Self> {
+        let mut guard = circuit.lock().unwrap();
+        if !guard.is_available(config) {
+            return None;
+        }
+        let is_probe = !matches!(guard.state, State::Closed);
+        if let State::Open { .. } = guard.state {
+            guard.state = State::HalfOpen;
+            guard.successes = 0;
+        }
+        if is_probe {
+            guard.probes += 1;
+        }
+        Some(Self {
+            circuit,
+            config,
+            is_probe,
+        })
+    }
+
+    /// Records whether the request failed with a server or network error.
+    pub(super) fn finish(self, failed: bool) {
+        self.circuit
+            .lock()
+            .unwrap()
+            .record(self.config, failed, self.is_probe);
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.is_probe {
+            let mut circuit = self.circuit.lock().unwrap();
+            circuit.probes = circuit.probes.saturating_sub(1);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Chunked, concurrent imports of large document streams.
+
+use super::documents::Documents;
+use crate::{
+    Error, ImportFailure,
+    client::timer,
+    models::{BulkImportOptions, BulkImportProgress, BulkImportReport, ImportResult},
+};
+use futures::{Stream, StreamExt};
+use serde::{Serialize, de::DeserializeOwned};
+use typesense_codegen::apis::documents_api;
+
+impl<'d, D> Documents<'d, D>
+where
+    D: DeserializeOwned + Serialize,
+{
+    /// Imports a stream of documents of any size in batches.
+    ///
+    /// The documents are split into batches of `options.batch_size`, and up to
+    /// `options.concurrency` batches are imported at the same time, spread over the healthy
+    /// nodes of the client. A batch that fails on every node is retried with exponential backoff;
+    /// if it still fails after `options.max_retries` retries, the import stops and the error is
+    /// returned. Documents from batches that completed before remain imported.
+    ///
+    /// `on_progress` is called after every completed batch. Documents rejected by Typesense do
+    /// not stop the import; they are collected into [`BulkImportReport::failures`].
+    ///
+    /// To import an iterator, wrap it with [`futures::stream::iter`].
+    ///
+    /// # Arguments
+    /// * `documents` - The documents to import.
+    /// * `options` - A `BulkImportOptions` struct with the batching, concurrency and retry settings.
+    /// * `on_progress` - Called with the overall progress after every completed batch.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use typesense::{Client, models::BulkImportOptions};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let documents = (0..10_000_000).map(|i| serde_json::json!({ "id": i.to_string() }));
+    ///
+    /// let products = client.collection_schemaless("products");
+    /// let report = products
+    ///     .documents()
+    ///     .bulk_import(
+    ///         futures::stream::iter(documents),
+    ///         BulkImportOptions::default(),
+    ///         |progress| println!("{} documents imported", progress.imported),
+    ///     )
+    ///     .await?
+    ///     .ensure_all_succeeded()?;
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub async fn bulk_import(
+        &self,
+        documents: impl Stream<Item = D>,
+        options: BulkImportOptions,
+        mut on_progress: impl FnMut(&BulkImportProgress),
+    ) -> Result<BulkImportReport, Error<documents_api::ImportDocumentsError>> {
+        let batch_size = options.batch_size.max(1);
+        let options = &options;
+
+        let mut batches = std::pin::pin!(
+            documents
+                .chunks(batch_size)
+                .enumerate()
+                .map(|(index, batch)| async move {
+                    let len = batch.len();
+                    let results = self.import_batch(batch, options).await;
+                    (index * batch_size, len, results)
+                })
+                .buffer_unordered(options.concurrency.max(1))
+        );
+
+        let mut progress = BulkImportProgress::default();
+        let mut failures = Vec::new();
+        while let Some((offset, len, results)) = batches.next().await {
+            let results = results?;
+            for (position, result) in results.into_iter().enumerate() {
+                if !result.success {
+                    failures.push(ImportFailure {
+                        index: offset + position,
+                        error: result.error.or(result.parse_error).unwrap_or_default(),
+                        code: result.code,
+                        document: result.failed_document,
+                    });
+                }
+            }
+
+            progress.batches += 1;
+            progress.processed += len;
+            progress.failed = failures.len();
+            progress.imported = progress.processed - progress.failed;
+            on_progress(&progress);
+        }
+
+        failures.sort_unstable_by_key(|failure| failure.index);
+        Ok(BulkImportReport {
+            total: progress.processed,
+            imported: progress.imported,
+            batches: progress.batches,
+            failures,
+        })
+    }
+
+    /// Imports a single batch, retrying it with backoff when every node failed.
+    async fn import_batch(
+        &self,
+        batch: Vec<D>,
+        options: &BulkImportOptions,
+    ) -> Result<Vec<ImportResult<D>>, Error<documents_api::ImportDocumentsError>> {
+        let mut backoff = options.retry_backoff;
+        let mut retries = 0;
+        loop {
+            match self.import_documents(&batch, options.params.clone()).await {
+                Err(Error::AllNodesFailed { .. }) if retries < options.max_retries => {
+                    timer::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
@@ -4,7 +4,7 @@
 //! via a parent `Collection` struct, for example:
 //! `client.collection::<Book>().document("123")`
 
-use crate::{Client, Error, execute_wrapper, traits};
+use crate::{Error, client::ClientRef, execute_wrapper, traits};
 use ::std::borrow::Cow;
 use serde::{Serialize, de::DeserializeOwned};
 use typesense_codegen::apis::documents_api;
@@ -19,8 +19,8 @@ pub struct Document<'d, D = serde_json::Value>
 where
     D: DeserializeOwned + Serialize,
 {
-    client: &'d Client,
-    collection_name: &'d str,
+    client: ClientRef<'d>,
+    collection_name: Cow<'d, str>,
     document_id: Cow<'d, str>,
     _phantom: core::marker::PhantomData<D>,
 }
@@ -32,8 +32,8 @@ where
     /// Creates a new `Document` instance for a specific document ID.
     #[inline]
     pub(super) fn new(
-        client: &'d Client,
-        collection_name: &'d str,
+        client: ClientRef<'d>,
+        collection_name: Cow<'d, str>,
         document_id: impl Into<Cow<'d, str>>,
     ) -> Self {
         Self {
@@ -44,17 +44,19 @@ where
         }
     }
 
+    with_options!();
+
     /// Fetches this individual document from the collection and deserializes it into `D`.
     ///
     /// # Returns
     /// A `Result` containing the strongly-typed document `D` if successful.
     pub async fn retrieve(&self) -> Result<D, Error<documents_api::GetDocumentError>> {
         let params = documents_api::GetDocumentParams {
-            collection_name: self.collection_name.into(),
+            collection_name: self.collection_name.as_ref().into(),
             document_id: self.document_id.as_ref().into(),
         };
 
-        let result_value = execute_wrapper!(self, documents_api::get_document, params)?;
+        let result_value = execute_wrapper!(self, read documents_api::get_document, params)?;
 
         // Deserialize the raw JSON value into the user's type D.
         serde_json::from_value(result_value).map_err(Error::from)
@@ -67,11 +69,11 @@ where
     /// A `Result` containing the deleted document deserialized into `D`.
     pub async fn delete(&self) -> Result<D, Error<documents_api::DeleteDocumentError>> {
         let params = documents_api::DeleteDocumentParams {
-            collection_name: self.collection_name.into(),
+            collection_name: self.collection_name.as_ref().into(),
             document_id: self.document_id.as_ref().into(),
         };
 
-        let result_value = execute_wrapper!(self, documents_api::delete_document, params)?;
+        let result_value = execute_wrapper!(self, write documents_api::delete_document, params)?;
 
         // Deserialize the raw JSON value of the deleted document into T.
         serde_json::from_value(result_value).map_err(Error::from)
@@ -130,13 +132,13 @@ where
         params: Option<crate::models::DocumentIndexParameters>,
     ) -> Result<D, Error<documents_api::UpdateDocumentError>> {
         let params = documents_api::UpdateDocumentParams {
-            collection_name: self.collection_name.into(),
+            collection_name: self.collection_name.as_ref().into(),
             document_id: self.document_id.as_ref().into(),
             body: partial_document,
             dirty_values: params.and_then(|d| d.dirty_values),
         };
 
-        let result_value = execute_wrapper!(self, documents_api::update_document, params)?;
+        let result_value = execute_wrapper!(self, write documents_api::update_document, params)?;
 
         // Deserialize the raw JSON value of the updated document into T.
         serde_json::from_value(result_value).map_err(Error::from)
@@ -4,20 +4,27 @@
 //! via the main `client.collection_schemaless("collection_name").documents()` method or
 //! `client.collection_named::<T>("...").documents()`.
 
+use super::jsonl;
 use crate::{
-    Client, Error, execute_wrapper,
-    models::{DocumentIndexParameters, SearchResult},
+    ApiError, Error,
+    client::ClientRef,
+    execute_wrapper,
+    models::{DocumentIndexParameters, ImportResult, SearchResult},
     traits,
 };
-use ::std::borrow::Cow;
+use ::std::{borrow::Cow, io};
+use futures::{AsyncWrite, AsyncWriteExt, Stream, StreamExt, TryStreamExt, stream};
 use serde::{Serialize, de::DeserializeOwned};
 use typesense_codegen::{
     apis::documents_api,
     models::{
         self as raw_models, DeleteDocumentsParameters, ExportDocumentsParameters,
-        ImportDocumentsParameters, UpdateDocumentsParameters,
+        ImportDocumentsParameters, SearchGroupedHit, SearchResultHit, UpdateDocumentsParameters,
     },
 };
+
+/// The maximum `per_page` value accepted by Typesense.
+const MAX_PER_PAGE: i32 = 250;
 /// Provides methods for interacting with documents within a specific Typesense collection.
 ///
 /// This struct is generic over the document type `D`. If created via `client.collection_schemaless(...)`,
@@ -27,8 +34,8 @@ pub struct Documents<'d, D = serde_json::Value>
 where
     D: DeserializeOwned + Serialize,
 {
-    client: &'d Client,
-    collection_name: &'d str,
+    client: ClientRef<'d>,
+    collection_name: Cow<'d, str>,
     _phantom: core::marker::PhantomData<D>,
 }
 
@@ -38,7 +45,7 @@ where
 {
     /// Creates a new `Documents` instance.
     #[inline]
-    pub(super) fn new(client: &'d Client, collection_name: &'d str) -> Self {
+    pub(super) fn new(client: ClientRef<'d>, collection_name: Cow<'d, str>) -> Self {
         Self {
             client,
             collection_name,
@@ -46,6 +53,8 @@ where
         }
     }
 
+    with_options!();
+
     /// Indexes a document in the collection.
     /// # Arguments
     /// * `document` - A `serde_json::Value` representing the document.
@@ -57,12 +66,12 @@ where
         params: Option<DocumentIndexParameters>,
     ) -> Result<serde_json::Value, Error<documents_api::IndexDocumentError>> {
         let params = documents_api::IndexDocumentParams {
-            collection_name: self.collection_name.into(),
+            collection_name: self.collection_name.as_ref().into(),
             body: document,
             action: Some(action.into()),
             dirty_values: params.and_then(|d| d.dirty_values), // Or expose this as an argument if needed
         };
-        execute_wrapper!(self, documents_api::index_document, params)
+        execute_wrapper!(self, write documents_api::index_document, params)
     }
 
     // --- Bulk Operation Methods ---
@@ -81,7 +90,7 @@ where
     ) -> Result<String, Error<documents_api::ImportDocumentsError>> {
         let params = documents_api::ImportDocumentsParams {
             body: documents_jsonl.into(),
-            collection_name: self.collection_name.into(),
+            collection_name: self.collection_name.as_ref().into(),
 
             action: params.action,
             batch_size: params.batch_size,
@@ -90,7 +99,37 @@ where
             return_doc: params.return_doc,
             return_id: params.return_id,
         };
-        execute_wrapper!(self, documents_api::import_documents, params)
+        execute_wrapper!(self, write documents_api::import_documents, params)
+    }
+
+    /// Serializes `documents` into JSONL, imports them and parses the result of every document.
+    ///
+    /// No request is sent when there are no documents.
+    pub(super) async fn import_documents<'a>(
+        &self,
+        documents: impl IntoIterator<Item = &'a D>,
+        params: ImportDocumentsParameters,
+    ) -> Result<Vec<ImportResult<D>>, Error<documents_api::ImportDocumentsError>>
+    where
+        D: 'a,
+    {
+        let mut body = String::new();
+        for document in documents {
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            body.push_str(&serde_json::to_string(document)?);
+        }
+        if body.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let response = self.import_jsonl(body, params).await?;
+        Ok(response
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(ImportResult::from_line)
+            .collect())
     }
 
     /// Exports all documents in a collection in JSONL format.
@@ -102,12 +141,134 @@ where
         params: ExportDocumentsParameters<'_>,
     ) -> Result<String, Error<documents_api::ExportDocumentsError>> {
         let params = documents_api::ExportDocumentsParams {
-            collection_name: self.collection_name.into(),
+            collection_name: self.collection_name.as_ref().into(),
             exclude_fields: params.exclude_fields,
             filter_by: params.filter_by,
             include_fields: params.include_fields,
         };
-        execute_wrapper!(self, documents_api::export_documents, params)
+        execute_wrapper!(self, read documents_api::export_documents, params)
+    }
+
+    /// Exports the documents in a collection as a stream of raw JSONL lines.
+    ///
+    /// Unlike [`export_jsonl`](Self::export_jsonl), the response body is not buffered: lines are
+    /// yielded as they arrive, so collections of any size can be exported in constant memory.
+    /// Node failover applies to the initial request only; a network error while reading the body
+    /// is yielded once and ends the stream.
+    ///
+    /// # Arguments
+    /// * `params` - An `ExportDocumentsParameters` struct containing options like `filter_by` and `include_fields`.
+    pub async fn export_jsonl_stream(
+        &self,
+        params: ExportDocumentsParameters<'_>,
+    ) -> Result<
+        impl Stream<Item = Result<String, Error<documents_api::ExportDocumentsError>>> + use<D>,
+        Error<documents_api::ExportDocumentsError>,
+    > {
+        let response = self.export_response(params).await?;
+        Ok(jsonl::lines(response).map(|line| {
+            String::from_utf8(line?).map_err(|e| {
+                Error::from(ApiError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))
+            })
+        }))
+    }
+
+    /// Exports the documents in a collection as a stream of documents of type `D`.
+    ///
+    /// Documents are deserialized one line at a time as the response body arrives. A line that
+    /// cannot be deserialized into `D` yields an [`Error::Deserialization`] without ending the
+    /// stream, so the remaining documents can still be read.
+    ///
+    /// # Arguments
+    /// * `params` - An `ExportDocumentsParameters` struct containing options like `filter_by` and `include_fields`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use typesense::{Client, models::ExportDocumentsParameters};
+    /// use futures::StreamExt;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let products = client.collection_schemaless("products");
+    /// let documents = products
+    ///     .documents()
+    ///     .export_stream(ExportDocumentsParameters::default())
+    ///     .await?;
+    ///
+    /// let mut documents = std::pin::pin!(documents);
+    /// while let Some(document) = documents.next().await {
+    ///     match document {
+    ///         Ok(document) => println!("{document}"),
+    ///         Err(e) => eprintln!("Skipping document: {e}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub async fn export_stream(
+        &self,
+        params: ExportDocumentsParameters<'_>,
+    ) -> Result<
+        impl Stream<Item = Result<D, Error<documents_api::ExportDocumentsError>>> + use<D>,
+        Error<documents_api::ExportDocumentsError>,
+    > {
+        let response = self.export_response(params).await?;
+        Ok(jsonl::lines(response).map(|line| serde_json::from_slice(&line?).map_err(Error::from)))
+    }
+
+    /// Exports the documents in a collection as JSONL into `writer`, without buffering the
+    /// response body. Returns the number of bytes written.
+    ///
+    /// The writer is flushed once the export is complete, but not closed.
+    ///
+    /// # Arguments
+    /// * `params` - An `ExportDocumentsParameters` struct containing options like `filter_by` and `include_fields`.
+    /// * `writer` - Any [`futures::AsyncWrite`], e.g. a file wrapped with `tokio_util::compat`.
+    pub async fn export_to_writer<W>(
+        &self,
+        params: ExportDocumentsParameters<'_>,
+        writer: &mut W,
+    ) -> Result<u64, Error<documents_api::ExportDocumentsError>>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let response = self.export_response(params).await?;
+        let node = jsonl::node_url(&response);
+        let mut body = std::pin::pin!(response.bytes_stream());
+        let mut written = 0;
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(|e| jsonl::body_error(e, &node))?;
+            writer
+                .write_all(&chunk)
+                .await
+                .map_err(|e| Error::from(ApiError::Io(e)))?;
+            written += chunk.len() as u64;
+        }
+        writer
+            .flush()
+            .await
+            .map_err(|e| Error::from(ApiError::Io(e)))?;
+        Ok(written)
+    }
+
+    /// Sends the export request through the client's failover, without reading the body.
+    async fn export_response(
+        &self,
+        params: ExportDocumentsParameters<'_>,
+    ) -> Result<reqwest::Response, Error<documents_api::ExportDocumentsError>> {
+        let params = documents_api::ExportDocumentsParams {
+            collection_name: self.collection_name.as_ref().into(),
+            exclude_fields: params.exclude_fields,
+            filter_by: params.filter_by,
+            include_fields: params.include_fields,
+        };
+        execute_wrapper!(self, read jsonl::export_documents, params)
     }
 
     /// Deletes a batch of documents matching a specific filter condition.
@@ -120,13 +281,13 @@ where
     ) -> Result<raw_models::DeleteDocuments200Response, Error<documents_api::DeleteDocumentsError>>
     {
         let params = documents_api::DeleteDocumentsParams {
-            collection_name: self.collection_name.into(),
+            collection_name: self.collection_name.as_ref().into(),
             filter_by: Some(params.filter_by),
             batch_size: params.batch_size,
             ignore_not_found: params.ignore_not_found,
             truncate: params.truncate,
         };
-        execute_wrapper!(self, documents_api::delete_documents, params)
+        execute_wrapper!(self, write documents_api::delete_documents, params)
     }
 
     /// Searches for documents in the collection that match the given criteria.
@@ -139,7 +300,7 @@ where
         params: raw_models::SearchParameters<'_>,
     ) -> Result<SearchResult<D>, Error<documents_api::SearchCollectionError>> {
         let search_params = documents_api::SearchCollectionParams {
-            collection_name: self.collection_name.into(),
+            collection_name: self.collection_name.as_ref().into(),
 
             // Map all corresponding fields directly.
             cache_ttl: params.cache_ttl,
@@ -214,7 +375,122 @@ where
             enable_analytics: params.enable_analytics,
             synonym_sets: params.synonym_sets,
         };
-        execute_wrapper!(self, documents_api::search_collection, search_params)
+        // Conversational searches add to the history of the conversation, so they are never
+        // sent twice.
+        if search_params.conversation == Some(true) {
+            execute_wrapper!(self, write documents_api::search_collection, search_params)
+        } else {
+            execute_wrapper!(self, read documents_api::search_collection, search_params)
+        }
+    }
+
+    /// Walks through all pages of a search, yielding one `SearchResult` per page.
+    ///
+    /// Pages are requested lazily, one at a time, starting at `params.page` (or the first page).
+    /// `per_page` defaults to and is capped at 250, the maximum accepted by Typesense. The stream
+    /// ends after the page that reaches `found`, or after a page that is not full. `offset` and
+    /// `limit` are ignored; use [`StreamExt::take`] to stop early.
+    ///
+    /// Every page is fetched with [`search`](Self::search), so each request goes through the
+    /// client's node failover. The stream yields the error and ends if a page cannot be fetched.
+    ///
+    /// # Arguments
+    /// * `params` - A `SearchParameters` struct containing all search parameters.
+    pub fn search_pages<'p>(
+        &self,
+        params: raw_models::SearchParameters<'p>,
+    ) -> impl Stream<Item = Result<SearchResult<D>, Error<documents_api::SearchCollectionError>>>
+    + use<'d, 'p, D> {
+        let (client, collection_name) = (self.client.clone(), self.collection_name.clone());
+        let per_page = params
+            .per_page
+            .map_or(MAX_PER_PAGE, |per_page| per_page.clamp(1, MAX_PER_PAGE));
+        let first_page = params.page.unwrap_or(1).max(1);
+
+        stream::try_unfold(Some(first_page), move |page| {
+            let (client, collection_name, params) =
+                (client.clone(), collection_name.clone(), params.clone());
+            async move {
+                let Some(page) = page else {
+                    return Ok(None);
+                };
+                let result = Documents::<D>::new(client, collection_name)
+                    .search(raw_models::SearchParameters {
+                        page: Some(page),
+                        per_page: Some(per_page),
+                        offset: None,
+                        limit: None,
+                        ..params
+                    })
+                    .await?;
+
+                // `found` counts groups instead of hits when `group_by` is set.
+                let returned = match (&result.grouped_hits, &result.hits) {
+                    (Some(groups), _) => groups.len(),
+                    (None, Some(hits)) => hits.len(),
+                    (None, None) => 0,
+                };
+                let found = i64::from(result.found.unwrap_or(0));
+                let is_last =
+                    returned < per_page as usize || i64::from(page) * i64::from(per_page) >= found;
+
+                Ok(Some((result, (!is_last).then_some(page + 1))))
+            }
+        })
+    }
+
+    /// Walks through all pages of a search, yielding every hit.
+    ///
+    /// This is [`search_pages`](Self::search_pages) flattened into individual hits.
+    /// For searches with `group_by`, use [`search_grouped_stream`](Self::search_grouped_stream).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use typesense::{Client, models::SearchParameters};
+    /// use futures::TryStreamExt;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let params = SearchParameters::builder()
+    ///     .q("*")
+    ///     .filter_by("in_stock:true")
+    ///     .build();
+    ///
+    /// let products = client.collection_schemaless("products");
+    /// let mut hits = std::pin::pin!(products.documents().search_stream(params));
+    /// while let Some(hit) = hits.try_next().await? {
+    ///     println!("{:?}", hit.document);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub fn search_stream<'p>(
+        &self,
+        params: raw_models::SearchParameters<'p>,
+    ) -> impl Stream<Item = Result<SearchResultHit<D>, Error<documents_api::SearchCollectionError>>>
+    + use<'d, 'p, D> {
+        self.search_pages(params)
+            .map_ok(|page| stream::iter(page.hits.unwrap_or_default()).map(Ok))
+            .try_flatten()
+    }
+
+    /// Walks through all pages of a search with `group_by`, yielding every group of hits.
+    ///
+    /// This is [`search_pages`](Self::search_pages) flattened into individual groups.
+    pub fn search_grouped_stream<'p>(
+        &self,
+        params: raw_models::SearchParameters<'p>,
+    ) -> impl Stream<Item = Result<SearchGroupedHit<D>, Error<documents_api::SearchCollectionError>>>
+    + use<'d, 'p, D> {
+        self.search_pages(params)
+            .map_ok(|page| stream::iter(page.grouped_hits.unwrap_or_default()).map(Ok))
+            .try_flatten()
     }
 }
 
@@ -241,6 +517,57 @@ where
         serde_json::from_value(result_value).map_err(Error::from)
     }
 
+    /// Imports a batch of documents and parses the result of every document.
+    ///
+    /// Typesense imports are not atomic: the request succeeds even if some documents are
+    /// rejected. The returned results are in the same order as `documents`; use
+    /// [`ImportResultsExt::ensure_all_succeeded`](crate::prelude::ImportResultsExt::ensure_all_succeeded)
+    /// to turn rejected documents into an error.
+    ///
+    /// # Arguments
+    /// * `documents` - The documents to import.
+    /// * `params` - An `ImportDocumentsParameters` struct containing options like `action`,
+    ///   `return_id` and `return_doc`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use typesense::{Client, Typesense, models::{ImportDocumentsParameters, IndexAction}, prelude::*};
+    /// # use serde::{Serialize, Deserialize};
+    /// #
+    /// # #[derive(Typesense, Serialize, Deserialize, Debug)]
+    /// # struct Book { id: String, title: String }
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let books = vec![Book { id: "1".to_owned(), title: "Dune".to_owned() }];
+    /// let params = ImportDocumentsParameters {
+    ///     action: Some(IndexAction::Upsert),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// client
+    ///     .collection::<Book>()
+    ///     .documents()
+    ///     .import(&books, params)
+    ///     .await?
+    ///     .ensure_all_succeeded()?;
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub async fn import(
+        &self,
+        documents: &[D],
+        params: ImportDocumentsParameters,
+    ) -> Result<Vec<ImportResult<D>>, Error<documents_api::ImportDocumentsError>> {
+        self.import_documents(documents, params).await
+    }
+
     /// Creates a new document or updates an existing one if an ID match is found.
     ///
     /// This method requires the full document to be sent. For partial updates, use
@@ -271,10 +598,10 @@ where
     ) -> Result<raw_models::UpdateDocuments200Response, Error<documents_api::UpdateDocumentsError>>
     {
         let params = documents_api::UpdateDocumentsParams {
-            collection_name: self.collection_name.into(),
+            collection_name: self.collection_name.as_ref().into(),
             filter_by: params.filter_by,
             body: document,
         };
-        execute_wrapper!(self, documents_api::update_documents, params)
+        execute_wrapper!(self, write documents_api::update_documents, params)
     }
 }
@@ -0,0 +1,160 @@
+//! Helpers for streaming JSONL response bodies.
+
+use crate::{ApiError, Error};
+use futures::{Stream, StreamExt, stream};
+use typesense_codegen::apis::{ResponseContent, configuration, documents_api, urlencode};
+
+/// Sends an export request and returns the response once its status has been checked,
+/// without reading the body.
+///
+/// This mirrors `documents_api::export_documents`, which buffers the whole body into a `String`.
+pub(super) async fn export_documents(
+    configuration: &configuration::Configuration,
+    params: &documents_api::ExportDocumentsParams<'_>,
+) -> Result<reqwest::Response, ApiError<documents_api::ExportDocumentsError>> {
+    let uri_str = format!(
+        "{}/collections/{}/documents/export",
+        configuration.base_path,
+        urlencode(&params.collection_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = params.filter_by {
+        req_builder = req_builder.query(&[("filter_by", param_value)]);
+    }
+    if let Some(ref param_value) = params.include_fields {
+        req_builder = req_builder.query(&[("include_fields", param_value)]);
+    }
+    if let Some(ref param_value) = params.exclude_fields {
+        req_builder = req_builder.query(&[("exclude_fields", param_value)]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = &apikey.key;
+        let value = match apikey.prefix {
+            Some(ref prefix) => &format!("{prefix} {key}"),
+            None => key,
+        };
+        req_builder = req_builder.header("X-TYPESENSE-API-KEY", value);
+    };
+
+    let resp = req_builder.send().await?;
+
+    let status = resp.status();
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(resp)
+    } else {
+        let content = resp.text().await?;
+        let entity = serde_json::from_str(&content).ok();
+        Err(ApiError::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Splits a response body into its non-empty lines, as they arrive.
+///
+/// A network error while reading the body is yielded once and ends the stream.
+pub(super) fn lines<E>(response: reqwest::Response) -> impl Stream<Item = Result<Vec<u8>, Error<E>>>
+where
+    E: std::fmt::Debug + 'static,
+    ApiError<E>: std::error::Error + 'static,
+{
+    let node = node_url(&response);
+    let body = Box::pin(response.bytes_stream());
+
+    stream::try_unfold(
+        (body, LineBuffer::default(), false, node),
+        |(mut body, mut buffer, mut eof, node)| async move {
+            loop {
+                let line = match buffer.next_line(eof) {
+                    Some(line) => line,
+                    None => {
+                        match body.next().await {
+                            Some(chunk) => buffer.extend(&chunk.map_err(|e| body_error(e, &node))?),
+                            None => eof = true,
+                        }
+                        continue;
+                    }
+                };
+
+                if !line.iter().all(u8::is_ascii_whitespace) {
+                    return Ok(Some((line, (body, buffer, eof, node))));
+                }
+                if eof && buffer.is_empty() {
+                    return Ok(None);
+                }
+            }
+        },
+    )
+}
+
+/// The bytes of a body that have been received but not yet split into lines.
+///
+/// Lines are taken from the front without shifting the rest, and each byte is scanned for
+/// a newline only once, so splitting a body is linear in its size however it is chunked.
+#[derive(Default)]
+struct LineBuffer {
+    bytes: Vec<u8>,
+    /// Start of the first line that has not been taken.
+    start: usize,
+    /// Position up to which `bytes` is known not to contain a newline.
+    scanned: usize,
+}
+
+impl LineBuffer {
+    fn is_empty(&self) -> bool {
+        self.start == self.bytes.len()
+    }
+
+    /// Appends a chunk of the body, dropping the lines that have already been taken.
+    fn extend(&mut self, chunk: &[u8]) {
+        self.bytes.drain(..self.start);
+        self.scanned -= self.start;
+        self.start = 0;
+        self.bytes.extend_from_slice(chunk);
+    }
+
+    /// Takes the next complete line without its line ending, or what is left of the body
+    /// once it has been fully received.
+    fn next_line(&mut self, eof: bool) -> Option<Vec<u8>> {
+        let end = match self.bytes[self.scanned..].iter().position(|&b| b == b'\n') {
+            Some(offset) => self.scanned + offset,
+            None if eof => self.bytes.len(),
+            None => {
+                self.scanned = self.bytes.len();
+                return None;
+            }
+        };
+
+        let mut line = &self.bytes[self.start..end];
+        if let Some(stripped) = line.strip_suffix(b"\r") {
+            line = stripped;
+        }
+        let line = line.to_vec();
+        self.start = (end + 1).min(self.bytes.len());
+        self.scanned = self.start;
+        Some(line)
+    }
+}
+
+/// Returns the URL of the node that sent `response`.
+pub(super) fn node_url(response: &reqwest::Response) -> String {
+    response.url().origin().ascii_serialization()
+}
+
+/// Wraps a network error that occurred while reading the body of a response from `node`.
+pub(super) fn body_error<E>(error: reqwest::Error, node: &str) -> Error<E>
+where
+    E: std::fmt::Debug + 'static,
+    ApiError<E>: std::error::Error + 'static,
+{
+    Error::Api {
+        source: ApiError::Reqwest(error),
+        node: Some(node.to_owned()),
+    }
+}
@@ -2,10 +2,12 @@
 //!
 //! A `Collections` instance is created via the main `client.collections()` method.
 
-mod document;
-mod documents;
+mod bulk_import;
+pub(super) mod document;
+pub(super) mod documents;
+mod jsonl;
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use ::std::borrow::Cow;
 use serde::{Serialize, de::DeserializeOwned};
 use typesense_codegen::{apis::collections_api, models};
@@ -17,38 +19,65 @@ pub struct Collection<'c, D = serde_json::Value>
 where
     D: DeserializeOwned + Serialize,
 {
-    client: &'c Client,
+    client: ClientRef<'c>,
     collection_name: Cow<'c, str>,
     _phantom: core::marker::PhantomData<D>,
 }
 
+// Implemented by hand, so that `D` does not have to be `Clone`.
+impl<D> Clone for Collection<'_, D>
+where
+    D: DeserializeOwned + Serialize,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            collection_name: self.collection_name.clone(),
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
 impl<'c, D> Collection<'c, D>
 where
     D: DeserializeOwned + Serialize,
 {
     /// Creates a new `Collection` instance.
     #[inline]
-    pub(super) fn new(client: &'c Client, collection_name: impl Into<Cow<'c, str>>) -> Self {
+    pub(super) fn new(client: ClientRef<'c>, collection_name: impl Into<Cow<'c, str>>) -> Self {
+        let collection_name = collection_name.into();
         Self {
-            client,
-            collection_name: collection_name.into(),
+            client: client.with_collection(&collection_name),
+            collection_name,
             _phantom: core::marker::PhantomData,
         }
     }
 
+    with_options!();
+
     /// Provides access to the document-related API endpoints for a specific collection.
+    ///
+    /// The returned handle does not borrow the collection, so it is `'static` when the
+    /// collection is.
     #[inline]
-    pub fn documents<'d>(&'d self) -> documents::Documents<'d, D> {
-        documents::Documents::new(self.client, &self.collection_name)
+    pub fn documents(&self) -> documents::Documents<'c, D> {
+        documents::Documents::new(self.client.clone(), self.collection_name.clone())
     }
 
     /// Provides access to the API endpoints for a single document within a Typesense collection.
+    ///
+    /// The returned handle does not borrow the collection, so it is `'static` when the
+    /// collection is and `document_id` is owned or `'static`.
     #[inline]
-    pub fn document<'d>(
-        &'d self,
-        document_id: impl Into<Cow<'d, str>>,
-    ) -> document::Document<'d, D> {
-        document::Document::new(self.client, &self.collection_name, document_id)
+    pub fn document<'d>(&self, document_id: impl Into<Cow<'d, str>>) -> document::Document<'d, D>
+    where
+        'c: 'd,
+    {
+        document::Document::new(
+            self.client.clone(),
+            self.collection_name.clone(),
+            document_id,
+        )
     }
 
     /// Retrieves the details of a collection, given its name.
@@ -59,7 +88,7 @@ where
         let params = collections_api::GetCollectionParams {
             collection_name: self.collection_name.as_ref().into(),
         };
-        execute_wrapper!(self, collections_api::get_collection, params)
+        execute_wrapper!(self, read collections_api::get_collection, params)
     }
 
     /// Permanently drops a collection.
@@ -70,7 +99,7 @@ where
         let params = collections_api::DeleteCollectionParams {
             collection_name: self.collection_name.as_ref().into(),
         };
-        execute_wrapper!(self, collections_api::delete_collection, params)
+        execute_wrapper!(self, write collections_api::delete_collection, params)
     }
 
     /// Updates a collection's schema to modify the fields and their types.
@@ -86,6 +115,6 @@ where
             collection_name: self.collection_name.as_ref().into(),
             collection_update_schema: update_schema,
         };
-        execute_wrapper!(self, collections_api::update_collection, params)
+        execute_wrapper!(self, write collections_api::update_collection, params)
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! A `Collections` instance is created via the main `client.collections()` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use typesense_codegen::{
     apis::collections_api::{self, GetCollectionsParams},
     models::{self, GetCollectionsParameters},
@@ -12,16 +12,18 @@ use typesense_codegen::{
 ///
 /// This struct is created by calling `client.collections()`.
 pub struct Collections<'c> {
-    pub(super) client: &'c Client,
+    pub(super) client: ClientRef<'c>,
 }
 
 impl<'c> Collections<'c> {
     /// Creates a new `Collection` instance
     #[inline]
-    pub(super) fn new(client: &'c Client) -> Self {
+    pub(super) fn new(client: ClientRef<'c>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Creates a new collection with the given schema.
     ///
     /// # Arguments
@@ -33,7 +35,7 @@ impl<'c> Collections<'c> {
         let params = collections_api::CreateCollectionParams {
             collection_schema: schema,
         };
-        execute_wrapper!(self, collections_api::create_collection, params)
+        execute_wrapper!(self, write collections_api::create_collection, params)
     }
 
     /// List the existing Typesense collections.
@@ -46,6 +48,6 @@ impl<'c> Collections<'c> {
             limit: params.limit,
             offset: params.offset,
         };
-        execute_wrapper!(self, collections_api::get_collections, params)
+        execute_wrapper!(self, read collections_api::get_collections, params)
     }
 }
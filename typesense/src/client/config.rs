@@ -0,0 +1,369 @@
+//! Client configuration from configuration files, environment variables and connection URLs.
+
+use super::{Client, ExponentialBackoff, NodeConfig};
+use crate::ConfigError;
+use ::std::{env, fmt, str::FromStr};
+use serde::Deserialize;
+use web_time::Duration;
+
+/// The configuration of a [`Client`], which can be deserialized from a configuration file,
+/// read from environment variables, or parsed from a connection URL.
+///
+/// Durations are given as a number of seconds, or as a string with a unit: `ms`, `s`, `m` or
+/// `h`, e.g. `"30s"` or `"500ms"`.
+///
+/// # Example
+///
+/// ```
+/// use typesense::ClientConfig;
+///
+/// let config: ClientConfig = serde_json::from_str(r#"{
+///     "nodes": ["http://node-1:8108", "http://node-2:8108"],
+///     "api_key": "xyz",
+///     "healthcheck_interval": "30s",
+///     "max_retries": 2
+/// }"#).unwrap();
+///
+/// let url: ClientConfig = "http://xyz@node-1,node-2:8108?healthcheck=30s&max_retries=2"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(config, url);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    /// The URLs of the nodes of the cluster.
+    pub nodes: Vec<String>,
+    /// The URL of the node tried first for every request, see `Client::builder().nearest_node`.
+    #[serde(default)]
+    pub nearest_node: Option<String>,
+    /// The Typesense API key.
+    pub api_key: String,
+    /// The duration after which an unhealthy node is retried, 60 seconds by default.
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub healthcheck_interval: Option<Duration>,
+    /// The maximum number of retries of a request on a single node, 3 by default.
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+    /// The timeout for connecting to a node, 5 seconds by default. Native targets only.
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub connection_timeout: Option<Duration>,
+    /// The timeout of each HTTP request, including reading the response body. Native targets
+    /// only.
+    #[serde(default, deserialize_with = "deserialize_duration")]
+    pub timeout: Option<Duration>,
+}
+
+impl fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("nodes", &self.nodes)
+            .field("nearest_node", &self.nearest_node)
+            .field("api_key", &"[REDACTED]")
+            .field("healthcheck_interval", &self.healthcheck_interval)
+            .field("max_retries", &self.max_retries)
+            .field("connection_timeout", &self.connection_timeout)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl ClientConfig {
+    /// Reads the configuration from environment variables.
+    ///
+    /// - `TYPESENSE_API_KEY`: the API key (required).
+    /// - `TYPESENSE_HOST`: the hosts of the nodes, separated by commas (required). A host can
+    ///   have its own port, e.g. `localhost:8108`, or be a full URL, e.g.
+    ///   `https://node-1.example.com:443`.
+    /// - `TYPESENSE_PORT`: the port of the hosts without one, 8108 by default.
+    /// - `TYPESENSE_PROTOCOL`: `http` (the default) or `https`.
+    /// - `TYPESENSE_NEAREST_NODE`: the URL of the nearest node.
+    /// - `TYPESENSE_HEALTHCHECK_INTERVAL`, `TYPESENSE_MAX_RETRIES`,
+    ///   `TYPESENSE_CONNECTION_TIMEOUT` and `TYPESENSE_TIMEOUT`: the other fields.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let var = |name: &'static str| env::var(name).ok().filter(|value| !value.is_empty());
+        let required = |name: &'static str| var(name).ok_or(ConfigError::MissingVariable(name));
+        let protocol = var("TYPESENSE_PROTOCOL").unwrap_or_else(|| "http".to_owned());
+        let port = var("TYPESENSE_PORT").unwrap_or_else(|| "8108".to_owned());
+        let nodes = required("TYPESENSE_HOST")?
+            .split(',')
+            .map(str::trim)
+            .filter(|host| !host.is_empty())
+            .map(|host| match (host.contains("://"), split_port(host)) {
+                (true, _) => host.to_owned(),
+                (false, (_, Some(_))) => format!("{protocol}://{host}"),
+                (false, (_, None)) => format!("{protocol}://{host}:{port}"),
+            })
+            .collect();
+
+        Ok(Self {
+            nodes,
+            nearest_node: var("TYPESENSE_NEAREST_NODE"),
+            api_key: required("TYPESENSE_API_KEY")?,
+            healthcheck_interval: parse_var("TYPESENSE_HEALTHCHECK_INTERVAL", var, parse_duration)?,
+            max_retries: parse_var("TYPESENSE_MAX_RETRIES", var, |value| value.parse().ok())?,
+            connection_timeout: parse_var("TYPESENSE_CONNECTION_TIMEOUT", var, parse_duration)?,
+            timeout: parse_var("TYPESENSE_TIMEOUT", var, parse_duration)?,
+        })
+    }
+
+    /// Parses a connection URL of the form
+    /// `http[s]://api_key@host1[:port],host2[:port][/path][?parameters]`.
+    ///
+    /// A host without a port uses the port of the last host, so that `host1,host2:8108` puts
+    /// both nodes on port 8108. IPv6 hosts are written in brackets, e.g. `[::1]:8108`. The API
+    /// key is percent-decoded. The parameters are `nearest` (the URL or host of the nearest
+    /// node), `healthcheck`, `max_retries`, `connection_timeout` and `timeout`.
+    pub fn from_url(url: &str) -> Result<Self, ConfigError> {
+        let invalid = |reason: String| ConfigError::InvalidUrl(reason);
+        let (scheme, rest) = url
+            .split_once("://")
+            .ok_or_else(|| invalid("the scheme is missing".to_owned()))?;
+        if scheme != "http" && scheme != "https" {
+            return Err(invalid(format!("unsupported scheme `{scheme}`")));
+        }
+        let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let path = path.trim_end_matches('/');
+        let (api_key, hosts) = match authority.rsplit_once('@') {
+            Some((api_key, hosts)) => (Some(percent_decode(api_key)?), hosts),
+            None => (None, authority),
+        };
+
+        let hosts = hosts
+            .split(',')
+            .map(|host| match split_port(host) {
+                (name, Some(port)) => match port.parse::<u16>() {
+                    Ok(port) => Ok((name, Some(port))),
+                    Err(_) => Err(invalid(format!("invalid port in `{host}`"))),
+                },
+                (name, None) => Ok((name, None)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if hosts.iter().any(|(name, _)| name.is_empty()) {
+            return Err(invalid("a host is empty".to_owned()));
+        }
+        let last_port = hosts.iter().rev().find_map(|(_, port)| *port);
+        let node_url = |name: &str, port: Option<u16>| match port.or(last_port) {
+            Some(port) => format!("{scheme}://{name}:{port}{path}"),
+            None => format!("{scheme}://{name}{path}"),
+        };
+
+        let mut config = Self {
+            nodes: hosts
+                .iter()
+                .map(|(name, port)| node_url(name, *port))
+                .collect(),
+            api_key: api_key.unwrap_or_default(),
+            ..Self::default()
+        };
+        for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+            let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+            let value = percent_decode(value)?;
+            let invalid_value = || invalid(format!("invalid value `{value}` for `{name}`"));
+            match name {
+                "nearest" if value.contains("://") => config.nearest_node = Some(value),
+                "nearest" => config.nearest_node = Some(format!("{scheme}://{value}{path}")),
+                "healthcheck" => {
+                    config.healthcheck_interval =
+                        Some(parse_duration(&value).ok_or_else(invalid_value)?);
+                }
+                "max_retries" => {
+                    config.max_retries = Some(value.parse().map_err(|_| invalid_value())?);
+                }
+                "connection_timeout" => {
+                    config.connection_timeout =
+                        Some(parse_duration(&value).ok_or_else(invalid_value)?);
+                }
+                "timeout" => {
+                    config.timeout = Some(parse_duration(&value).ok_or_else(invalid_value)?);
+                }
+                _ => return Err(invalid(format!("unknown parameter `{name}`"))),
+            }
+        }
+        if config.api_key.is_empty() {
+            return Err(invalid("the API key is missing".to_owned()));
+        }
+        Ok(config)
+    }
+}
+
+impl FromStr for ClientConfig {
+    type Err = ConfigError;
+
+    /// Parses a connection URL, see [`ClientConfig::from_url`].
+    fn from_str(url: &str) -> Result<Self, Self::Err> {
+        Self::from_url(url)
+    }
+}
+
+impl Client {
+    /// Creates a client from a [`ClientConfig`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// use typesense::{Client, ClientConfig};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct AppConfig {
+    ///     typesense: ClientConfig,
+    /// }
+    ///
+    /// # fn run(file: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// let config: AppConfig = serde_json::from_str(file)?;
+    /// let client = Client::from_config(config.typesense)?;
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub fn from_config(config: ClientConfig) -> Result<Self, ConfigError> {
+        let ClientConfig {
+            nodes,
+            nearest_node,
+            api_key,
+            healthcheck_interval,
+            max_retries,
+            connection_timeout,
+            timeout,
+        } = config;
+        let node = |url: String| {
+            let node = NodeConfig::new(url);
+            #[cfg(not(target_arch = "wasm32"))]
+            if connection_timeout.is_some() || timeout.is_some() {
+                return node.http_builder(move |builder| {
+                    let builder = builder
+                        .connect_timeout(connection_timeout.unwrap_or(Duration::from_secs(5)));
+                    match timeout {
+                        Some(timeout) => builder.timeout(timeout),
+                        None => builder,
+                    }
+                });
+            }
+            #[cfg(target_arch = "wasm32")]
+            let _ = (connection_timeout, timeout);
+            node
+        };
+
+        Client::builder()
+            .nodes(nodes.into_iter().map(node))
+            .maybe_nearest_node(nearest_node.map(node))
+            .api_key(api_key)
+            .maybe_healthcheck_interval(healthcheck_interval)
+            .maybe_retry_policy(max_retries.map(|max_retries| {
+                ExponentialBackoff::builder().build_with_max_retries(max_retries)
+            }))
+            .build()
+            .map_err(ConfigError::Client)
+    }
+
+    /// Creates a client from environment variables, see [`ClientConfig::from_env`].
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_config(ClientConfig::from_env()?)
+    }
+
+    /// Creates a client from a connection URL, see [`ClientConfig::from_url`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// use typesense::Client;
+    ///
+    /// let client =
+    ///     Client::from_url("https://xyz@node-1,node-2:443?nearest=node-lb:443&healthcheck=30s")
+    ///         .unwrap();
+    /// # }
+    /// ```
+    pub fn from_url(url: &str) -> Result<Self, ConfigError> {
+        Self::from_config(ClientConfig::from_url(url)?)
+    }
+}
+
+/// Parses the environment variable `name` with `parse`, if it is set.
+fn parse_var<T>(
+    name: &'static str,
+    var: impl Fn(&'static str) -> Option<String>,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Option<T>, ConfigError> {
+    var(name)
+        .map(|value| parse(&value).ok_or(ConfigError::InvalidVariable { name, value }))
+        .transpose()
+}
+
+/// Splits `host[:port]` into the host and its port, if any. An IPv6 host is written in brackets,
+/// e.g. `[::1]:8108`, and keeps them.
+fn split_port(host: &str) -> (&str, Option<&str>) {
+    let name_end = match host.starts_with('[') {
+        true => host.find(']').map_or(host.len(), |end| end + 1),
+        false => 0,
+    };
+    match host[name_end..].rfind(':') {
+        Some(colon) => (
+            &host[..name_end + colon],
+            Some(&host[name_end + colon + 1..]),
+        ),
+        None => (host, None),
+    }
+}
+
+/// Parses a duration given in seconds, or with a unit: `ms`, `s`, `m` or `h`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let seconds = match unit.trim() {
+        "" | "s" => number,
+        "ms" => number / 1000.0,
+        "m" | "min" => number * 60.0,
+        "h" => number * 3600.0,
+        _ => return None,
+    };
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Decodes the `%XX` escapes of a URL component.
+fn percent_decode(value: &str) -> Result<String, ConfigError> {
+    let invalid = || ConfigError::InvalidUrl(format!("invalid percent-encoding in `{value}`"));
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = tail.get(..2).ok_or_else(invalid)?;
+            let hex = ::std::str::from_utf8(hex).map_err(|_| invalid())?;
+            bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+/// Deserializes an optional duration, given in seconds or as a string with a unit.
+fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Seconds(u64),
+        Text(String),
+    }
+
+    match Option::<Value>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Value::Seconds(seconds)) => Ok(Some(Duration::from_secs(seconds))),
+        Some(Value::Text(text)) => parse_duration(&text).map(Some).ok_or_else(|| {
+            serde::de::Error::custom(format!("invalid duration `{text}`, expected e.g. `30s`"))
+        }),
+    }
+}
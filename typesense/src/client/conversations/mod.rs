@@ -2,7 +2,8 @@
 //!
 //! An `Conversations` instance is created via the main `client.conversations()` method.
 
-use super::Client;
+use super::ClientRef;
+use ::std::borrow::Cow;
 use model::Model;
 use models::Models;
 
@@ -13,22 +14,24 @@ mod models;
 ///
 /// This struct is created by calling `client.conversations()`.
 pub struct Conversations<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Conversations<'a> {
     /// Creates a new `Conversations` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Provides access to endpoints for managing the collection of conversation models.
     ///
     /// Example: `client.conversations().models().list().await`
     #[inline]
     pub fn models(&self) -> Models<'a> {
-        Models::new(self.client)
+        Models::new(self.client.clone())
     }
 
     /// Provides access to endpoints for managing a single conversation model.
@@ -38,7 +41,7 @@ impl<'a> Conversations<'a> {
     ///
     /// Example: `client.conversations().model("...").get().await`
     #[inline]
-    pub fn model(&self, model_id: &'a str) -> Model<'a> {
-        Model::new(self.client, model_id)
+    pub fn model(&self, model_id: impl Into<Cow<'a, str>>) -> Model<'a> {
+        Model::new(self.client.clone(), model_id)
     }
 }
@@ -2,24 +2,30 @@
 //!
 //! An instance of `Model` is created via the `client.conversations().model("model_id")` method.
 
-use crate::{Client, Error, execute_wrapper, models};
+use crate::{Error, client::ClientRef, execute_wrapper, models};
+use ::std::borrow::Cow;
 use typesense_codegen::apis::conversations_api;
 
 /// Provides methods for interacting with a specific conversation model.
 ///
 /// This struct is created by calling `client.conversations().model("model_id")`.
 pub struct Model<'a> {
-    pub(super) client: &'a Client,
-    pub(super) model_id: &'a str,
+    pub(super) client: ClientRef<'a>,
+    pub(super) model_id: Cow<'a, str>,
 }
 
 impl<'a> Model<'a> {
     /// Creates a new `Model` instance for a specific model ID.
     #[inline]
-    pub(super) fn new(client: &'a Client, model_id: &'a str) -> Self {
-        Self { client, model_id }
+    pub(super) fn new(client: ClientRef<'a>, model_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            client,
+            model_id: model_id.into(),
+        }
     }
 
+    with_options!();
+
     /// Retrieves the details of this specific conversation model.
     pub async fn retrieve(
         &self,
@@ -28,9 +34,9 @@ impl<'a> Model<'a> {
         Error<conversations_api::RetrieveConversationModelError>,
     > {
         let params = conversations_api::RetrieveConversationModelParams {
-            model_id: self.model_id.into(),
+            model_id: self.model_id.as_ref().into(),
         };
-        execute_wrapper!(self, conversations_api::retrieve_conversation_model, params)
+        execute_wrapper!(self, read conversations_api::retrieve_conversation_model, params)
     }
 
     /// Updates this specific conversation model.
@@ -45,10 +51,10 @@ impl<'a> Model<'a> {
         Error<conversations_api::UpdateConversationModelError>,
     > {
         let params = conversations_api::UpdateConversationModelParams {
-            model_id: self.model_id.into(),
+            model_id: self.model_id.as_ref().into(),
             conversation_model_update_schema: schema,
         };
-        execute_wrapper!(self, conversations_api::update_conversation_model, params)
+        execute_wrapper!(self, write conversations_api::update_conversation_model, params)
     }
 
     /// Deletes this specific conversation model.
@@ -59,8 +65,8 @@ impl<'a> Model<'a> {
         Error<conversations_api::DeleteConversationModelError>,
     > {
         let params = conversations_api::DeleteConversationModelParams {
-            model_id: self.model_id.into(),
+            model_id: self.model_id.as_ref().into(),
         };
-        execute_wrapper!(self, conversations_api::delete_conversation_model, params)
+        execute_wrapper!(self, write conversations_api::delete_conversation_model, params)
     }
 }
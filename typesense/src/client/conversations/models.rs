@@ -2,23 +2,25 @@
 //!
 //! An instance of `Models` is created via the `client.conversations().models()` method.
 
-use crate::{Client, Error, execute_wrapper, models};
+use crate::{Error, client::ClientRef, execute_wrapper, models};
 use typesense_codegen::apis::conversations_api;
 
 /// Provides methods for creating and listing conversation models.
 ///
 /// This struct is created by calling `client.conversations().models()`.
 pub struct Models<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Models<'a> {
     /// Creates a new `Models` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Creates a new conversation model.
     ///
     /// # Arguments
@@ -33,7 +35,7 @@ impl<'a> Models<'a> {
         let params = conversations_api::CreateConversationModelParams {
             conversation_model_create_schema: schema,
         };
-        execute_wrapper!(self, conversations_api::create_conversation_model, params)
+        execute_wrapper!(self, write conversations_api::create_conversation_model, params)
     }
 
     /// Retrieves a summary of all conversation models.
@@ -43,6 +45,6 @@ impl<'a> Models<'a> {
         Vec<models::ConversationModelSchema>,
         Error<conversations_api::RetrieveAllConversationModelsError>,
     > {
-        execute_wrapper!(self, conversations_api::retrieve_all_conversation_models)
+        execute_wrapper!(self, read conversations_api::retrieve_all_conversation_models)
     }
 }
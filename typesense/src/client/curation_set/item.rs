@@ -3,7 +3,8 @@
 //! Curation sets allow you to include or exclude specific documents for a given query.
 //!
 //! A `CurationSetItem` instance is created via the main `client.curation_set("curation_set_name").item("item_id")` method.
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
+use ::std::borrow::Cow;
 use typesense_codegen::{
     apis::curation_sets_api::{self, RetrieveCurationSetItemParams},
     models,
@@ -13,32 +14,38 @@ use typesense_codegen::{
 ///
 /// This struct is created by calling `client.curation_set("curation_set_name").item("item_id")`.
 pub struct CurationSetItem<'a> {
-    pub(super) client: &'a Client,
-    pub(super) curation_set_name: &'a str,
-    pub(super) item_id: &'a str,
+    pub(super) client: ClientRef<'a>,
+    pub(super) curation_set_name: Cow<'a, str>,
+    pub(super) item_id: Cow<'a, str>,
 }
 
 impl<'a> CurationSetItem<'a> {
     /// Creates a new `CurationSetItem` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client, curation_set_name: &'a str, item_id: &'a str) -> Self {
+    pub(super) fn new(
+        client: ClientRef<'a>,
+        curation_set_name: Cow<'a, str>,
+        item_id: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
             client,
             curation_set_name,
-            item_id,
+            item_id: item_id.into(),
         }
     }
 
+    with_options!();
+
     /// Retrieve this curation set item.
     pub async fn retrieve(
         &self,
     ) -> Result<models::CurationItemSchema, Error<curation_sets_api::RetrieveCurationSetItemError>>
     {
         let params = RetrieveCurationSetItemParams {
-            curation_set_name: self.curation_set_name.into(),
-            item_id: self.item_id.into(),
+            curation_set_name: self.curation_set_name.as_ref().into(),
+            item_id: self.item_id.as_ref().into(),
         };
-        execute_wrapper!(self, curation_sets_api::retrieve_curation_set_item, params)
+        execute_wrapper!(self, read curation_sets_api::retrieve_curation_set_item, params)
     }
 
     /// Delete this curation set item.
@@ -49,9 +56,9 @@ impl<'a> CurationSetItem<'a> {
         Error<curation_sets_api::DeleteCurationSetItemError>,
     > {
         let params = curation_sets_api::DeleteCurationSetItemParams {
-            curation_set_name: self.curation_set_name.into(),
-            item_id: self.item_id.into(),
+            curation_set_name: self.curation_set_name.as_ref().into(),
+            item_id: self.item_id.as_ref().into(),
         };
-        execute_wrapper!(self, curation_sets_api::delete_curation_set_item, params)
+        execute_wrapper!(self, write curation_sets_api::delete_curation_set_item, params)
     }
 }
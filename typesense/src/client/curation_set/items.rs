@@ -6,7 +6,7 @@
 
 use ::std::borrow::Cow;
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use typesense_codegen::{
     apis::curation_sets_api::{self, RetrieveCurationSetItemsParams},
     models,
@@ -16,20 +16,22 @@ use typesense_codegen::{
 ///
 /// This struct is created by calling `client.curation_set("curation_set_name").items()`.
 pub struct CurationSetItems<'a> {
-    pub(super) client: &'a Client,
-    pub(super) curation_set_name: &'a str,
+    pub(super) client: ClientRef<'a>,
+    pub(super) curation_set_name: Cow<'a, str>,
 }
 
 impl<'a> CurationSetItems<'a> {
     /// Creates a new `CurationSetItems` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client, curation_set_name: &'a str) -> Self {
+    pub(super) fn new(client: ClientRef<'a>, curation_set_name: Cow<'a, str>) -> Self {
         Self {
             client,
             curation_set_name,
         }
     }
 
+    with_options!();
+
     /// Retrieves all the items of this curation set.
     pub async fn retrieve(
         &self,
@@ -38,9 +40,9 @@ impl<'a> CurationSetItems<'a> {
         Error<curation_sets_api::RetrieveCurationSetItemsError>,
     > {
         let params = RetrieveCurationSetItemsParams {
-            curation_set_name: self.curation_set_name.into(),
+            curation_set_name: self.curation_set_name.as_ref().into(),
         };
-        execute_wrapper!(self, curation_sets_api::retrieve_curation_set_items, params)
+        execute_wrapper!(self, read curation_sets_api::retrieve_curation_set_items, params)
     }
 
     /// Creates or updates an existing item of a curation set.
@@ -56,9 +58,9 @@ impl<'a> CurationSetItems<'a> {
     {
         let params = curation_sets_api::UpsertCurationSetItemParams {
             item_id: item_id.into(),
-            curation_set_name: self.curation_set_name.into(),
+            curation_set_name: self.curation_set_name.as_ref().into(),
             curation_item_create_schema: schema,
         };
-        execute_wrapper!(self, curation_sets_api::upsert_curation_set_item, params)
+        execute_wrapper!(self, write curation_sets_api::upsert_curation_set_item, params)
     }
 }
@@ -7,7 +7,8 @@
 mod item;
 mod items;
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
+use ::std::borrow::Cow;
 use item::CurationSetItem;
 use items::CurationSetItems;
 use typesense_codegen::{
@@ -19,30 +20,32 @@ use typesense_codegen::{
 ///
 /// This struct is created by calling `client.curation_set("curation_set_name")`.
 pub struct CurationSet<'a> {
-    pub(super) client: &'a Client,
-    pub(super) curation_set_name: &'a str,
+    pub(super) client: ClientRef<'a>,
+    pub(super) curation_set_name: Cow<'a, str>,
 }
 
 impl<'a> CurationSet<'a> {
     /// Creates a new `CurationSet` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client, curation_set_name: &'a str) -> Self {
+    pub(super) fn new(client: ClientRef<'a>, curation_set_name: impl Into<Cow<'a, str>>) -> Self {
         Self {
             client,
-            curation_set_name,
+            curation_set_name: curation_set_name.into(),
         }
     }
 
+    with_options!();
+
     /// Provides access to the items of this curation set.
     #[inline]
-    pub fn items(&self) -> CurationSetItems<'_> {
-        CurationSetItems::new(self.client, self.curation_set_name)
+    pub fn items(&self) -> CurationSetItems<'a> {
+        CurationSetItems::new(self.client.clone(), self.curation_set_name.clone())
     }
 
     /// Provides access to this specific item of this curation set.
     #[inline]
-    pub fn item(&self, item_id: &'a str) -> CurationSetItem<'a> {
-        CurationSetItem::new(self.client, self.curation_set_name, item_id)
+    pub fn item(&self, item_id: impl Into<Cow<'a, str>>) -> CurationSetItem<'a> {
+        CurationSetItem::new(self.client.clone(), self.curation_set_name.clone(), item_id)
     }
 
     /// Retrieves the details of this curation set.
@@ -50,9 +53,9 @@ impl<'a> CurationSet<'a> {
         &self,
     ) -> Result<models::CurationSetSchema, Error<curation_sets_api::RetrieveCurationSetError>> {
         let params = RetrieveCurationSetParams {
-            curation_set_name: self.curation_set_name.into(),
+            curation_set_name: self.curation_set_name.as_ref().into(),
         };
-        execute_wrapper!(self, curation_sets_api::retrieve_curation_set, params)
+        execute_wrapper!(self, read curation_sets_api::retrieve_curation_set, params)
     }
 
     /// Delete this curation set.
@@ -61,8 +64,8 @@ impl<'a> CurationSet<'a> {
     ) -> Result<models::CurationSetDeleteSchema, Error<curation_sets_api::DeleteCurationSetError>>
     {
         let params = curation_sets_api::DeleteCurationSetParams {
-            curation_set_name: self.curation_set_name.into(),
+            curation_set_name: self.curation_set_name.as_ref().into(),
         };
-        execute_wrapper!(self, curation_sets_api::delete_curation_set, params)
+        execute_wrapper!(self, write curation_sets_api::delete_curation_set, params)
     }
 }
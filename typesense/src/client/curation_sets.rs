@@ -4,7 +4,7 @@
 //!
 //! A `CurationSets` instance is created via the main `client.curation_sets()` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use ::std::borrow::Cow;
 use typesense_codegen::{apis::curation_sets_api, models};
 
@@ -12,22 +12,24 @@ use typesense_codegen::{apis::curation_sets_api, models};
 ///
 /// This struct is created by calling `client.curation_sets()`.
 pub struct CurationSets<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> CurationSets<'a> {
     /// Creates a new `CurationSets` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Retrieves the details of all curation sets.
     pub async fn retrieve(
         &self,
     ) -> Result<Vec<models::CurationSetSchema>, Error<curation_sets_api::RetrieveCurationSetsError>>
     {
-        execute_wrapper!(self, curation_sets_api::retrieve_curation_sets)
+        execute_wrapper!(self, read curation_sets_api::retrieve_curation_sets)
     }
 
     /// Creates or updates an existing curation set.
@@ -44,6 +46,6 @@ impl<'a> CurationSets<'a> {
             curation_set_name: name.into(),
             curation_set_create_schema: schema,
         };
-        execute_wrapper!(self, curation_sets_api::upsert_curation_set, params)
+        execute_wrapper!(self, write curation_sets_api::upsert_curation_set, params)
     }
 }
@@ -0,0 +1,198 @@
+//! Active health checking of the nodes of a client.
+//!
+//! Without a health checker, an unhealthy node is only tried again by a request sent after
+//! `healthcheck_interval` has elapsed. A [`HealthChecker`] instead calls the `/health` endpoint
+//! of the nodes in the background, so that nodes are marked healthy (or unhealthy) before
+//! requests are sent to them.
+
+use super::{Client, PreparedOptions, RequestOptions, timer};
+use ::std::{
+    future::poll_fn,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::Poll,
+};
+use futures::{
+    future::{self, Either},
+    task::AtomicWaker,
+};
+use typesense_codegen::apis::health_api;
+use web_time::Duration;
+
+/// Periodically checks the health of the nodes of a client.
+///
+/// The checker does not spawn a task itself: [`HealthChecker::run`] returns a future that is
+/// spawned on the runtime of the application, e.g. with `tokio::spawn` on native targets or
+/// `wasm_bindgen_futures::spawn_local` on WebAssembly. It runs until
+/// [`HealthCheckerHandle::shutdown`] is called.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(not(target_family = "wasm"))]
+/// # {
+/// use std::{sync::Arc, time::Duration};
+/// use typesense::{Client, HealthChecker};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Arc::new(
+///     Client::builder()
+///         .nodes(vec!["http://node-1:8108", "http://node-2:8108"])
+///         .api_key("xyz")
+///         .build()?,
+/// );
+///
+/// let checker = HealthChecker::builder()
+///     .client(client.clone())
+///     .interval(Duration::from_secs(5))
+///     .build();
+/// let handle = checker.handle();
+/// tokio::spawn(checker.run());
+///
+/// // ... send requests with `client` ...
+///
+/// handle.shutdown();
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HealthChecker {
+    client: Arc<Client>,
+    /// The options the health checks are sent with.
+    options: PreparedOptions,
+    interval: Duration,
+    all_nodes: bool,
+    shutdown: Arc<Shutdown>,
+}
+
+#[bon::bon]
+impl HealthChecker {
+    /// Creates a new `HealthChecker`.
+    ///
+    /// Default values:
+    /// - **interval**: 10 seconds.
+    /// - **timeout**: 2 seconds.
+    /// - **all_nodes**: false.
+    #[builder]
+    pub fn new(
+        /// The client whose nodes are checked.
+        client: Arc<Client>,
+        /// The time between two rounds of health checks.
+        #[builder(default = Duration::from_secs(10))]
+        interval: Duration,
+        /// The time after which a health check fails. Health checks are never retried.
+        #[builder(default = Duration::from_secs(2))]
+        timeout: Duration,
+        /// Whether to check the healthy nodes too, and mark them unhealthy when a check fails.
+        /// By default only the unhealthy nodes are checked, including the nodes whose circuit
+        /// is open.
+        #[builder(default)]
+        all_nodes: bool,
+    ) -> Self {
+        let options = RequestOptions::builder()
+            .timeout(timeout)
+            .disable_retry(true)
+            .build();
+        Self {
+            options: PreparedOptions::new(&client, options),
+            client,
+            interval,
+            all_nodes,
+            shutdown: Arc::default(),
+        }
+    }
+
+    /// Returns a handle that stops the checker.
+    pub fn handle(&self) -> HealthCheckerHandle {
+        HealthCheckerHandle {
+            shutdown: self.shutdown.clone(),
+        }
+    }
+
+    /// Checks the nodes every `interval`, until the checker is shut down.
+    ///
+    /// The first round of checks runs immediately.
+    pub async fn run(self) {
+        while !self.shutdown.is_requested() {
+            self.check().await;
+            let sleep = std::pin::pin!(timer::sleep(self.interval));
+            if let Either::Right(_) =
+                future::select(sleep, std::pin::pin!(self.shutdown.wait())).await
+            {
+                break;
+            }
+        }
+    }
+
+    /// Runs a single round of health checks and updates the health of the checked nodes.
+    pub async fn check(&self) {
+        let checks = self
+            .client
+            .nodes
+            .iter()
+            .zip(&self.options.configs)
+            .filter(|(node, _)| self.all_nodes || !self.client.is_healthy(node))
+            .map(|(node, config)| async move {
+                let result = health_api::health(config);
+                #[cfg(target_arch = "wasm32")]
+                let result =
+                    super::request_options::with_timeout(self.options.options.timeout, result);
+                let is_healthy = matches!(result.await, Ok(status) if status.ok);
+                node.set_health(is_healthy);
+            });
+        future::join_all(checks).await;
+    }
+}
+
+/// Stops a [`HealthChecker`].
+#[derive(Clone, Debug)]
+pub struct HealthCheckerHandle {
+    shutdown: Arc<Shutdown>,
+}
+
+impl HealthCheckerHandle {
+    /// Stops the checker. A round of checks that is in progress is completed first.
+    pub fn shutdown(&self) {
+        self.shutdown.request();
+    }
+
+    /// Returns whether the checker was shut down.
+    pub fn is_shutdown(&self) -> bool {
+        self.shutdown.is_requested()
+    }
+}
+
+/// The shutdown signal shared by a checker and its handles.
+#[derive(Debug, Default)]
+struct Shutdown {
+    requested: AtomicBool,
+    waker: AtomicWaker,
+}
+
+impl Shutdown {
+    fn request(&self) {
+        self.requested.store(true, Ordering::Release);
+        self.waker.wake();
+    }
+
+    fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::Acquire)
+    }
+
+    /// Waits until the shutdown is requested.
+    async fn wait(&self) {
+        poll_fn(|cx| {
+            self.waker.register(cx.waker());
+            if self.is_requested() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
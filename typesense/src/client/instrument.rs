@@ -0,0 +1,180 @@
+//! Spans and events emitted for the requests of the client, when the `tracing` feature is enabled.
+//!
+//! Every API call opens a `typesense.request` span with its namespace, operation and collection.
+//! Each node the call is sent to opens a child `typesense.attempt` span with the node URL, the
+//! HTTP status code, the number of retries on that node and the latency. A `WARN` event is
+//! emitted when the call fails over to the next node. The API keys sent with a request, from the
+//! client, the request options or an `x-typesense-api-key` header or query parameter, are never
+//! recorded, and are redacted from recorded error messages.
+
+/// Identifies an API call made through a namespace.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Operation {
+    /// The type name of the namespace, e.g. `typesense::client::collections::Collections`.
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    namespace: &'static str,
+    /// The API function, e.g. `collections_api::create_collection`.
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    call: &'static str,
+    /// Whether the API call only reads data, and can safely be sent more than once.
+    is_read: bool,
+}
+
+impl Operation {
+    /// An API call that only reads data, which may be hedged.
+    #[inline]
+    pub(crate) fn read(namespace: &'static str, call: &'static str) -> Self {
+        Self {
+            namespace,
+            call,
+            is_read: true,
+        }
+    }
+
+    /// An API call that changes data, which is never sent more than once.
+    #[inline]
+    pub(crate) fn write(namespace: &'static str, call: &'static str) -> Self {
+        Self {
+            namespace,
+            call,
+            is_read: false,
+        }
+    }
+
+    /// The name of the API function, e.g. `create_collection`.
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    fn name(&self) -> &'static str {
+        self.call.rsplit("::").next().unwrap_or_default().trim()
+    }
+
+    /// Returns whether the API call only reads data, and can safely be sent more than once.
+    #[inline]
+    pub(super) fn is_read(&self) -> bool {
+        self.is_read
+    }
+}
+
+#[cfg(feature = "tracing")]
+pub(super) use spans::*;
+
+#[cfg(feature = "tracing")]
+mod spans {
+    use super::Operation;
+    use reqwest::header::HeaderMap;
+    use tracing::{Span, field::Empty};
+    use typesense_codegen::apis::configuration::Configuration;
+    use web_time::Duration;
+
+    /// The header and query parameter Typesense reads API keys from.
+    const API_KEY_PARAMETER: &str = "x-typesense-api-key";
+    const REDACTED: &str = "[REDACTED]";
+
+    impl Operation {
+        /// The name of the namespace in snake case, e.g. `nl_search_models`.
+        fn namespace(&self) -> String {
+            let name = self.namespace.split('<').next().unwrap_or_default();
+            let name = name.rsplit("::").next().unwrap_or_default();
+            let mut namespace = String::with_capacity(name.len() + 2);
+            for (i, c) in name.char_indices() {
+                if c.is_ascii_uppercase() && i > 0 {
+                    namespace.push('_');
+                }
+                namespace.push(c.to_ascii_lowercase());
+            }
+            namespace
+        }
+
+        /// Opens the span of this API call.
+        pub(in crate::client) fn span(&self, collection: Option<&str>) -> Span {
+            tracing::info_span!(
+                "typesense.request",
+                namespace = %self.namespace(),
+                operation = self.name(),
+                collection,
+                attempts = Empty,
+                latency_ms = Empty,
+            )
+        }
+    }
+
+    /// Opens the span of the `attempt`-th node an API call is sent to.
+    pub(in crate::client) fn attempt_span(node: &str, attempt: usize) -> Span {
+        tracing::info_span!(
+            "typesense.attempt",
+            node,
+            attempt,
+            status_code = Empty,
+            retries = Empty,
+            latency_ms = Empty,
+            error = Empty,
+        )
+    }
+
+    /// The API keys sent with the requests made with `config`: the key of the client or of the
+    /// request options, and the `x-typesense-api-key` values of the request option `headers`.
+    pub(in crate::client) fn api_keys<'c>(
+        config: &'c Configuration,
+        headers: Option<&'c HeaderMap>,
+    ) -> Vec<&'c str> {
+        let header_keys = headers
+            .into_iter()
+            .flat_map(|headers| headers.get_all(API_KEY_PARAMETER))
+            .filter_map(|value| value.to_str().ok());
+        config
+            .api_key
+            .iter()
+            .map(|api_key| api_key.key.as_str())
+            .chain(header_keys)
+            .filter(|key| !key.is_empty())
+            .collect()
+    }
+
+    /// Records the latency of a request, and its error with `api_keys` redacted.
+    pub(in crate::client) fn record_result<T, E: ::std::fmt::Display>(
+        span: &Span,
+        latency: Duration,
+        result: &Result<T, E>,
+        api_keys: &[&str],
+    ) {
+        span.record("latency_ms", latency.as_millis() as u64);
+        if let Err(e) = result {
+            span.record("error", redact(e, api_keys));
+        }
+    }
+
+    /// Emits the event of a call that failed on `node` and is sent to the next node.
+    pub(in crate::client) fn failover<E: ::std::fmt::Display>(
+        node: &str,
+        error: &E,
+        api_keys: &[&str],
+    ) {
+        tracing::warn!(
+            node,
+            error = redact(error, api_keys),
+            "Request failed, trying the next node"
+        );
+    }
+
+    /// Formats `error`, replacing every occurrence of `api_keys` in it, and the value of every
+    /// `x-typesense-api-key` query parameter of the URLs it contains.
+    fn redact(error: &impl ::std::fmt::Display, api_keys: &[&str]) -> String {
+        let message = api_keys.iter().fold(error.to_string(), |message, key| {
+            message.replace(key, REDACTED)
+        });
+        let parameter = format!("{API_KEY_PARAMETER}=");
+        // Lowercasing ASCII characters keeps the byte offsets of `message`.
+        let lowercase = message.to_ascii_lowercase();
+        let mut redacted = String::with_capacity(message.len());
+        let mut end = 0;
+        for (start, _) in lowercase.match_indices(&parameter) {
+            let value = start + parameter.len();
+            redacted.push_str(&message[end..value]);
+            redacted.push_str(REDACTED);
+            end = message[value..]
+                .find(|c: char| matches!(c, '&' | '#' | ')' | '"' | '\'') || c.is_whitespace())
+                .map_or(message.len(), |len| value + len);
+        }
+        redacted.push_str(&message[end..]);
+        redacted
+    }
+}
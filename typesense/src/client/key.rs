@@ -2,24 +2,26 @@
 //!
 //! A `Key` instance is created via the `client.key(key_id)` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use typesense_codegen::{apis::keys_api, models};
 
 /// Provides methods for managing a specific Typesense API key.
 ///
 /// This struct is created by calling `client.key(key_id)`.
 pub struct Key<'c> {
-    pub(super) client: &'c Client,
+    pub(super) client: ClientRef<'c>,
     pub(super) key_id: i64,
 }
 
 impl<'c> Key<'c> {
     /// Creates a new `Key` instance for a specific key ID.
     #[inline]
-    pub(super) fn new(client: &'c Client, key_id: i64) -> Self {
+    pub(super) fn new(client: ClientRef<'c>, key_id: i64) -> Self {
         Self { client, key_id }
     }
 
+    with_options!();
+
     /// Retrieves metadata about this specific API key.
     ///
     /// For security reasons, this endpoint only returns the key prefix and metadata,
@@ -29,7 +31,7 @@ impl<'c> Key<'c> {
         let params = keys_api::GetKeyParams {
             key_id: self.key_id,
         };
-        execute_wrapper!(self, keys_api::get_key, params)
+        execute_wrapper!(self, read keys_api::get_key, params)
     }
 
     /// Deletes this specific API key.
@@ -40,6 +42,6 @@ impl<'c> Key<'c> {
         let params = keys_api::DeleteKeyParams {
             key_id: self.key_id,
         };
-        execute_wrapper!(self, keys_api::delete_key, params)
+        execute_wrapper!(self, write keys_api::delete_key, params)
     }
 }
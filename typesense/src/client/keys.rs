@@ -3,7 +3,9 @@
 //! An `Keys` instance is created via the `client.keys()` method.
 
 use crate::{
-    Client, Error, execute_wrapper,
+    Error,
+    client::ClientRef,
+    execute_wrapper,
     models::{self, ScopedKeyParameters},
 };
 use base64::{Engine, engine::general_purpose::STANDARD as Base64Engine};
@@ -15,16 +17,18 @@ use typesense_codegen::apis::keys_api;
 ///
 /// This struct is created by calling `client.keys()`.
 pub struct Keys<'c> {
-    pub(super) client: &'c Client,
+    pub(super) client: ClientRef<'c>,
 }
 
 impl<'c> Keys<'c> {
     /// Creates a new `Keys` instance.
     #[inline]
-    pub(super) fn new(client: &'c Client) -> Self {
+    pub(super) fn new(client: ClientRef<'c>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Creates a new API key with fine-grained access control.
     ///
     /// You can restrict access on a per-collection and per-action level.
@@ -40,13 +44,13 @@ impl<'c> Keys<'c> {
         let params = keys_api::CreateKeyParams {
             api_key_schema: Some(schema),
         };
-        execute_wrapper!(self, keys_api::create_key, params)
+        execute_wrapper!(self, write keys_api::create_key, params)
     }
 
     /// Lists all API keys and their metadata.
     #[inline]
     pub async fn retrieve(&self) -> Result<models::ApiKeysResponse, Error<keys_api::GetKeysError>> {
-        execute_wrapper!(self, keys_api::get_keys)
+        execute_wrapper!(self, read keys_api::get_keys)
     }
 
     /// Generate a scoped search API key that can have embedded search parameters in them.
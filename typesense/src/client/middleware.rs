@@ -0,0 +1,253 @@
+//! Middleware that runs on the HTTP requests sent by the client.
+//!
+//! Middleware is added with `Client::builder().middleware(..)` and wraps every request sent to
+//! every node, on native and WebAssembly targets alike. Retries are native only: the retry
+//! policies rely on `std::time::SystemTime`, which is not available in the browser. The types in this module are re-exported
+//! from [`reqwest_middleware`]; implementing [`Middleware`] requires the
+//! [`async_trait`](https://docs.rs/async-trait) attribute.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # #[cfg(not(target_family = "wasm"))]
+//! # {
+//! use typesense::{
+//!     Client,
+//!     middleware::{Extensions, Middleware, Next, Result},
+//! };
+//!
+//! /// Logs the status of every request.
+//! struct Logger;
+//!
+//! #[async_trait::async_trait]
+//! impl Middleware for Logger {
+//!     async fn handle(
+//!         &self,
+//!         request: reqwest::Request,
+//!         extensions: &mut Extensions,
+//!         next: Next<'_>,
+//!     ) -> Result<reqwest::Response> {
+//!         let url = request.url().clone();
+//!         let response = next.run(request, extensions).await;
+//!         match &response {
+//!             Ok(response) => println!("{url}: {}", response.status()),
+//!             Err(error) => println!("{url}: {error}"),
+//!         }
+//!         response
+//!     }
+//! }
+//!
+//! let client = Client::builder()
+//!     .nodes(vec!["http://localhost:8108"])
+//!     .api_key("xyz")
+//!     .middleware(Logger)
+//!     .build()
+//!     .unwrap();
+//! # }
+//! ```
+
+use super::retry_policy::ClientRetryPolicy;
+use ::std::{fmt, sync::Arc};
+use reqwest::header::HeaderMap;
+use reqwest_middleware::ClientWithMiddleware;
+use web_time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    super::timer,
+    ::std::time::SystemTime,
+    reqwest::{StatusCode, header::RETRY_AFTER},
+    reqwest_retry::{
+        DefaultRetryableStrategy, RetryDecision, RetryPolicy, Retryable, RetryableStrategy,
+    },
+};
+
+pub use http::Extensions;
+pub use reqwest_middleware::{Error, Middleware, Next, Result};
+
+/// The middleware shared by all nodes of a client: the [`Retry`] layer on native targets,
+/// followed by the middleware added with `ClientBuilder::middleware`.
+#[derive(Clone)]
+pub(super) struct Stack(Arc<[Arc<dyn Middleware>]>);
+
+impl Stack {
+    pub(super) fn new(
+        retry_policy: ClientRetryPolicy,
+        middleware: Vec<Arc<dyn Middleware>>,
+    ) -> Self {
+        // The retry layer runs first, so that every retry goes through the other middleware.
+        #[cfg(not(target_arch = "wasm32"))]
+        let stack = ::std::iter::once(Arc::new(Retry::new(retry_policy)) as Arc<dyn Middleware>)
+            .chain(middleware);
+        #[cfg(target_arch = "wasm32")]
+        let stack = {
+            let _ = retry_policy;
+            middleware.into_iter()
+        };
+        // The trace layer runs last, so that it records what is sent to the node.
+        #[cfg(feature = "tracing")]
+        let stack = stack.chain(::std::iter::once(Arc::new(Trace) as Arc<dyn Middleware>));
+        Self(stack.collect())
+    }
+
+    /// Wraps `http_client` in the middleware, preceded by `first` if given.
+    pub(super) fn client(
+        &self,
+        http_client: reqwest::Client,
+        first: Option<Arc<dyn Middleware>>,
+    ) -> ClientWithMiddleware {
+        let middleware: Vec<_> = first.into_iter().chain(self.0.iter().cloned()).collect();
+        ClientWithMiddleware::new(http_client, middleware)
+    }
+}
+
+impl fmt::Debug for Stack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stack").field("len", &self.0.len()).finish()
+    }
+}
+
+/// A request extension that makes the [`Retry`] middleware send the request only once.
+#[derive(Clone, Copy, Debug)]
+struct DisableRetry;
+
+/// Applies the headers, timeout and retry setting of `RequestOptions` to a request.
+///
+/// The generated API functions send prepared requests, which skip the initialisers of
+/// [`ClientWithMiddleware`], so the options are applied by the first middleware instead.
+pub(super) struct Overrides {
+    pub(super) headers: HeaderMap,
+    pub(super) timeout: Option<Duration>,
+    pub(super) disable_retry: bool,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl Middleware for Overrides {
+    async fn handle(
+        &self,
+        mut request: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<reqwest::Response> {
+        request.headers_mut().extend(self.headers.clone());
+        // The browser client does not support timeouts, see `with_timeout`.
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(timeout) = self.timeout {
+            *request.timeout_mut() = Some(timeout);
+        }
+        if self.disable_retry {
+            extensions.insert(DisableRetry);
+        }
+        next.run(request, extensions).await
+    }
+}
+
+/// Retries requests that failed with a transient error on the same node, according to the
+/// `retry_policy` of the client.
+///
+/// Requests rejected with `429 Too Many Requests` are retried too, after the delay given by
+/// their `Retry-After` header if any, and count against the same retry policy. Only
+/// `Retry-After` values in seconds are supported; HTTP dates are ignored, and the delay of the
+/// retry policy is used instead.
+///
+/// This is always the first middleware of a node, so that every retry runs through the
+/// middleware added with `ClientBuilder::middleware`. It is not installed on wasm32, where
+/// `std::time::SystemTime` panics.
+#[cfg(not(target_arch = "wasm32"))]
+struct Retry {
+    policy: ClientRetryPolicy,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Retry {
+    /// The longest `Retry-After` delay that is waited for; longer delays are capped.
+    const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+    fn new(policy: ClientRetryPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait::async_trait]
+impl Middleware for Retry {
+    async fn handle(
+        &self,
+        request: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<reqwest::Response> {
+        if extensions.get::<DisableRetry>().is_some() {
+            return next.run(request, extensions).await;
+        }
+
+        let started = SystemTime::now();
+        let mut retries = 0;
+        loop {
+            // A request with a streaming body cannot be sent again.
+            let Some(duplicate) = request.try_clone() else {
+                return next.run(request, extensions).await;
+            };
+            let result = next.clone().run(duplicate, extensions).await;
+            let Some(Retryable::Transient) = DefaultRetryableStrategy.handle(&result) else {
+                return result;
+            };
+            let RetryDecision::Retry { execute_after } = self.policy.should_retry(started, retries)
+            else {
+                return result;
+            };
+            let delay = result
+                .as_ref()
+                .ok()
+                .filter(|response| response.status() == StatusCode::TOO_MANY_REQUESTS)
+                .and_then(retry_after)
+                .unwrap_or_else(|| {
+                    execute_after
+                        .duration_since(SystemTime::now())
+                        .unwrap_or_default()
+                });
+            timer::sleep(delay.min(Self::MAX_RETRY_AFTER)).await;
+            retries += 1;
+        }
+    }
+}
+
+/// Returns the delay of the `Retry-After` header of `response`, if given in seconds.
+#[cfg(not(target_arch = "wasm32"))]
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+/// Records the status code and the number of retries of a request on the current
+/// `typesense.attempt` span.
+#[cfg(feature = "tracing")]
+struct Trace;
+
+/// A request extension that counts the times a request was sent.
+#[cfg(feature = "tracing")]
+#[derive(Clone, Copy, Debug, Default)]
+struct Sends(u32);
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl Middleware for Trace {
+    async fn handle(
+        &self,
+        request: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<reqwest::Response> {
+        let span = tracing::Span::current();
+        let sends = extensions.get_or_insert_default::<Sends>();
+        span.record("retries", sends.0);
+        sends.0 += 1;
+
+        let response = next.run(request, extensions).await;
+        if let Ok(response) = &response {
+            span.record("status_code", response.status().as_u16());
+        }
+        response
+    }
+}
@@ -6,9 +6,15 @@
 //!
 //! ## Key Features:
 //! - **Multi-Node Configuration**: Automatically manages connections to multiple Typesense nodes.
+//! - **Configuration**: A client can be created from a deserialized [`ClientConfig`], environment variables ([`Client::from_env`]) or a connection URL ([`Client::from_url`]).
 //! - **Health Checks & Failover**: Monitors node health and seamlessly fails over to healthy nodes upon encountering server or network errors.
+//! - **Active Health Checks**: An optional [`HealthChecker`] probes the nodes in the background, so that recovered nodes are used again without waiting for a request.
 //! - **Nearest Node Priority**: Can be configured to always prioritize a specific nearest node to reduce latency.
-//! - **Built-in Retries**: Handles transient network errors with an exponential backoff policy for each node.
+//! - **Built-in Retries**: Handles transient network errors with an exponential backoff policy for each node, and rate-limited requests after their `Retry-After` delay.
+//! - **Client-Side Rate Limiting**: An optional [`RateLimiter`] caps the rate and concurrency of the API calls.
+//! - **Hedged Reads**: With a `hedge_delay`, a slow search or retrieval is also sent to another node, and the first response wins.
+//! - **Tracing**: With the `tracing` feature, every request is instrumented with spans for the API call and each node it is sent to.
+//! - **Blocking Client**: With the `blocking` feature, `typesense::blocking::Client` offers the collections, documents, keys, aliases, operations and multi search endpoints without async, for command-line tools and build scripts.
 //!
 //! ## Example Usage
 //!
@@ -57,8 +63,9 @@
 //!
 //! ### WebAssembly (Wasm) Usage
 //!
-//! When compiling for a WebAssembly target (`wasm32-unknown-unknown`),
-//! Tokio-based features such as middleware and retries are **not available**.
+//! The client also works on WebAssembly targets (`wasm32-unknown-unknown`), with the same
+//! failover and middleware. Retries on a single node are **not available**, and per-node HTTP
+//! client options such as connect timeouts are not supported by the browser client.
 //!
 //! Example:
 //!
@@ -103,41 +110,71 @@
 //! }
 //! }
 //! ```
+#[macro_use]
+mod request_options;
+
 mod alias;
 mod aliases;
 mod analytics;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+mod circuit_breaker;
 mod collection;
 mod collections;
+mod config;
 mod conversations;
 mod curation_set;
 mod curation_sets;
+mod health_checker;
+mod instrument;
 mod key;
 mod keys;
+pub mod middleware;
 mod multi_search;
+mod nl_search_model;
+mod nl_search_models;
+pub mod node_selector;
+mod node_stats;
 mod operations;
 mod preset;
 mod presets;
+mod rate_limiter;
 mod retry_policy;
 mod stemming;
 mod stopword;
 mod stopwords;
 mod synonym_set;
 mod synonym_sets;
+mod timer;
 
-use crate::{Error, traits::Document};
+use crate::{Error, NodeAttempt, traits::Document};
 use alias::Alias;
 use aliases::Aliases;
 use analytics::Analytics;
-use collection::Collection;
+use circuit_breaker::Permit;
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
+pub use collection::Collection;
 use collections::Collections;
+pub use config::ClientConfig;
 use conversations::Conversations;
 use curation_set::CurationSet;
 use curation_sets::CurationSets;
+pub use health_checker::{HealthChecker, HealthCheckerHandle};
+pub(crate) use instrument::Operation;
 use key::Key;
 use keys::Keys;
+use middleware::{Middleware, Stack};
+use nl_search_model::NlSearchModel;
+use nl_search_models::NlSearchModels;
+use node_selector::{Candidate, NodeSelector, RoundRobin};
+pub use node_stats::{LatencyPercentiles, NodeStats};
 use operations::Operations;
 use preset::Preset;
 use presets::Presets;
+pub use rate_limiter::RateLimiter;
+pub(crate) use request_options::ClientRef;
+use request_options::PreparedOptions;
+pub use request_options::RequestOptions;
 use retry_policy::ClientRetryPolicy;
 use stemming::Stemming;
 use stopword::Stopword;
@@ -145,34 +182,50 @@ use stopwords::Stopwords;
 use synonym_set::SynonymSet;
 use synonym_sets::SynonymSets;
 
-#[cfg(not(target_arch = "wasm32"))]
-use reqwest_middleware::ClientBuilder as ReqwestMiddlewareClientBuilder;
-#[cfg(not(target_arch = "wasm32"))]
-use reqwest_retry::RetryTransientMiddleware;
 pub use reqwest_retry::policies::ExponentialBackoff;
 
 use ::std::{
     borrow::Cow,
     future::Future,
+    io,
+    pin::pin,
     sync::{
-        RwLock,
+        Arc, Mutex, RwLock,
         atomic::{AtomicBool, AtomicUsize, Ordering},
     },
 };
+use futures::future::{self, Either};
+use reqwest::StatusCode;
+use reqwest_retry::RetryError;
 use serde::{Serialize, de::DeserializeOwned};
 use typesense_codegen::apis::{self, configuration};
 use web_time::{Duration, Instant};
 
 /// Wraps api call in `client::execute()`
+///
+/// The call is marked as a `read`, which may be hedged, or a `write`, which is never sent twice.
 #[macro_export]
 macro_rules! execute_wrapper {
-    ($self:ident, $call:expr) => {
-        $self.client.execute($call).await
+    ($self:ident, $kind:ident $call:expr) => {
+        $self
+            .client
+            .execute(
+                $crate::client::Operation::$kind(
+                    ::std::any::type_name_of_val($self),
+                    stringify!($call),
+                ),
+                $call,
+            )
+            .await
     };
-    ($self:ident, $call:expr, $params:ident) => {
+    ($self:ident, $kind:ident $call:expr, $params:ident) => {
         $self
             .client
             .execute(
+                $crate::client::Operation::$kind(
+                    ::std::any::type_name_of_val($self),
+                    stringify!($call),
+                ),
                 |config: &typesense_codegen::apis::configuration::Configuration| {
                     $call(config, &$params)
                 },
@@ -312,11 +365,28 @@ impl From<reqwest::Url> for NodeConfig {
 }
 
 // This is an internal detail to track the state of each node.
-#[derive(Debug)]
 struct Node {
     config: configuration::Configuration,
+    /// The HTTP client of the node without any middleware.
+    http_client: reqwest::Client,
+    middleware: Stack,
     is_healthy: AtomicBool,
     last_accessed: RwLock<Instant>,
+    stats: Mutex<node_stats::Recorder>,
+    circuit: Mutex<circuit_breaker::Circuit>,
+}
+
+// The configuration holds the API key, so only the URL of the node is printed.
+impl std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Node")
+            .field("url", &self.config.base_path)
+            .field("is_healthy", &self.is_healthy)
+            .field("last_accessed", &self.last_accessed)
+            .field("stats", &self.stats)
+            .field("circuit", &self.circuit)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Node {
@@ -337,7 +407,22 @@ pub struct Client {
     nodes: Vec<Node>,
     is_nearest_node_set: bool,
     healthcheck_interval: Duration,
-    current_node_index: AtomicUsize,
+    node_selector: Arc<dyn NodeSelector>,
+    circuit_breaker: Option<CircuitBreaker>,
+    hedge_delay: Option<Duration>,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl<S: client_builder::State> ClientBuilder<S> {
+    /// Adds a middleware that runs on every request sent to every node, and on its response.
+    ///
+    /// Middleware runs in the order it is added, after the retry layer configured with
+    /// `retry_policy` on native targets, so it sees every retry of a request. See the [`middleware`] module for
+    /// an example.
+    pub fn middleware(mut self, middleware: impl Middleware) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
 }
 
 #[bon::bon]
@@ -348,6 +433,11 @@ impl Client {
     /// - **nearest_node**: None.
     /// - **healthcheck_interval**: 60 seconds.
     /// - **retry_policy**: Exponential backoff with a maximum of 3 retries. (disabled on WASM)
+    /// - **middleware**: None.
+    /// - **node_selector**: Round-robin over the healthy nodes.
+    /// - **circuit_breaker**: None.
+    /// - **hedge_delay**: None.
+    /// - **rate_limiter**: None.
     /// - **http_builder**: An `FnOnce(reqwest::ClientBuilder) -> reqwest::ClientBuilder` closure
     ///   for per-node HTTP client customization (optional, via [`NodeConfig`]).
     ///
@@ -355,6 +445,10 @@ impl Client {
     /// a 5-second connect timeout is used (native targets only).
     #[builder]
     pub fn new(
+        /// Middleware that runs on every request sent to every node, added with
+        /// [`ClientBuilder::middleware`].
+        #[builder(field)]
+        middleware: Vec<Arc<dyn Middleware>>,
         /// The Typesense API key used for authentication.
         #[builder(into)]
         api_key: String,
@@ -378,9 +472,28 @@ impl Client {
         #[builder(into, default)]
         /// The retry policy for transient network errors on a *single* node.
         retry_policy: ClientRetryPolicy,
+        /// The strategy that selects the node each request is sent to, among the healthy nodes.
+        /// See the [`node_selector`] module.
+        #[builder(
+            with = |selector: impl NodeSelector + 'static| Arc::new(selector) as Arc<dyn NodeSelector>,
+            default = Arc::new(RoundRobin::default()),
+        )]
+        node_selector: Arc<dyn NodeSelector>,
+        /// Enables a circuit breaker for each node. Failed requests then open the circuit of
+        /// the node instead of marking it unhealthy; a node is skipped while its circuit is
+        /// open, or while it is marked down by a [`HealthChecker`].
+        circuit_breaker: Option<CircuitBreaker>,
+        /// Sends idempotent reads (searches, retrievals and exports) to a second node when the
+        /// first one has not answered after this delay, and uses the first response. Writes are
+        /// never sent twice.
+        hedge_delay: Option<Duration>,
+        /// Limits the rate and concurrency of the API calls of the client.
+        rate_limiter: Option<RateLimiter>,
     ) -> Result<Self, &'static str> {
         let is_nearest_node_set = nearest_node.is_some();
 
+        let middleware = Stack::new(retry_policy, middleware);
+
         let nodes: Vec<_> = nodes
             .into_iter()
             .chain(nearest_node)
@@ -395,24 +508,8 @@ impl Client {
                     }
                 };
 
-                #[cfg(target_arch = "wasm32")]
                 let http_client = builder.build().expect("Failed to build reqwest client");
 
-                #[cfg(not(target_arch = "wasm32"))]
-                let mw_builder = ReqwestMiddlewareClientBuilder::new(
-                    builder.build().expect("Failed to build reqwest client"),
-                );
-
-                #[cfg(not(target_arch = "wasm32"))]
-                let http_client = match retry_policy {
-                    ClientRetryPolicy::Default(policy) => mw_builder
-                        .with(RetryTransientMiddleware::new_with_policy(policy))
-                        .build(),
-                    ClientRetryPolicy::Timed(policy) => mw_builder
-                        .with(RetryTransientMiddleware::new_with_policy(policy))
-                        .build(),
-                };
-
                 let mut url = node_config.url;
                 if url.len() > 1 && matches!(url.chars().last(), Some('/')) {
                     url.pop();
@@ -424,14 +521,18 @@ impl Client {
                         prefix: None,
                         key: api_key.clone(),
                     }),
-                    client: http_client,
+                    client: middleware.client(http_client.clone(), None),
                     ..Default::default()
                 };
 
                 Node {
                     config,
+                    http_client,
+                    middleware: middleware.clone(),
                     is_healthy: AtomicBool::new(true),
                     last_accessed: RwLock::new(Instant::now()),
+                    stats: Default::default(),
+                    circuit: Default::default(),
                 }
             })
             .collect();
@@ -444,76 +545,231 @@ impl Client {
             nodes,
             is_nearest_node_set,
             healthcheck_interval,
-            current_node_index: AtomicUsize::new(0),
+            node_selector,
+            circuit_breaker,
+            hedge_delay,
+            rate_limiter,
         })
     }
 
     /// Selects the next node to use for a request based on health and priority.
-    fn get_next_node(&self) -> &Node {
+    ///
+    /// The node at index `exclude`, if any, is only selected when no other node is available.
+    fn get_next_node_index(&self, exclude: Option<usize>) -> usize {
         // if only one node (including nearest)
-        if self.nodes.len() == 1
-            && let Some(first) = self.nodes.first()
-        {
-            return first;
+        if self.nodes.len() == 1 {
+            return 0;
         }
 
-        let (nodes_len, mut index) = if self.is_nearest_node_set {
-            let last_node_index = self.nodes.len() - 1;
-            (last_node_index, last_node_index)
+        let is_available =
+            |(index, node): (usize, &Node)| exclude != Some(index) && self.is_available(node);
+        let nodes = if self.is_nearest_node_set {
+            let (nearest_node, nodes) = self.nodes.split_last().unwrap();
+            if is_available((nodes.len(), nearest_node)) {
+                return nodes.len();
+            }
+            nodes
         } else {
-            (
-                self.nodes.len(),
-                self.current_node_index.fetch_add(1, Ordering::Relaxed) % self.nodes.len(),
-            )
+            &self.nodes[..]
         };
 
-        for _ in 0..self.nodes.len() {
-            let node = &self.nodes[index];
+        let candidates: Vec<_> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| Candidate {
+                url: &node.config.base_path,
+                is_available: is_available((index, node)),
+            })
+            .collect();
+        let index = self.node_selector.select(&candidates) % candidates.len();
+        // A request that failed on a node must fail over to another node, whatever the selector.
+        (index..candidates.len())
+            .chain(0..index)
+            .find(|&index| candidates[index].is_available)
+            .unwrap_or(index)
+    }
+
+    /// Returns whether a request can be sent to `node`: it is healthy or due to be tried again,
+    /// and with a circuit breaker, its circuit is not open.
+    fn is_available(&self, node: &Node) -> bool {
+        let is_healthy = node.is_healthy.load(Ordering::Relaxed)
+            || node.last_accessed.read().unwrap().elapsed() >= self.healthcheck_interval;
+        is_healthy
+            && self
+                .circuit_breaker
+                .as_ref()
+                .is_none_or(|config| node.circuit.lock().unwrap().is_available(config))
+    }
 
-            if node.is_healthy.load(Ordering::Relaxed)
-                || node.last_accessed.read().unwrap().elapsed() >= self.healthcheck_interval
-            {
-                return node;
-            }
-            index = self.current_node_index.fetch_add(1, Ordering::Relaxed) % nodes_len;
-        }
+    /// Returns whether `node` is healthy: it is not marked down, and with a circuit breaker,
+    /// its circuit is not open.
+    fn is_healthy(&self, node: &Node) -> bool {
+        node.is_healthy.load(Ordering::Relaxed)
+            && self.circuit_breaker.as_ref().is_none_or(|config| {
+                node.circuit.lock().unwrap().state(config).0 != CircuitState::Open
+            })
+    }
 
-        // If all nodes are unhealthy and not due for a check, just pick the next one in the round-robin.
-        // This gives it a chance to prove it has recovered.
-        index = self.current_node_index.load(Ordering::Relaxed) % self.nodes.len();
-        &self.nodes[index]
+    /// Returns whether `index` is a node that the node selector selects from.
+    #[inline]
+    fn is_selectable(&self, index: usize) -> bool {
+        !(self.is_nearest_node_set && index == self.nodes.len() - 1)
+    }
+
+    /// Returns the health and request statistics of each node, in the order the nodes were
+    /// configured, followed by the nearest node.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use typesense::Client;
+    /// #
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// for node in client.node_stats() {
+    ///     if !node.is_healthy {
+    ///         println!("{} is unhealthy: {:?}", node.url, node.last_error);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn node_stats(&self) -> Vec<NodeStats> {
+        self.nodes
+            .iter()
+            .map(|node| {
+                let is_healthy = self.is_healthy(node);
+                let mut retry_in = (!node.is_healthy.load(Ordering::Relaxed)).then(|| {
+                    self.healthcheck_interval
+                        .saturating_sub(node.last_accessed.read().unwrap().elapsed())
+                });
+                let circuit = self.circuit_breaker.as_ref().map(|config| {
+                    let (state, circuit_retry_in) = node.circuit.lock().unwrap().state(config);
+                    retry_in = retry_in.max(circuit_retry_in);
+                    state
+                });
+                node.stats.lock().unwrap().stats(
+                    &node.config.base_path,
+                    is_healthy,
+                    retry_in,
+                    circuit,
+                )
+            })
+            .collect()
     }
 
     /// For use in legacy APIs.
     #[inline]
     pub fn get_legacy_config(&self) -> &configuration::Configuration {
-        &self.get_next_node().config
+        &self.nodes[self.get_next_node_index(None)].config
     }
 
     /// The core execution method that handles multi-node failover and retries.
-    /// This internal method is called by all public API methods.
-    pub(super) async fn execute<F, Fut, T, E, 'a>(&'a self, api_call: F) -> Result<T, Error<E>>
+    /// This internal method is called by all public API methods, through `ClientRef::execute`.
+    async fn execute_with_options<'a, F, Fut, T, E>(
+        &'a self,
+        operation: Operation,
+        collection: Option<&str>,
+        options: Option<&'a PreparedOptions>,
+        api_call: F,
+    ) -> Result<T, Error<E>>
     where
         F: Fn(&'a configuration::Configuration) -> Fut,
         Fut: Future<Output = Result<T, apis::Error<E>>>,
         E: std::fmt::Debug + 'static,
         apis::Error<E>: std::error::Error + 'static,
     {
+        let _permit = match &self.rate_limiter {
+            Some(rate_limiter) => Some(rate_limiter.acquire().await),
+            None => None,
+        };
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = operation.span(collection);
+            let started = Instant::now();
+            let future = self.execute_on_nodes(operation, options, api_call);
+            let result = tracing::Instrument::instrument(future, span.clone()).await;
+            span.record("latency_ms", started.elapsed().as_millis() as u64);
+            result
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = collection;
+            self.execute_on_nodes(operation, options, api_call).await
+        }
+    }
+
+    /// Sends an API call to the nodes in turn, until one of them succeeds or fails with an error
+    /// that is not retriable.
+    async fn execute_on_nodes<'a, F, Fut, T, E>(
+        &'a self,
+        operation: Operation,
+        options: Option<&'a PreparedOptions>,
+        api_call: F,
+    ) -> Result<T, Error<E>>
+    where
+        F: Fn(&'a configuration::Configuration) -> Fut,
+        Fut: Future<Output = Result<T, apis::Error<E>>>,
+        E: std::fmt::Debug + 'static,
+        apis::Error<E>: std::error::Error + 'static,
+    {
+        let pinned_node = match options.and_then(|options| options.pinned_node.as_ref()) {
+            Some(Ok(index)) => Some(*index),
+            Some(Err(url)) => {
+                return Err(Error::from(apis::Error::Io(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("The client has no node with the URL `{url}`."),
+                ))));
+            }
+            None => None,
+        };
+        let attempts = match options {
+            Some(options) if pinned_node.is_some() || options.options.disable_retry => 1,
+            _ => self.nodes.len(),
+        };
+        // Only idempotent reads are hedged, as writes must not be sent twice.
+        let hedge_delay = options
+            .and_then(|options| options.options.hedge_delay)
+            .or(self.hedge_delay)
+            .filter(|_| attempts > 1 && operation.is_read());
+        let sent = Attempts::default();
+
         let mut last_api_error: Option<apis::Error<E>> = None;
         // Loop up to the total number of available nodes.
-        for _ in 0..self.nodes.len() {
-            let node = self.get_next_node();
-            match api_call(&node.config).await {
-                Ok(response) => {
-                    node.set_health(true);
-                    return Ok(response);
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        for attempt in 1..=attempts {
+            let index = pinned_node.unwrap_or_else(|| self.get_next_node_index(None));
+            let (index, result) = match hedge_delay {
+                Some(delay) => {
+                    self.send_hedged(index, delay, options, &api_call, &sent)
+                        .await
                 }
+                None => (index, self.send(index, options, &api_call, &sent).await),
+            };
+
+            match result {
+                Ok(response) => return Ok(response),
                 Err(e) => {
                     if is_retriable(&e) {
-                        node.set_health(false);
+                        #[cfg(feature = "tracing")]
+                        if attempt < attempts {
+                            let config = options.map_or(&self.nodes[index].config, |options| {
+                                &options.configs[index]
+                            });
+                            let headers = options.map(|options| &options.options.headers);
+                            let api_keys = instrument::api_keys(config, headers);
+                            instrument::failover(&config.base_path, &e, &api_keys);
+                        }
                         last_api_error = Some(e);
                     } else {
-                        return Err(e.into());
+                        return Err(Error::Api {
+                            source: e,
+                            node: Some(self.nodes[index].config.base_path.clone()),
+                        });
                     }
                 }
             }
@@ -522,9 +778,142 @@ impl Client {
         Err(crate::Error::AllNodesFailed {
             source: last_api_error
                 .expect("No nodes were available to try, or all errors were non-retriable."),
+            attempts: sent.failed.into_inner().unwrap(),
         })
     }
 
+    /// Sends an API call to the node at `index`, and records its outcome in the health and
+    /// statistics of the node, its circuit and the node selector.
+    ///
+    /// `sent` records the requests sent for the API call.
+    async fn send<'a, F, Fut, T, E>(
+        &'a self,
+        index: usize,
+        options: Option<&'a PreparedOptions>,
+        api_call: &F,
+        sent: &Attempts,
+    ) -> Result<T, apis::Error<E>>
+    where
+        F: Fn(&'a configuration::Configuration) -> Fut,
+        Fut: Future<Output = Result<T, apis::Error<E>>>,
+        E: std::fmt::Debug + 'static,
+        apis::Error<E>: std::error::Error + 'static,
+    {
+        let node = &self.nodes[index];
+        let config = options.map_or(&node.config, |options| &options.configs[index]);
+        let permit = match &self.circuit_breaker {
+            Some(circuit_breaker) => match Permit::acquire(&node.circuit, circuit_breaker) {
+                Some(permit) => Some(permit),
+                None => {
+                    let error = apis::Error::Io(io::Error::new(
+                        io::ErrorKind::ConnectionRefused,
+                        format!(
+                            "The circuit breaker of the node `{}` is open.",
+                            node.config.base_path
+                        ),
+                    ));
+                    sent.fail(&node.config.base_path, &error, Duration::ZERO);
+                    return Err(error);
+                }
+            },
+            None => None,
+        };
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        let attempt = sent.sent.fetch_add(1, Ordering::Relaxed) + 1;
+
+        let future = api_call(config);
+        #[cfg(target_arch = "wasm32")]
+        let future = request_options::with_timeout(
+            options.and_then(|options| options.options.timeout),
+            future,
+        );
+        #[cfg(feature = "tracing")]
+        let (span, api_keys) = (
+            instrument::attempt_span(&config.base_path, attempt),
+            instrument::api_keys(config, options.map(|options| &options.options.headers)),
+        );
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, span.clone());
+        let request = self
+            .is_selectable(index)
+            .then(|| node_selector::Request::start(&*self.node_selector, index));
+        let started = Instant::now();
+        let result = future.await;
+        let latency = started.elapsed();
+        let failed = matches!(&result, Err(e) if is_retriable(e) && !is_rate_limited(e));
+        if let Some(request) = request {
+            request.finish(!failed);
+        }
+        if let Some(permit) = permit {
+            permit.finish(failed);
+        }
+        #[cfg(feature = "tracing")]
+        {
+            instrument::record_result(&span, latency, &result, &api_keys);
+            tracing::Span::current().record("attempts", sent.sent.load(Ordering::Relaxed));
+        }
+
+        if let Err(e) = &result
+            && is_retriable(e)
+        {
+            sent.fail(&node.config.base_path, e, latency);
+        }
+        match &result {
+            Err(e) if failed => {
+                node.stats.lock().unwrap().failure(latency, e.to_string());
+                // With a circuit breaker, failed requests only count towards opening the
+                // circuit; the node is marked down by the health checker.
+                if self.circuit_breaker.is_none() {
+                    node.set_health(false);
+                }
+            }
+            Ok(_) => {
+                node.stats.lock().unwrap().success(latency);
+                node.set_health(true);
+            }
+            Err(_) => node.stats.lock().unwrap().success(latency),
+        }
+        result
+    }
+
+    /// Sends an API call to the node at `index`, and to another available node if the first
+    /// has not answered after `delay`.
+    ///
+    /// Returns the first success or error that is not retriable together with the index of the
+    /// node that sent it, and cancels the other request.
+    async fn send_hedged<'a, F, Fut, T, E>(
+        &'a self,
+        index: usize,
+        delay: Duration,
+        options: Option<&'a PreparedOptions>,
+        api_call: &F,
+        sent: &Attempts,
+    ) -> (usize, Result<T, apis::Error<E>>)
+    where
+        F: Fn(&'a configuration::Configuration) -> Fut,
+        Fut: Future<Output = Result<T, apis::Error<E>>>,
+        E: std::fmt::Debug + 'static,
+        apis::Error<E>: std::error::Error + 'static,
+    {
+        let primary = pin!(self.send(index, options, api_call, sent));
+        let primary = match future::select(primary, pin!(timer::sleep(delay))).await {
+            Either::Left((result, _)) => return (index, result),
+            Either::Right((_, primary)) => primary,
+        };
+
+        let hedge_index = self.get_next_node_index(Some(index));
+        if hedge_index == index || !self.is_available(&self.nodes[hedge_index]) {
+            return (index, primary.await);
+        }
+        let hedge = pin!(self.send(hedge_index, options, api_call, sent));
+        match future::select(primary, hedge).await {
+            Either::Left((Err(e), hedge)) if is_retriable(&e) => (hedge_index, hedge.await),
+            Either::Right((Err(e), primary)) if is_retriable(&e) => (index, primary.await),
+            Either::Left((result, _)) => (index, result),
+            Either::Right((result, _)) => (hedge_index, result),
+        }
+    }
+
     /// Provides access to the collection aliases-related API endpoints.
     ///
     /// # Example
@@ -547,7 +936,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn aliases(&self) -> Aliases<'_> {
-        Aliases::new(self)
+        Aliases::new(self.into())
     }
 
     /// Provides access to a specific collection alias's-related API endpoints.
@@ -571,7 +960,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn alias<'a>(&'a self, alias_name: &'a str) -> Alias<'a> {
-        Alias::new(self, alias_name)
+        Alias::new(self.into(), alias_name)
     }
 
     /// Provides access to the analytics API endpoints.
@@ -596,7 +985,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn analytics(&self) -> Analytics<'_> {
-        Analytics::new(self)
+        Analytics::new(self.into())
     }
 
     /// Provides access to API endpoints for managing collections like `create()` and `retrieve()`.
@@ -620,7 +1009,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn collections(&self) -> Collections<'_> {
-        Collections::new(self)
+        Collections::new(self.into())
     }
 
     /// Provides access to API endpoints for a specific collection.
@@ -671,7 +1060,7 @@ impl Client {
     where
         D: DeserializeOwned + Serialize,
     {
-        Collection::new(self, collection_name)
+        Collection::new(self.into(), collection_name)
     }
 
     /// Provides access to API endpoints for a specific collection.
@@ -716,7 +1105,7 @@ impl Client {
     where
         D: Document,
     {
-        Collection::new(self, D::COLLECTION_NAME)
+        Collection::new(self.into(), D::COLLECTION_NAME)
     }
 
     /// Provides access to API endpoints for a specific collection using schemaless `serde_json::Value` documents.
@@ -752,7 +1141,258 @@ impl Client {
         &'c self,
         collection_name: impl Into<Cow<'c, str>>,
     ) -> Collection<'c, serde_json::Value> {
-        Collection::new(self, collection_name)
+        Collection::new(self.into(), collection_name)
+    }
+
+    /// Returns a handle to the collection of the document type `D` that owns a reference to the
+    /// client, like [`Client::collection`].
+    ///
+    /// The handle is `'static`, so it can be stored in the state of an application or moved into
+    /// a spawned task. It is cheap to clone, and is `Send + Sync` when `D` is.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use std::sync::Arc;
+    /// # use typesense::{Client, Typesense};
+    /// # use serde::{Serialize, Deserialize};
+    /// #
+    /// # #[derive(Typesense, Serialize, Deserialize, Debug)]
+    /// # struct Book { id: String, title: String }
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Arc::new(
+    ///     Client::builder()
+    ///         .nodes(vec!["http://localhost:8108"])
+    ///         .api_key("xyz")
+    ///         .build()?,
+    /// );
+    /// let books = client.collection_owned::<Book>();
+    ///
+    /// let book = tokio::spawn(async move { books.document("123").retrieve().await }).await??;
+    /// println!("Retrieved book: {:?}", book);
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn collection_owned<D>(self: &Arc<Self>) -> Collection<'static, D>
+    where
+        D: Document,
+    {
+        Collection::new(self.clone().into(), D::COLLECTION_NAME)
+    }
+
+    /// Returns a handle to the collection `collection_name` that owns a reference to the client
+    /// and the collection name, like [`Client::collection_named`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn collection_named_owned<D>(
+        self: &Arc<Self>,
+        collection_name: impl Into<String>,
+    ) -> Collection<'static, D>
+    where
+        D: DeserializeOwned + Serialize,
+    {
+        Collection::new(self.clone().into(), collection_name.into())
+    }
+
+    /// Returns a handle to the collection `collection_name` with schemaless `serde_json::Value`
+    /// documents that owns a reference to the client and the collection name, like
+    /// [`Client::collection_schemaless`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn collection_schemaless_owned(
+        self: &Arc<Self>,
+        collection_name: impl Into<String>,
+    ) -> Collection<'static, serde_json::Value> {
+        Collection::new(self.clone().into(), collection_name.into())
+    }
+
+    /// Returns an `Aliases` handle that owns a reference to the client, like [`Client::aliases`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn aliases_owned(self: &Arc<Self>) -> Aliases<'static> {
+        Aliases::new(self.clone().into())
+    }
+
+    /// Returns an `Alias` handle that owns a reference to the client and
+    /// `alias_name`, like [`Client::alias`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn alias_owned(self: &Arc<Self>, alias_name: impl Into<String>) -> Alias<'static> {
+        Alias::new(self.clone().into(), alias_name.into())
+    }
+
+    /// Returns an `Analytics` handle that owns a reference to the client,
+    /// like [`Client::analytics`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn analytics_owned(self: &Arc<Self>) -> Analytics<'static> {
+        Analytics::new(self.clone().into())
+    }
+
+    /// Returns a `Collections` handle that owns a reference to the client,
+    /// like [`Client::collections`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn collections_owned(self: &Arc<Self>) -> Collections<'static> {
+        Collections::new(self.clone().into())
+    }
+
+    /// Returns a `Conversations` handle that owns a reference to the client,
+    /// like [`Client::conversations`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn conversations_owned(self: &Arc<Self>) -> Conversations<'static> {
+        Conversations::new(self.clone().into())
+    }
+
+    /// Returns a `CurationSets` handle that owns a reference to the client,
+    /// like [`Client::curation_sets`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn curation_sets_owned(self: &Arc<Self>) -> CurationSets<'static> {
+        CurationSets::new(self.clone().into())
+    }
+
+    /// Returns a `CurationSet` handle that owns a reference to the client and
+    /// `curation_set_name`, like [`Client::curation_set`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn curation_set_owned(
+        self: &Arc<Self>,
+        curation_set_name: impl Into<String>,
+    ) -> CurationSet<'static> {
+        CurationSet::new(self.clone().into(), curation_set_name.into())
+    }
+
+    /// Returns a `Keys` handle that owns a reference to the client, like [`Client::keys`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn keys_owned(self: &Arc<Self>) -> Keys<'static> {
+        Keys::new(self.clone().into())
+    }
+
+    /// Returns a `Key` handle that owns a reference to the client, like [`Client::key`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn key_owned(self: &Arc<Self>, key_id: i64) -> Key<'static> {
+        Key::new(self.clone().into(), key_id)
+    }
+
+    /// Returns a `MultiSearch` handle that owns a reference to the client,
+    /// like [`Client::multi_search`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn multi_search_owned(self: &Arc<Self>) -> multi_search::MultiSearch<'static> {
+        multi_search::MultiSearch::new(self.clone().into())
+    }
+
+    /// Returns a `NlSearchModels` handle that owns a reference to the client,
+    /// like [`Client::nl_search_models`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn nl_search_models_owned(self: &Arc<Self>) -> NlSearchModels<'static> {
+        NlSearchModels::new(self.clone().into())
+    }
+
+    /// Returns a `NlSearchModel` handle that owns a reference to the client and
+    /// `model_id`, like [`Client::nl_search_model`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn nl_search_model_owned(
+        self: &Arc<Self>,
+        model_id: impl Into<String>,
+    ) -> NlSearchModel<'static> {
+        NlSearchModel::new(self.clone().into(), model_id.into())
+    }
+
+    /// Returns an `Operations` handle that owns a reference to the client,
+    /// like [`Client::operations`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn operations_owned(self: &Arc<Self>) -> Operations<'static> {
+        Operations::new(self.clone().into())
+    }
+
+    /// Returns a `Presets` handle that owns a reference to the client, like [`Client::presets`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn presets_owned(self: &Arc<Self>) -> Presets<'static> {
+        Presets::new(self.clone().into())
+    }
+
+    /// Returns a `Preset` handle that owns a reference to the client and
+    /// `preset_id`, like [`Client::preset`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn preset_owned(self: &Arc<Self>, preset_id: impl Into<String>) -> Preset<'static> {
+        Preset::new(self.clone().into(), preset_id.into())
+    }
+
+    /// Returns a `Stemming` handle that owns a reference to the client, like [`Client::stemming`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn stemming_owned(self: &Arc<Self>) -> Stemming<'static> {
+        Stemming::new(self.clone().into())
+    }
+
+    /// Returns a `Stopwords` handle that owns a reference to the client,
+    /// like [`Client::stopwords`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn stopwords_owned(self: &Arc<Self>) -> Stopwords<'static> {
+        Stopwords::new(self.clone().into())
+    }
+
+    /// Returns a `Stopword` handle that owns a reference to the client and
+    /// `set_id`, like [`Client::stopword`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn stopword_owned(self: &Arc<Self>, set_id: impl Into<String>) -> Stopword<'static> {
+        Stopword::new(self.clone().into(), set_id.into())
+    }
+
+    /// Returns a `SynonymSets` handle that owns a reference to the client,
+    /// like [`Client::synonym_sets`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn synonym_sets_owned(self: &Arc<Self>) -> SynonymSets<'static> {
+        SynonymSets::new(self.clone().into())
+    }
+
+    /// Returns a `SynonymSet` handle that owns a reference to the client and
+    /// `synonym_set_name`, like [`Client::synonym_set`].
+    ///
+    /// The handle is `'static`, see [`Client::collection_owned`].
+    #[inline]
+    pub fn synonym_set_owned(
+        self: &Arc<Self>,
+        synonym_set_name: impl Into<String>,
+    ) -> SynonymSet<'static> {
+        SynonymSet::new(self.clone().into(), synonym_set_name.into())
     }
 
     /// Returns a `Conversations` instance for managing conversation models.
@@ -776,7 +1416,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn conversations(&self) -> Conversations<'_> {
-        Conversations::new(self)
+        Conversations::new(self.into())
     }
 
     /// Provides access to endpoints for managing curation sets.
@@ -800,7 +1440,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn curation_sets(&self) -> CurationSets<'_> {
-        CurationSets::new(self)
+        CurationSets::new(self.into())
     }
 
     /// Provides access to endpoints for managing a specific curation set.
@@ -824,7 +1464,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn curation_set<'a>(&'a self, curation_set_name: &'a str) -> CurationSet<'a> {
-        CurationSet::new(self, curation_set_name)
+        CurationSet::new(self.into(), curation_set_name)
     }
 
     /// Provides access to endpoints for managing the collection of API keys.
@@ -855,7 +1495,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn keys(&self) -> Keys<'_> {
-        Keys::new(self)
+        Keys::new(self.into())
     }
 
     /// Provides access to endpoints for managing a single API key.
@@ -883,7 +1523,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn key(&self, key_id: i64) -> Key<'_> {
-        Key::new(self, key_id)
+        Key::new(self.into(), key_id)
     }
 
     /// Provides access to the multi search endpoint.
@@ -918,7 +1558,60 @@ impl Client {
     /// ```
     #[inline]
     pub fn multi_search(&self) -> multi_search::MultiSearch<'_> {
-        multi_search::MultiSearch::new(self)
+        multi_search::MultiSearch::new(self.into())
+    }
+
+    /// Provides access to endpoints for managing natural language search models.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use typesense::Client;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let nl_search_models = client.nl_search_models().retrieve().await.unwrap();
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn nl_search_models(&self) -> NlSearchModels<'_> {
+        NlSearchModels::new(self.into())
+    }
+
+    /// Provides access to endpoints for managing a specific natural language search model.
+    ///
+    /// # Arguments
+    /// * `model_id` - The ID of the natural language search model to manage.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use typesense::Client;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let nl_search_model = client.nl_search_model("gemini-model").retrieve().await.unwrap();
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn nl_search_model<'a>(&'a self, model_id: &'a str) -> NlSearchModel<'a> {
+        NlSearchModel::new(self.into(), model_id)
     }
 
     /// Provides access to top-level, non-namespaced API endpoints like `health` and `debug`.
@@ -942,7 +1635,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn operations(&self) -> Operations<'_> {
-        Operations::new(self)
+        Operations::new(self.into())
     }
 
     /// Provides access to endpoints for managing all of your presets.
@@ -967,7 +1660,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn presets(&self) -> Presets<'_> {
-        Presets::new(self)
+        Presets::new(self.into())
     }
 
     /// Provides access to endpoints for managing a single preset.
@@ -995,7 +1688,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn preset<'a>(&'a self, preset_id: &'a str) -> Preset<'a> {
-        Preset::new(self, preset_id)
+        Preset::new(self.into(), preset_id)
     }
 
     /// Provides access to the stemming-related API endpoints.
@@ -1021,7 +1714,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn stemming(&self) -> Stemming<'_> {
-        Stemming::new(self)
+        Stemming::new(self.into())
     }
 
     /// Provides access to endpoints for managing the collection of stopwords sets.
@@ -1046,7 +1739,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn stopwords(&self) -> Stopwords<'_> {
-        Stopwords::new(self)
+        Stopwords::new(self.into())
     }
 
     /// Provides access to endpoints for managing a single stopwords set.
@@ -1074,7 +1767,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn stopword<'a>(&'a self, set_id: &'a str) -> Stopword<'a> {
-        Stopword::new(self, set_id)
+        Stopword::new(self.into(), set_id)
     }
 
     /// Provides access to endpoints for managing all synonym sets.
@@ -1099,7 +1792,7 @@ impl Client {
     /// ```
     #[inline]
     pub fn synonym_sets(&self) -> SynonymSets<'_> {
-        SynonymSets::new(self)
+        SynonymSets::new(self.into())
     }
 
     /// Provides access to endpoints for managing a single synonym set.
@@ -1127,28 +1820,78 @@ impl Client {
     /// ```
     #[inline]
     pub fn synonym_set<'a>(&'a self, synonym_set_name: &'a str) -> SynonymSet<'a> {
-        SynonymSet::new(self, synonym_set_name)
+        SynonymSet::new(self.into(), synonym_set_name)
+    }
+}
+
+/// The requests sent for an API call.
+#[derive(Debug, Default)]
+struct Attempts {
+    /// The number of requests sent.
+    sent: AtomicUsize,
+    /// The requests that failed with a retriable error, in the order they failed.
+    failed: Mutex<Vec<NodeAttempt>>,
+}
+
+impl Attempts {
+    fn fail(&self, node: &str, error: &impl ToString, elapsed: Duration) {
+        self.failed.lock().unwrap().push(NodeAttempt {
+            node: node.to_owned(),
+            error: error.to_string(),
+            elapsed,
+        });
     }
 }
 
 /// A helper function to determine if an error is worth retrying on another node.
-fn is_retriable<E>(error: &apis::Error<E>) -> bool
+pub(crate) fn is_retriable<E>(error: &apis::Error<E>) -> bool
 where
     E: std::fmt::Debug + 'static,
     apis::Error<E>: std::error::Error + 'static,
 {
     match error {
         // Server-side errors (5xx) indicate a problem with the node, so we should try another.
-        apis::Error::ResponseError(content) => content.status.is_server_error(),
+        // A node that still rate limits the request after its retries may have another one
+        // that does not.
+        apis::Error::ResponseError(content) => {
+            content.status.is_server_error() || content.status == StatusCode::TOO_MANY_REQUESTS
+        }
 
         // Underlying reqwest errors (e.g., connection refused) are retriable.
         apis::Error::Reqwest(_) => true,
 
-        // Network-level errors from middleware are always retriable.
-        #[cfg(not(target_arch = "wasm32"))]
-        apis::Error::ReqwestMiddleware(_) => true,
+        // Requests that exceeded their `RequestOptions::timeout` on WebAssembly, or that were
+        // not sent because the circuit breaker of the node is open.
+        apis::Error::Io(e) => matches!(
+            e.kind(),
+            io::ErrorKind::TimedOut | io::ErrorKind::ConnectionRefused
+        ),
+
+        // Network-level errors that went through the middleware are retriable; errors
+        // returned by the middleware itself say nothing about the node.
+        apis::Error::ReqwestMiddleware(e) => is_network_error(e),
 
         // Client-side (4xx) or parsing errors are not retriable as the request is likely invalid.
         _ => false,
     }
 }
+
+/// Whether a middleware error is a network error, possibly wrapped by the retry layer once
+/// its retries are exhausted.
+fn is_network_error(error: &reqwest_middleware::Error) -> bool {
+    match error {
+        reqwest_middleware::Error::Reqwest(_) => true,
+        reqwest_middleware::Error::Middleware(e) => match e.downcast_ref::<RetryError>() {
+            Some(RetryError::WithRetries { err, .. } | RetryError::Error(err)) => {
+                is_network_error(err)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Returns whether the request was rejected by the rate limiting of the node, which does not
+/// make the node unhealthy.
+fn is_rate_limited<E>(error: &apis::Error<E>) -> bool {
+    matches!(error, apis::Error::ResponseError(content) if content.status == StatusCode::TOO_MANY_REQUESTS)
+}
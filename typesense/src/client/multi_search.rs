@@ -3,7 +3,9 @@
 //! A `MultiSearch` instance is created via the main `client.multi_search()` method.
 
 use crate::{
-    Client, Error, execute_wrapper,
+    Error,
+    client::ClientRef,
+    execute_wrapper,
     models::{MultiSearchBody, SearchResult},
 };
 use typesense_codegen::{
@@ -15,16 +17,18 @@ use typesense_codegen::{
 ///
 /// This struct is created by calling `client.multi_search()`.
 pub struct MultiSearch<'c> {
-    pub(super) client: &'c Client,
+    pub(super) client: ClientRef<'c>,
 }
 
 impl<'c> MultiSearch<'c> {
     /// Creates a new `MultiSearch` instance.
     #[inline]
-    pub(super) fn new(client: &'c Client) -> Self {
+    pub(super) fn new(client: ClientRef<'c>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Performs a **federated** multi-search operation, returning a list of search results.
     ///
     /// This function allows you to send multiple search queries in a single HTTP request, which is
@@ -124,7 +128,11 @@ impl<'c> MultiSearch<'c> {
         };
         let multi_search_params = build_multi_search_params(request_body, common_search_params);
 
-        let raw_result = execute_wrapper!(self, documents_api::multi_search, multi_search_params);
+        let raw_result = if is_conversation(&multi_search_params) {
+            execute_wrapper!(self, write documents_api::multi_search, multi_search_params)
+        } else {
+            execute_wrapper!(self, read documents_api::multi_search, multi_search_params)
+        };
 
         // Now, handle the raw result and parse it into the strong type.
         match raw_result {
@@ -263,7 +271,11 @@ impl<'c> MultiSearch<'c> {
         let multi_search_params = build_multi_search_params(request_body, common_search_params);
 
         // Execute the request to get the raw JSON value
-        let raw_result = execute_wrapper!(self, documents_api::multi_search, multi_search_params);
+        let raw_result = if is_conversation(&multi_search_params) {
+            execute_wrapper!(self, write documents_api::multi_search, multi_search_params)
+        } else {
+            execute_wrapper!(self, read documents_api::multi_search, multi_search_params)
+        };
 
         match raw_result {
             Ok(json_value) => serde_json::from_value(json_value).map_err(Error::from),
@@ -271,6 +283,20 @@ impl<'c> MultiSearch<'c> {
         }
     }
 }
+// Conversational searches add to the history of the conversation, so they are never sent
+// twice.
+fn is_conversation(params: &documents_api::MultiSearchParams<'_>) -> bool {
+    params.conversation == Some(true)
+        || params
+            .multi_search_searches_parameter
+            .as_ref()
+            .is_some_and(|body| {
+                body.searches
+                    .iter()
+                    .any(|search| search.conversation == Some(true))
+            })
+}
+
 // Private helper function to construct the final search parameters object.
 // This encapsulates the repetitive mapping logic.
 fn build_multi_search_params<'a>(
@@ -0,0 +1,68 @@
+//! Provides access to the API endpoints for managing a single natural language search model.
+//!
+//! An instance of `NlSearchModel` is created via the main `client.nl_search_model("model_id")` method.
+
+use crate::{Error, client::ClientRef, execute_wrapper};
+use ::std::borrow::Cow;
+use typesense_codegen::{apis::nl_search_models_api, models};
+
+/// Provides methods for interacting with a specific natural language search model.
+///
+/// This struct is created by calling `client.nl_search_model("model_id")`.
+pub struct NlSearchModel<'a> {
+    pub(super) client: ClientRef<'a>,
+    pub(super) model_id: Cow<'a, str>,
+}
+
+impl<'a> NlSearchModel<'a> {
+    /// Creates a new `NlSearchModel` instance for a specific model ID.
+    #[inline]
+    pub(super) fn new(client: ClientRef<'a>, model_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            client,
+            model_id: model_id.into(),
+        }
+    }
+
+    with_options!();
+
+    /// Retrieves the details of this specific natural language search model.
+    pub async fn retrieve(
+        &self,
+    ) -> Result<models::NlSearchModelSchema, Error<nl_search_models_api::RetrieveNlSearchModelError>>
+    {
+        let params = nl_search_models_api::RetrieveNlSearchModelParams {
+            model_id: self.model_id.as_ref().into(),
+        };
+        execute_wrapper!(self, read nl_search_models_api::retrieve_nl_search_model, params)
+    }
+
+    /// Updates this specific natural language search model.
+    ///
+    /// # Arguments
+    /// * `schema` - A `NlSearchModelUpdateSchema` object with the fields to update.
+    pub async fn update(
+        &self,
+        schema: models::NlSearchModelUpdateSchema<'_>,
+    ) -> Result<models::NlSearchModelSchema, Error<nl_search_models_api::UpdateNlSearchModelError>>
+    {
+        let params = nl_search_models_api::UpdateNlSearchModelParams {
+            model_id: self.model_id.as_ref().into(),
+            nl_search_model_update_schema: schema,
+        };
+        execute_wrapper!(self, write nl_search_models_api::update_nl_search_model, params)
+    }
+
+    /// Deletes this specific natural language search model.
+    pub async fn delete(
+        &self,
+    ) -> Result<
+        models::NlSearchModelDeleteSchema,
+        Error<nl_search_models_api::DeleteNlSearchModelError>,
+    > {
+        let params = nl_search_models_api::DeleteNlSearchModelParams {
+            model_id: self.model_id.as_ref().into(),
+        };
+        execute_wrapper!(self, write nl_search_models_api::delete_nl_search_model, params)
+    }
+}
@@ -0,0 +1,48 @@
+//! Provides access to the API endpoints for managing natural language search models.
+//!
+//! An instance of `NlSearchModels` is created via the main `client.nl_search_models()` method.
+
+use crate::{Error, client::ClientRef, execute_wrapper};
+use typesense_codegen::{apis::nl_search_models_api, models};
+
+/// Provides methods for creating and listing natural language search models.
+///
+/// This struct is created by calling `client.nl_search_models()`.
+pub struct NlSearchModels<'a> {
+    pub(super) client: ClientRef<'a>,
+}
+
+impl<'a> NlSearchModels<'a> {
+    /// Creates a new `NlSearchModels` instance.
+    #[inline]
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
+        Self { client }
+    }
+
+    with_options!();
+
+    /// Creates a new natural language search model.
+    ///
+    /// # Arguments
+    /// * `schema` - A `NlSearchModelCreateSchema` object describing the model.
+    pub async fn create(
+        &self,
+        schema: models::NlSearchModelCreateSchema<'_>,
+    ) -> Result<models::NlSearchModelSchema, Error<nl_search_models_api::CreateNlSearchModelError>>
+    {
+        let params = nl_search_models_api::CreateNlSearchModelParams {
+            nl_search_model_create_schema: schema,
+        };
+        execute_wrapper!(self, write nl_search_models_api::create_nl_search_model, params)
+    }
+
+    /// Retrieves a summary of all natural language search models.
+    pub async fn retrieve(
+        &self,
+    ) -> Result<
+        Vec<models::NlSearchModelSchema>,
+        Error<nl_search_models_api::RetrieveAllNlSearchModelsError>,
+    > {
+        execute_wrapper!(self, read nl_search_models_api::retrieve_all_nl_search_models)
+    }
+}
@@ -0,0 +1,323 @@
+//! Strategies that select the node each request is sent to.
+//!
+//! A strategy is set with `Client::builder().node_selector(..)`, and defaults to [`RoundRobin`].
+//! Whatever the strategy, the client keeps its failover behavior: a request that fails on a node
+//! is sent to another one, the `nearest_node` is always tried first while it is healthy, and
+//! unhealthy nodes are only selected again after `healthcheck_interval`, or when no other node
+//! is left.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # #[cfg(not(target_family = "wasm"))]
+//! # {
+//! use typesense::{Client, node_selector::Ewma};
+//!
+//! let client = Client::builder()
+//!     .nodes(vec!["http://eu-1:8108", "http://us-1:8108", "http://ap-1:8108"])
+//!     .api_key("xyz")
+//!     .node_selector(Ewma::default())
+//!     .build()
+//!     .unwrap();
+//! # }
+//! ```
+
+use ::std::{
+    fmt,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+use web_time::{Duration, Instant};
+
+/// A node that a request can be sent to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Candidate<'a> {
+    /// The URL of the node.
+    pub url: &'a str,
+    /// Whether the node is healthy, or unhealthy but due to be tried again.
+    pub is_available: bool,
+}
+
+/// Selects the node each request is sent to.
+///
+/// Nodes are identified by their index in the `nodes` the client was built with. The
+/// `nearest_node` is never passed to a selector.
+pub trait NodeSelector: fmt::Debug + Send + Sync {
+    /// Returns the index of the node in `candidates` to send a request to.
+    ///
+    /// `candidates` contains every node, and is never empty. When the returned node is not
+    /// available, the next available node is used instead. An index out of bounds wraps around.
+    fn select(&self, candidates: &[Candidate<'_>]) -> usize;
+
+    /// Called when a request is sent to `node`.
+    fn request_started(&self, node: usize) {
+        let _ = node;
+    }
+
+    /// Called when a request sent to `node` completes. `succeeded` is false when the request
+    /// failed with a server or network error.
+    fn request_finished(&self, node: usize, latency: Duration, succeeded: bool) {
+        let _ = (node, latency, succeeded);
+    }
+}
+
+impl<S: NodeSelector + ?Sized> NodeSelector for Arc<S> {
+    fn select(&self, candidates: &[Candidate<'_>]) -> usize {
+        (**self).select(candidates)
+    }
+
+    fn request_started(&self, node: usize) {
+        (**self).request_started(node);
+    }
+
+    fn request_finished(&self, node: usize, latency: Duration, succeeded: bool) {
+        (**self).request_finished(node, latency, succeeded);
+    }
+}
+
+/// Sends requests to the available nodes in turn. This is the default strategy.
+#[derive(Debug, Default)]
+pub struct RoundRobin {
+    next: AtomicUsize,
+}
+
+impl NodeSelector for RoundRobin {
+    fn select(&self, candidates: &[Candidate<'_>]) -> usize {
+        let mut index = self.next.fetch_add(1, Ordering::Relaxed) % candidates.len();
+        for _ in 0..candidates.len() {
+            if candidates[index].is_available {
+                return index;
+            }
+            index = self.next.fetch_add(1, Ordering::Relaxed) % candidates.len();
+        }
+        // If all nodes are unhealthy and not due for a check, just pick the next one in the
+        // round-robin. This gives it a chance to prove it has recovered.
+        self.next.load(Ordering::Relaxed) % candidates.len()
+    }
+}
+
+/// Prefers the nodes with the lowest latency.
+///
+/// The latency of each node is tracked as an exponentially weighted moving average (EWMA), and
+/// nodes are picked at random with a probability inversely proportional to it. Slower nodes
+/// thus still receive some requests, which keeps their average up to date. Nodes without a
+/// measured latency are tried first.
+#[derive(Debug)]
+pub struct Ewma {
+    alpha: f64,
+    /// The average latency of each node in seconds, if measured.
+    latencies: Mutex<Vec<Option<f64>>>,
+}
+
+impl Ewma {
+    /// The latency recorded for a failed request, if it failed faster.
+    const FAILURE_LATENCY: Duration = Duration::from_secs(1);
+
+    /// Creates a selector whose averages give the weight `alpha` to each new latency.
+    ///
+    /// `alpha` is clamped to `0.0..=1.0`; higher values adapt faster to latency changes.
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            latencies: Mutex::default(),
+        }
+    }
+
+    /// Returns the average latency of `node`, if measured.
+    pub fn latency(&self, node: usize) -> Option<Duration> {
+        let latencies = self.latencies.lock().unwrap();
+        latencies
+            .get(node)
+            .copied()
+            .flatten()
+            .map(Duration::from_secs_f64)
+    }
+}
+
+impl Default for Ewma {
+    /// Creates a selector with an `alpha` of 0.3.
+    fn default() -> Self {
+        Self::new(0.3)
+    }
+}
+
+impl NodeSelector for Ewma {
+    fn select(&self, candidates: &[Candidate<'_>]) -> usize {
+        let indices = available(candidates);
+        let latencies = self.latencies.lock().unwrap();
+        let latency = |index: usize| latencies.get(index).copied().flatten();
+
+        if let Some(&index) = indices.iter().find(|&&index| latency(index).is_none()) {
+            return index;
+        }
+        let weight = |index: usize| 1.0 / latency(index).unwrap_or_default().max(1e-6);
+        let total: f64 = indices.iter().map(|&index| weight(index)).sum();
+        let mut target = random_f64() * total;
+        for &index in &indices {
+            target -= weight(index);
+            if target <= 0.0 {
+                return index;
+            }
+        }
+        indices[indices.len() - 1]
+    }
+
+    fn request_finished(&self, node: usize, latency: Duration, succeeded: bool) {
+        let latency = if succeeded {
+            latency
+        } else {
+            latency.max(Self::FAILURE_LATENCY)
+        };
+        let mut latencies = self.latencies.lock().unwrap();
+        if latencies.len() <= node {
+            latencies.resize(node + 1, None);
+        }
+        let sample = latency.as_secs_f64();
+        latencies[node] = Some(match latencies[node] {
+            Some(average) => self.alpha * sample + (1.0 - self.alpha) * average,
+            None => sample,
+        });
+    }
+}
+
+/// Sends each request to the available node with the fewest requests in flight.
+///
+/// Ties are broken in turn, so that idle nodes share the requests evenly.
+#[derive(Debug, Default)]
+pub struct LeastInFlight {
+    next: AtomicUsize,
+    in_flight: Mutex<Vec<usize>>,
+}
+
+impl LeastInFlight {
+    /// Returns the number of requests in flight to `node`.
+    pub fn in_flight(&self, node: usize) -> usize {
+        let in_flight = self.in_flight.lock().unwrap();
+        in_flight.get(node).copied().unwrap_or_default()
+    }
+}
+
+impl NodeSelector for LeastInFlight {
+    fn select(&self, candidates: &[Candidate<'_>]) -> usize {
+        let indices = available(candidates);
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let in_flight = self.in_flight.lock().unwrap();
+        (0..indices.len())
+            .map(|offset| indices[(start + offset) % indices.len()])
+            .min_by_key(|&index| in_flight.get(index).copied().unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    fn request_started(&self, node: usize) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight.len() <= node {
+            in_flight.resize(node + 1, 0);
+        }
+        in_flight[node] += 1;
+    }
+
+    fn request_finished(&self, node: usize, _latency: Duration, _succeeded: bool) {
+        if let Some(count) = self.in_flight.lock().unwrap().get_mut(node) {
+            *count = count.saturating_sub(1);
+        }
+    }
+}
+
+/// Sends all requests to a node picked at random, until it becomes unavailable.
+///
+/// This spreads many clients, such as the instances of an application, over the nodes, while
+/// each client keeps sending its requests to the same node. When the node becomes unavailable,
+/// another one is picked at random.
+#[derive(Debug)]
+pub struct RandomAffinity {
+    node: AtomicUsize,
+}
+
+impl Default for RandomAffinity {
+    fn default() -> Self {
+        Self {
+            node: AtomicUsize::new(usize::MAX),
+        }
+    }
+}
+
+impl NodeSelector for RandomAffinity {
+    fn select(&self, candidates: &[Candidate<'_>]) -> usize {
+        let node = self.node.load(Ordering::Relaxed);
+        if candidates.get(node).is_some_and(|node| node.is_available) {
+            return node;
+        }
+        let indices = available(candidates);
+        let node = indices[(random_f64() * indices.len() as f64) as usize % indices.len()];
+        self.node.store(node, Ordering::Relaxed);
+        node
+    }
+}
+
+/// A request sent to a node, reported to the selector when it is finished or dropped.
+pub(super) struct Request<'a> {
+    selector: &'a dyn NodeSelector,
+    node: usize,
+    started: Instant,
+    succeeded: bool,
+}
+
+impl<'a> Request<'a> {
+    pub(super) fn start(selector: &'a dyn NodeSelector, node: usize) -> Self {
+        selector.request_started(node);
+        Self {
+            selector,
+            node,
+            started: Instant::now(),
+            // A request that is dropped before it completes is not a failure of the node.
+            succeeded: true,
+        }
+    }
+
+    pub(super) fn finish(mut self, succeeded: bool) {
+        self.succeeded = succeeded;
+    }
+}
+
+impl Drop for Request<'_> {
+    fn drop(&mut self) {
+        self.selector
+            .request_finished(self.node, self.started.elapsed(), self.succeeded);
+    }
+}
+
+/// Returns the indices of the available candidates, or of all candidates if none is available.
+fn available(candidates: &[Candidate<'_>]) -> Vec<usize> {
+    let indices: Vec<_> = (0..candidates.len())
+        .filter(|&index| candidates[index].is_available)
+        .collect();
+    if indices.is_empty() {
+        (0..candidates.len()).collect()
+    } else {
+        indices
+    }
+}
+
+/// Returns a random number in `0.0..1.0`.
+///
+/// The keys of `RandomState` are seeded by the operating system, and a counter makes every
+/// number different.
+#[cfg(not(target_arch = "wasm32"))]
+fn random_f64() -> f64 {
+    use ::std::{collections::hash_map::RandomState, hash::BuildHasher};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let random = RandomState::new().hash_one(COUNTER.fetch_add(1, Ordering::Relaxed));
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Returns a random number in `0.0..1.0`.
+///
+/// `RandomState` has no source of entropy on `wasm32-unknown-unknown`, and would return the
+/// same sequence in every page, so the random number generator of the browser is used instead.
+#[cfg(target_arch = "wasm32")]
+fn random_f64() -> f64 {
+    js_sys::Math::random()
+}
@@ -0,0 +1,117 @@
+//! Health and latency statistics of the nodes of a client, returned by `Client::node_stats`.
+
+use super::CircuitState;
+use ::std::collections::VecDeque;
+use web_time::Duration;
+
+/// The number of most recent request latencies kept for each node.
+const LATENCY_SAMPLES: usize = 256;
+
+/// The health and request statistics of a single node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeStats {
+    /// The URL of the node.
+    pub url: String,
+    /// Whether the node is currently considered healthy. A node whose circuit is open is not.
+    pub is_healthy: bool,
+    /// The time left until an unhealthy node is tried again, or `None` if the node is healthy.
+    ///
+    /// This is zero when the node is due to be tried by the next request. With a circuit
+    /// breaker, this includes the time left until an open circuit is half-open.
+    pub retry_in: Option<Duration>,
+    /// The state of the circuit of the node, or `None` without a circuit breaker.
+    pub circuit: Option<CircuitState>,
+    /// The number of requests the node answered.
+    ///
+    /// Responses with a client error status (4xx) count as successes, since the node answered.
+    pub successes: u64,
+    /// The number of requests that failed on the node with a server or network error.
+    pub failures: u64,
+    /// The error of the most recent failed request.
+    pub last_error: Option<String>,
+    /// The latency percentiles of the most recent requests, or `None` if no request was sent
+    /// to the node yet.
+    pub latency: Option<LatencyPercentiles>,
+}
+
+/// Percentiles of the latency of the most recent requests sent to a node, including the
+/// retries made on the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LatencyPercentiles {
+    /// The number of requests the percentiles are computed from.
+    pub samples: usize,
+    /// The median latency.
+    pub p50: Duration,
+    /// The 90th percentile latency.
+    pub p90: Duration,
+    /// The 99th percentile latency.
+    pub p99: Duration,
+    /// The highest latency.
+    pub max: Duration,
+}
+
+/// The request statistics recorded for a node.
+#[derive(Debug, Default)]
+pub(super) struct Recorder {
+    successes: u64,
+    failures: u64,
+    last_error: Option<String>,
+    latencies: VecDeque<Duration>,
+}
+
+impl Recorder {
+    /// Records a request the node answered.
+    pub(super) fn success(&mut self, latency: Duration) {
+        self.successes += 1;
+        self.push_latency(latency);
+    }
+
+    /// Records a request that failed on the node with `error`.
+    pub(super) fn failure(&mut self, latency: Duration, error: String) {
+        self.failures += 1;
+        self.last_error = Some(error);
+        self.push_latency(latency);
+    }
+
+    fn push_latency(&mut self, latency: Duration) {
+        if self.latencies.len() == LATENCY_SAMPLES {
+            self.latencies.pop_front();
+        }
+        self.latencies.push_back(latency);
+    }
+
+    /// Returns the statistics of the node with the given URL and health.
+    pub(super) fn stats(
+        &self,
+        url: &str,
+        is_healthy: bool,
+        retry_in: Option<Duration>,
+        circuit: Option<CircuitState>,
+    ) -> NodeStats {
+        NodeStats {
+            url: url.to_owned(),
+            is_healthy,
+            retry_in,
+            circuit,
+            successes: self.successes,
+            failures: self.failures,
+            last_error: self.last_error.clone(),
+            latency: self.percentiles(),
+        }
+    }
+
+    fn percentiles(&self) -> Option<LatencyPercentiles> {
+        let mut latencies: Vec<_> = self.latencies.iter().copied().collect();
+        latencies.sort_unstable();
+        let max = *latencies.last()?;
+        // The nearest-rank percentile.
+        let percentile = |p: usize| latencies[(latencies.len() * p).div_ceil(100).max(1) - 1];
+        Some(LatencyPercentiles {
+            samples: latencies.len(),
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! An `Operations` instance is created via the main `client.operations()` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper, models::RuntimeConfig};
 use typesense_codegen::{
     apis::{debug_api, health_api, operations_api},
     models,
@@ -12,15 +12,17 @@ use typesense_codegen::{
 ///
 /// This struct is created by calling `client.operations()`.
 pub struct Operations<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Operations<'a> {
     /// Creates a new `Operations` instance
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
+
+    with_options!();
     /// Retrieves debugging information from a Typesense node.
     ///
     /// This method will try nodes in sequence according to the health policy
@@ -29,7 +31,7 @@ impl<'a> Operations<'a> {
     ///
     /// Docs: <https://typesense.org/docs/latest/api/cluster-operations.html#debug>
     pub async fn debug(&self) -> Result<models::Debug200Response, Error<debug_api::DebugError>> {
-        execute_wrapper!(self, debug_api::debug)
+        execute_wrapper!(self, read debug_api::debug)
     }
 
     /// Get health information about a Typesense node.
@@ -37,7 +39,7 @@ impl<'a> Operations<'a> {
     ///
     /// Docs: <https://typesense.org/docs/latest/api/cluster-operations.html#health>
     pub async fn health(&self) -> Result<models::HealthStatus, Error<health_api::HealthError>> {
-        execute_wrapper!(self, health_api::health)
+        execute_wrapper!(self, read health_api::health)
     }
 
     /// Get current RAM, CPU, Disk & Network usage metrics.
@@ -71,7 +73,7 @@ impl<'a> Operations<'a> {
     pub async fn retrieve_metrics(
         &self,
     ) -> Result<serde_json::Value, Error<operations_api::RetrieveMetricsError>> {
-        execute_wrapper!(self, operations_api::retrieve_metrics)
+        execute_wrapper!(self, read operations_api::retrieve_metrics)
     }
 
     /// Get stats about API endpoints.
@@ -96,7 +98,7 @@ impl<'a> Operations<'a> {
     pub async fn retrieve_api_stats(
         &self,
     ) -> Result<models::ApiStatsResponse, Error<operations_api::RetrieveApiStatsError>> {
-        execute_wrapper!(self, operations_api::retrieve_api_stats)
+        execute_wrapper!(self, read operations_api::retrieve_api_stats)
     }
 
     /// Creates a point-in-time snapshot of a Typesense node's state and data in the specified directory.
@@ -107,7 +109,7 @@ impl<'a> Operations<'a> {
         &self,
         params: operations_api::TakeSnapshotParams<'_>,
     ) -> Result<models::SuccessStatus, Error<operations_api::TakeSnapshotError>> {
-        execute_wrapper!(self, operations_api::take_snapshot, params)
+        execute_wrapper!(self, write operations_api::take_snapshot, params)
     }
 
     /// Triggers a follower node to initiate the raft voting process, which triggers leader re-election.
@@ -115,7 +117,7 @@ impl<'a> Operations<'a> {
     ///
     /// Docs: <https://typesense.org/docs/latest/api/cluster-operations.html#re-elect-leader>
     pub async fn vote(&self) -> Result<models::SuccessStatus, Error<operations_api::VoteError>> {
-        execute_wrapper!(self, operations_api::vote)
+        execute_wrapper!(self, write operations_api::vote)
     }
 
     /// You can check the status of in-progress schema change operations by using the schema changes endpoint.
@@ -125,7 +127,7 @@ impl<'a> Operations<'a> {
         &self,
     ) -> Result<Option<Vec<models::SchemaChangeStatus>>, Error<operations_api::GetSchemaChangesError>>
     {
-        execute_wrapper!(self, operations_api::get_schema_changes)
+        execute_wrapper!(self, read operations_api::get_schema_changes)
     }
 
     /// Typesense uses RocksDB to store your documents on the disk. If you do frequent writes or updates, you could benefit from running a compaction of the underlying RocksDB database. This could reduce the size of the database and decrease read latency.
@@ -135,7 +137,7 @@ impl<'a> Operations<'a> {
     pub async fn compact_db(
         &self,
     ) -> Result<models::SuccessStatus, Error<operations_api::CompactDbError>> {
-        execute_wrapper!(self, operations_api::compact_db)
+        execute_wrapper!(self, write operations_api::compact_db)
     }
 
     /// Responses of search requests that are sent with `use_cache` parameter are cached in a LRU cache. This operation will clear the cache completely.
@@ -144,22 +146,62 @@ impl<'a> Operations<'a> {
     pub async fn clear_cache(
         &self,
     ) -> Result<models::SuccessStatus, Error<operations_api::ClearCacheError>> {
-        execute_wrapper!(self, operations_api::clear_cache)
+        execute_wrapper!(self, write operations_api::clear_cache)
     }
 
     /// Enable logging of requests that take over a defined threshold of time.
     /// Default: `-1` which disables slow request logging.
+    /// Use [`Operations::update_config`] to change other runtime settings.
     ///
     /// Docs: <https://typesense.org/docs/latest/api/cluster-operations.html#toggle-slow-request-log>
     pub async fn toggle_slow_request_log(
         &self,
         slow_requests_threshold_ms: i32,
-    ) -> Result<models::SuccessStatus, Error<operations_api::ToggleSlowRequestLogError>> {
-        let params = operations_api::ToggleSlowRequestLogParams {
-            toggle_slow_request_log_request: Some(models::ToggleSlowRequestLogRequest {
+    ) -> Result<models::SuccessStatus, Error<operations_api::UpdateConfigError>> {
+        let params = operations_api::UpdateConfigParams {
+            body: models::ToggleSlowRequestLogRequest {
                 log_slow_requests_time_ms: slow_requests_threshold_ms,
-            }),
+            },
         };
-        execute_wrapper!(self, operations_api::toggle_slow_request_log, params)
+        execute_wrapper!(self, write operations_api::update_config, params)
+    }
+
+    /// Updates the runtime configuration of the Typesense nodes.
+    ///
+    /// Only the settings that are set in `config` are changed. Typesense has no endpoint that
+    /// returns the runtime configuration, so the current settings of a node cannot be read back.
+    ///
+    /// Like other writes, the request is sent to a single node; to change the settings of
+    /// every node in a cluster, use one client per node.
+    ///
+    /// # Arguments
+    /// * `config` - A `RuntimeConfig` with the settings to change.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use typesense::{Client, models::RuntimeConfig};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let config = RuntimeConfig::builder()
+    ///     .log_slow_requests_time_ms(2000)
+    ///     .enable_search_logging(true)
+    ///     .build();
+    /// client.operations().update_config(&config).await?;
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub async fn update_config(
+        &self,
+        config: &RuntimeConfig,
+    ) -> Result<models::SuccessStatus, Error<operations_api::UpdateConfigError>> {
+        let params = operations_api::UpdateConfigParams { body: config };
+        execute_wrapper!(self, write operations_api::update_config, params)
     }
 }
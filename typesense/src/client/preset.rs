@@ -2,32 +2,38 @@
 //!
 //! A `Preset` instance is created via the main `client.preset("preset_id")` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
+use ::std::borrow::Cow;
 use typesense_codegen::{apis::presets_api, models};
 
 /// Provides methods for managing a single Typesense preset.
 ///
 /// This struct is created by calling `client.preset("preset_id")`.
 pub struct Preset<'a> {
-    pub(super) client: &'a Client,
-    pub(super) preset_id: &'a str,
+    pub(super) client: ClientRef<'a>,
+    pub(super) preset_id: Cow<'a, str>,
 }
 
 impl<'a> Preset<'a> {
     /// Creates a new `Preset` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client, preset_id: &'a str) -> Self {
-        Self { client, preset_id }
+    pub(super) fn new(client: ClientRef<'a>, preset_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            client,
+            preset_id: preset_id.into(),
+        }
     }
 
+    with_options!();
+
     /// Retrieves the details of a preset, given its Id.
     pub async fn retrieve(
         &self,
     ) -> Result<models::PresetSchema, Error<presets_api::RetrievePresetError>> {
         let params = presets_api::RetrievePresetParams {
-            preset_id: self.preset_id.into(),
+            preset_id: self.preset_id.as_ref().into(),
         };
-        execute_wrapper!(self, presets_api::retrieve_preset, params)
+        execute_wrapper!(self, read presets_api::retrieve_preset, params)
     }
 
     /// Permanently deletes a preset, given its Id.
@@ -35,8 +41,8 @@ impl<'a> Preset<'a> {
         &self,
     ) -> Result<models::PresetDeleteSchema, Error<presets_api::DeletePresetError>> {
         let params = presets_api::DeletePresetParams {
-            preset_id: self.preset_id.into(),
+            preset_id: self.preset_id.as_ref().into(),
         };
-        execute_wrapper!(self, presets_api::delete_preset, params)
+        execute_wrapper!(self, write presets_api::delete_preset, params)
     }
 }
@@ -4,7 +4,7 @@
 //!
 //! A `Presets` instance is created via the main `client.presets()` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use ::std::borrow::Cow;
 use typesense_codegen::{apis::presets_api, models};
 
@@ -12,21 +12,23 @@ use typesense_codegen::{apis::presets_api, models};
 ///
 /// This struct is created by calling `client.presets()`.
 pub struct Presets<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Presets<'a> {
     /// Creates a new `Presets` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Retrieves the details of all presets.
     pub async fn retrieve(
         &self,
     ) -> Result<models::PresetsRetrieveSchema, Error<presets_api::RetrieveAllPresetsError>> {
-        execute_wrapper!(self, presets_api::retrieve_all_presets)
+        execute_wrapper!(self, read presets_api::retrieve_all_presets)
     }
 
     /// Creates or updates an existing preset.
@@ -43,6 +45,6 @@ impl<'a> Presets<'a> {
             preset_id: preset_id.into(),
             preset_upsert_schema: schema,
         };
-        execute_wrapper!(self, presets_api::upsert_preset, params)
+        execute_wrapper!(self, write presets_api::upsert_preset, params)
     }
 }
@@ -0,0 +1,269 @@
+//! Client-side limits on the rate and concurrency of API calls.
+
+use super::timer;
+use ::std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::Mutex,
+    task::{Context, Poll, Waker},
+};
+use web_time::{Duration, Instant};
+
+/// Limits the API calls a client makes, so that bulk jobs throttle themselves instead of
+/// overwhelming a shared cluster.
+///
+/// Calls beyond the limits wait until they are allowed, instead of failing. The limits apply
+/// to API calls, including all the nodes and retries a call is sent to, and to all the requests
+/// of the client together.
+///
+/// - `requests_per_second` enables a token bucket: calls are started at that rate on average,
+///   with bursts of up to `burst` calls.
+/// - `max_concurrency` caps the number of calls in progress at the same time.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(not(target_family = "wasm"))]
+/// # {
+/// use typesense::{Client, RateLimiter};
+///
+/// let client = Client::builder()
+///     .nodes(vec!["http://localhost:8108"])
+///     .api_key("xyz")
+///     .rate_limiter(
+///         RateLimiter::builder()
+///             .requests_per_second(50.0)
+///             .max_concurrency(4)
+///             .build(),
+///     )
+///     .build()
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    bucket: Option<Mutex<Bucket>>,
+    concurrency: Option<Semaphore>,
+}
+
+#[bon::bon]
+impl RateLimiter {
+    /// Creates a new `RateLimiter`. Without any limit, calls are never delayed.
+    ///
+    /// Default values:
+    /// - **requests_per_second**: None.
+    /// - **burst**: `requests_per_second` rounded up, and at least 1.
+    /// - **max_concurrency**: None.
+    #[builder]
+    pub fn new(
+        /// The average number of calls started per second. Must be positive.
+        requests_per_second: Option<f64>,
+        /// The number of calls that can be started at once after a period of inactivity.
+        burst: Option<u32>,
+        /// The maximum number of calls in progress at the same time.
+        max_concurrency: Option<usize>,
+    ) -> Self {
+        let bucket = requests_per_second.filter(|rate| *rate > 0.0).map(|rate| {
+            let burst = burst.map_or(rate.ceil(), f64::from).max(1.0);
+            Mutex::new(Bucket {
+                rate,
+                burst,
+                tokens: burst,
+                updated: Instant::now(),
+            })
+        });
+        Self {
+            bucket,
+            concurrency: max_concurrency.map(|limit| Semaphore {
+                limit: limit.max(1),
+                state: Mutex::default(),
+            }),
+        }
+    }
+
+    /// Waits until a call is allowed, and returns the permit held while it is in progress.
+    pub(super) async fn acquire(&self) -> Permit<'_> {
+        let permit = match &self.concurrency {
+            Some(semaphore) => Permit(Some(semaphore.acquire().await)),
+            None => Permit(None),
+        };
+        if let Some(bucket) = &self.bucket {
+            let delay = bucket.lock().unwrap().reserve();
+            if !delay.is_zero() {
+                timer::sleep(delay).await;
+            }
+        }
+        permit
+    }
+}
+
+/// A token bucket, refilled continuously at `rate` tokens per second.
+#[derive(Debug)]
+struct Bucket {
+    rate: f64,
+    burst: f64,
+    /// The tokens left, negative when calls are waiting for tokens that are not refilled yet.
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    /// Takes a token, and returns the time until it is available.
+    fn reserve(&mut self) -> Duration {
+        let now = Instant::now();
+        let refilled = now.duration_since(self.updated).as_secs_f64() * self.rate;
+        self.tokens = (self.tokens + refilled).min(self.burst) - 1.0;
+        self.updated = now;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+/// Limits the number of calls in progress.
+///
+/// Calls wait in a FIFO queue: a finished call hands its slot over to the call that has been
+/// waiting the longest, and wakes that call only.
+#[derive(Debug)]
+struct Semaphore {
+    limit: usize,
+    state: Mutex<SemaphoreState>,
+}
+
+#[derive(Debug, Default)]
+struct SemaphoreState {
+    in_progress: usize,
+    /// The identifier of the next call that starts waiting.
+    next_waiter: u64,
+    /// The calls waiting for a call to finish, in the order they started waiting.
+    waiters: VecDeque<Waiter>,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    id: u64,
+    waker: Waker,
+    /// Whether a finished call handed its slot over to this call.
+    granted: bool,
+}
+
+impl SemaphoreState {
+    /// Frees a slot, handing it over to the longest waiting call if there is one, and returns
+    /// the waker of that call.
+    fn release(&mut self) -> Option<Waker> {
+        match self.waiters.iter_mut().find(|waiter| !waiter.granted) {
+            Some(waiter) => {
+                waiter.granted = true;
+                Some(waiter.waker.clone())
+            }
+            None => {
+                self.in_progress -= 1;
+                None
+            }
+        }
+    }
+}
+
+impl Semaphore {
+    fn acquire(&self) -> Acquire<'_> {
+        Acquire {
+            semaphore: self,
+            waiter: None,
+        }
+    }
+
+    fn release(&self) {
+        let waker = self.state.lock().unwrap().release();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Waits for a slot of a [`Semaphore`], and leaves the queue when dropped before getting one.
+struct Acquire<'a> {
+    semaphore: &'a Semaphore,
+    /// The identifier of the call in the queue, once it waits.
+    waiter: Option<u64>,
+}
+
+impl<'a> Future for Acquire<'a> {
+    type Output = &'a Semaphore;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let semaphore = self.semaphore;
+        let mut state = semaphore.state.lock().unwrap();
+        let Some(id) = self.waiter else {
+            // Free slots are only left while no call waits.
+            if state.in_progress < semaphore.limit {
+                state.in_progress += 1;
+                return Poll::Ready(semaphore);
+            }
+            let id = state.next_waiter;
+            state.next_waiter += 1;
+            state.waiters.push_back(Waiter {
+                id,
+                waker: cx.waker().clone(),
+                granted: false,
+            });
+            drop(state);
+            self.waiter = Some(id);
+            return Poll::Pending;
+        };
+
+        let index = state
+            .waiters
+            .iter()
+            .position(|waiter| waiter.id == id)
+            .expect("a waiting call stays queued until it gets a slot");
+        if state.waiters[index].granted {
+            state.waiters.remove(index);
+            drop(state);
+            self.waiter = None;
+            return Poll::Ready(semaphore);
+        }
+        let waker = &mut state.waiters[index].waker;
+        if !waker.will_wake(cx.waker()) {
+            *waker = cx.waker().clone();
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let Some(id) = self.waiter else {
+            return;
+        };
+        let waker = {
+            let mut state = self.semaphore.state.lock().unwrap();
+            let Some(index) = state.waiters.iter().position(|waiter| waiter.id == id) else {
+                return;
+            };
+            let waiter = state.waiters.remove(index).unwrap();
+            // A slot handed over to a call that gave up goes to the next one.
+            if waiter.granted {
+                state.release()
+            } else {
+                None
+            }
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Allows a call to proceed, and frees its concurrency slot when dropped.
+pub(super) struct Permit<'a>(Option<&'a Semaphore>);
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if let Some(semaphore) = self.0 {
+            semaphore.release();
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Options that change how individual requests are sent.
+//!
+//! Options are attached to a namespace with its `with_options` method and are inherited by every
+//! namespace created from it.
+
+use super::{Client, Node, Operation, middleware::Overrides};
+use ::std::{ops::Deref, sync::Arc};
+use reqwest::header::HeaderMap;
+use typesense_codegen::apis::configuration;
+use web_time::Duration;
+
+/// Adds a `with_options` method to a namespace that stores its client in a `client` field.
+macro_rules! with_options {
+    () => {
+        /// Returns this namespace with `options` applied to all of its requests.
+        ///
+        /// The options are inherited by every namespace created from the returned one, and
+        /// replace any options that were applied before.
+        #[inline]
+        pub fn with_options(mut self, options: $crate::RequestOptions) -> Self {
+            self.client = self.client.with_options(options);
+            self
+        }
+    };
+}
+
+/// Per-request settings that override the client configuration.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use reqwest::header::{HeaderMap, HeaderValue};
+/// use typesense::RequestOptions;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("x-request-id", HeaderValue::from_static("42"));
+///
+/// let options = RequestOptions::builder()
+///     .timeout(Duration::from_secs(600))
+///     .headers(headers)
+///     .node("http://localhost:8108")
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default, bon::Builder)]
+pub struct RequestOptions {
+    /// The maximum duration of each HTTP request, including the time to read the response body.
+    ///
+    /// A request that times out is treated like a network error: the node is marked unhealthy
+    /// and the request is sent to the next node.
+    pub timeout: Option<Duration>,
+    /// Additional headers sent with every request.
+    #[builder(default)]
+    pub headers: HeaderMap,
+    /// An API key used instead of the one the client was built with.
+    #[builder(into)]
+    pub api_key: Option<String>,
+    /// The URL of the node that every request is sent to.
+    ///
+    /// A pinned request does not fail over to the other nodes, and is sent even when the node
+    /// is marked unhealthy, unless its circuit breaker is open. The URL must match one of the
+    /// nodes the client was built with.
+    #[builder(into)]
+    pub node: Option<String>,
+    /// Sends each request at most once: failed requests are neither retried on the same node
+    /// nor sent to another node.
+    #[builder(default)]
+    pub disable_retry: bool,
+    /// Overrides the `hedge_delay` of the client for reads. Reads are not hedged when a node is
+    /// pinned or `disable_retry` is set.
+    pub hedge_delay: Option<Duration>,
+}
+
+/// The request options of a namespace, with the node configurations that apply them.
+#[derive(Debug)]
+pub(super) struct PreparedOptions {
+    pub(super) options: RequestOptions,
+    /// The configuration used for each node, in the same order as `Client::nodes`.
+    pub(super) configs: Vec<configuration::Configuration>,
+    /// The index of the pinned node, or `Err` with its URL when no node matches it.
+    pub(super) pinned_node: Option<Result<usize, String>>,
+}
+
+impl PreparedOptions {
+    pub(super) fn new(client: &Client, options: RequestOptions) -> Self {
+        let configs = client
+            .nodes
+            .iter()
+            .map(|node| node_config(node, &options))
+            .collect();
+        let pinned_node = options.node.as_deref().map(|url| {
+            let url = url.strip_suffix('/').unwrap_or(url);
+            client
+                .nodes
+                .iter()
+                .position(|node| node.config.base_path == url)
+                .ok_or_else(|| url.to_owned())
+        });
+
+        Self {
+            options,
+            configs,
+            pinned_node,
+        }
+    }
+}
+
+/// Builds the configuration used to send requests with `options` to `node`.
+fn node_config(node: &Node, options: &RequestOptions) -> configuration::Configuration {
+    let mut config = node.config.clone();
+    if let Some(api_key) = &options.api_key {
+        config.api_key = Some(configuration::ApiKey {
+            prefix: None,
+            key: api_key.clone(),
+        });
+    }
+
+    let overrides = Overrides {
+        headers: options.headers.clone(),
+        timeout: options.timeout,
+        disable_retry: options.disable_retry,
+    };
+    config.client = node
+        .middleware
+        .client(node.http_client.clone(), Some(Arc::new(overrides)));
+
+    config
+}
+
+/// A reference to the client, together with the request options of a namespace.
+#[derive(Clone, Debug)]
+pub(crate) struct ClientRef<'a> {
+    client: Handle<'a>,
+    options: Option<Arc<PreparedOptions>>,
+    /// The collection the requests are made on, recorded by the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    collection: Option<Arc<str>>,
+}
+
+impl<'a> ClientRef<'a> {
+    /// Returns a reference that sends its requests with `options`.
+    pub(crate) fn with_options(self, options: RequestOptions) -> Self {
+        Self {
+            options: Some(Arc::new(PreparedOptions::new(&self.client, options))),
+            ..self
+        }
+    }
+
+    /// Returns a reference whose requests are made on the collection `name`.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn with_collection(self, name: &str) -> Self {
+        Self {
+            collection: Some(name.into()),
+            ..self
+        }
+    }
+
+    /// Returns a reference whose requests are made on the collection `name`.
+    #[cfg(not(feature = "tracing"))]
+    #[inline]
+    pub(crate) fn with_collection(self, _name: &str) -> Self {
+        self
+    }
+
+    /// Executes an API call with the failover logic of the client and the request options.
+    pub(crate) async fn execute<'b, F, Fut, T, E>(
+        &'b self,
+        operation: Operation,
+        api_call: F,
+    ) -> Result<T, crate::Error<E>>
+    where
+        F: Fn(&'b configuration::Configuration) -> Fut,
+        Fut: Future<Output = Result<T, typesense_codegen::apis::Error<E>>>,
+        E: std::fmt::Debug + 'static,
+        typesense_codegen::apis::Error<E>: std::error::Error + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        let collection = self.collection.as_deref();
+        #[cfg(not(feature = "tracing"))]
+        let collection = None;
+        self.client
+            .execute_with_options(operation, collection, self.options.as_deref(), api_call)
+            .await
+    }
+}
+
+impl<'a> From<&'a Client> for ClientRef<'a> {
+    #[inline]
+    fn from(client: &'a Client) -> Self {
+        Self {
+            client: Handle::Borrowed(client),
+            options: None,
+            #[cfg(feature = "tracing")]
+            collection: None,
+        }
+    }
+}
+
+impl From<Arc<Client>> for ClientRef<'static> {
+    #[inline]
+    fn from(client: Arc<Client>) -> Self {
+        Self {
+            client: Handle::Owned(client),
+            options: None,
+            #[cfg(feature = "tracing")]
+            collection: None,
+        }
+    }
+}
+
+/// The client of a [`ClientRef`], either borrowed or shared by the namespaces that own it.
+#[derive(Clone, Debug)]
+enum Handle<'a> {
+    Borrowed(&'a Client),
+    Owned(Arc<Client>),
+}
+
+impl Deref for Handle<'_> {
+    type Target = Client;
+
+    #[inline]
+    fn deref(&self) -> &Client {
+        match self {
+            Self::Borrowed(client) => client,
+            Self::Owned(client) => client,
+        }
+    }
+}
+
+/// Fails `request` with a `TimedOut` error if it does not complete within `timeout`.
+///
+/// Requests sent by the browser client cannot be given a timeout, so they are raced against
+/// a timer instead.
+#[cfg(target_arch = "wasm32")]
+pub(super) async fn with_timeout<T, E>(
+    timeout: Option<Duration>,
+    request: impl Future<Output = Result<T, typesense_codegen::apis::Error<E>>>,
+) -> Result<T, typesense_codegen::apis::Error<E>> {
+    use futures::future::{self, Either};
+
+    let Some(timeout) = timeout else {
+        return request.await;
+    };
+    match future::select(
+        std::pin::pin!(request),
+        std::pin::pin!(super::timer::sleep(timeout)),
+    )
+    .await
+    {
+        Either::Left((result, _)) => result,
+        Either::Right(((), _)) => Err(typesense_codegen::apis::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "The request timed out.",
+        ))),
+    }
+}
@@ -1,3 +1,6 @@
+use reqwest_retry::{RetryDecision, RetryPolicy};
+use std::time::SystemTime;
+
 pub use reqwest_retry::policies::{ExponentialBackoff, ExponentialBackoffTimed};
 
 #[derive(Clone, Debug)]
@@ -23,3 +26,12 @@ impl From<ExponentialBackoffTimed> for ClientRetryPolicy {
         Self::Timed(p)
     }
 }
+
+impl RetryPolicy for ClientRetryPolicy {
+    fn should_retry(&self, request_start_time: SystemTime, n_past_retries: u32) -> RetryDecision {
+        match self {
+            Self::Default(policy) => policy.should_retry(request_start_time, n_past_retries),
+            Self::Timed(policy) => policy.should_retry(request_start_time, n_past_retries),
+        }
+    }
+}
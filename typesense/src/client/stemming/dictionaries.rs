@@ -2,10 +2,7 @@
 //!
 //! A `Dictionaries` instance is created via the `client.stemming().dictionaries()` method.
 
-use crate::{
-    client::{Client, Error},
-    execute_wrapper,
-};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use ::std::borrow::Cow;
 use typesense_codegen::{apis::stemming_api, models};
 
@@ -13,16 +10,18 @@ use typesense_codegen::{apis::stemming_api, models};
 ///
 /// This struct is created by calling `client.stemming().dictionaries()`.
 pub struct Dictionaries<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Dictionaries<'a> {
     /// Creates a new `Dictionaries` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Imports a stemming dictionary from a JSONL file content.
     ///
     /// This creates or updates a dictionary with the given ID.
@@ -39,7 +38,7 @@ impl<'a> Dictionaries<'a> {
             id: dictionary_id.into(),
             body: dictionary_jsonl.into(),
         };
-        execute_wrapper!(self, stemming_api::import_stemming_dictionary, params)
+        execute_wrapper!(self, write stemming_api::import_stemming_dictionary, params)
     }
 
     /// Retrieves a list of all stemming dictionaries.
@@ -49,6 +48,6 @@ impl<'a> Dictionaries<'a> {
         models::ListStemmingDictionaries200Response,
         Error<stemming_api::ListStemmingDictionariesError>,
     > {
-        execute_wrapper!(self, stemming_api::list_stemming_dictionaries)
+        execute_wrapper!(self, read stemming_api::list_stemming_dictionaries)
     }
 }
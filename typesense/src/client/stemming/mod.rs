@@ -5,7 +5,8 @@
 pub mod dictionaries;
 pub mod dictionary;
 
-use super::Client;
+use super::ClientRef;
+use ::std::borrow::Cow;
 use dictionaries::Dictionaries;
 use dictionary::Dictionary;
 
@@ -13,20 +14,22 @@ use dictionary::Dictionary;
 ///
 /// This struct is created by calling `client.stemming()`.
 pub struct Stemming<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Stemming<'a> {
     /// Creates a new `Stemming` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Provides access to endpoints for managing the collection of dictionaries.
     #[inline]
     pub fn dictionaries(&self) -> Dictionaries<'a> {
-        Dictionaries::new(self.client)
+        Dictionaries::new(self.client.clone())
     }
 
     /// Provides access to endpoints for managing a single dictionary.
@@ -34,7 +37,7 @@ impl<'a> Stemming<'a> {
     /// # Arguments
     /// * `dictionary_id` - The ID of the dictionary to manage.
     #[inline]
-    pub fn dictionary(&self, dictionary_id: &'a str) -> Dictionary<'a> {
-        Dictionary::new(self.client, dictionary_id)
+    pub fn dictionary(&self, dictionary_id: impl Into<Cow<'a, str>>) -> Dictionary<'a> {
+        Dictionary::new(self.client.clone(), dictionary_id)
     }
 }
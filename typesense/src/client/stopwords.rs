@@ -2,7 +2,7 @@
 //!
 //! A `Stopwords` instance is created via the main `client.stopwords()` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use ::std::borrow::Cow;
 use typesense_codegen::{apis::stopwords_api, models};
 
@@ -10,16 +10,18 @@ use typesense_codegen::{apis::stopwords_api, models};
 ///
 /// This struct is created by calling `client.stopwords()`.
 pub struct Stopwords<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> Stopwords<'a> {
     /// Creates a new `Stopwords` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Creates or updates an existing stopwords set.
     ///
     /// # Arguments
@@ -34,7 +36,7 @@ impl<'a> Stopwords<'a> {
             set_id: set_id.into(),
             stopwords_set_upsert_schema: schema,
         };
-        execute_wrapper!(self, stopwords_api::upsert_stopwords_set, params)
+        execute_wrapper!(self, write stopwords_api::upsert_stopwords_set, params)
     }
 
     /// Retrieves the details of all stopwords sets.
@@ -44,6 +46,6 @@ impl<'a> Stopwords<'a> {
         models::StopwordsSetsRetrieveAllSchema,
         Error<stopwords_api::RetrieveStopwordsSetsError>,
     > {
-        execute_wrapper!(self, stopwords_api::retrieve_stopwords_sets)
+        execute_wrapper!(self, read stopwords_api::retrieve_stopwords_sets)
     }
 }
@@ -4,38 +4,45 @@
 //!
 //! A `SynonymSetItem` instance is created via the main `client.synonym_set("synonym_set_name").item("item_id")` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
+use ::std::borrow::Cow;
 use typesense_codegen::{apis::synonyms_api, models};
 
 /// Provides methods for managing a synonym set item.
 ///
 /// This struct is created by calling `client.synonym_set("synonym_set_name").item("item_id")`.
 pub struct SynonymSetItem<'a> {
-    pub(super) client: &'a Client,
-    pub(super) synonym_set_name: &'a str,
-    pub(super) item_id: &'a str,
+    pub(super) client: ClientRef<'a>,
+    pub(super) synonym_set_name: Cow<'a, str>,
+    pub(super) item_id: Cow<'a, str>,
 }
 
 impl<'a> SynonymSetItem<'a> {
     /// Creates a new `SynonymSetItem` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client, synonym_set_name: &'a str, item_id: &'a str) -> Self {
+    pub(super) fn new(
+        client: ClientRef<'a>,
+        synonym_set_name: Cow<'a, str>,
+        item_id: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
             client,
             synonym_set_name,
-            item_id,
+            item_id: item_id.into(),
         }
     }
 
+    with_options!();
+
     /// Retrieve this synonym set item.
     pub async fn retrieve(
         &self,
     ) -> Result<models::SynonymItemSchema, Error<synonyms_api::RetrieveSynonymSetItemError>> {
         let params = synonyms_api::RetrieveSynonymSetItemParams {
-            synonym_set_name: self.synonym_set_name.into(),
-            item_id: self.item_id.into(),
+            synonym_set_name: self.synonym_set_name.as_ref().into(),
+            item_id: self.item_id.as_ref().into(),
         };
-        execute_wrapper!(self, synonyms_api::retrieve_synonym_set_item, params)
+        execute_wrapper!(self, read synonyms_api::retrieve_synonym_set_item, params)
     }
 
     /// Delete this synonym set item.
@@ -44,9 +51,9 @@ impl<'a> SynonymSetItem<'a> {
     ) -> Result<models::SynonymItemDeleteSchema, Error<synonyms_api::DeleteSynonymSetItemError>>
     {
         let params = synonyms_api::DeleteSynonymSetItemParams {
-            synonym_set_name: self.synonym_set_name.into(),
-            item_id: self.item_id.into(),
+            synonym_set_name: self.synonym_set_name.as_ref().into(),
+            item_id: self.item_id.as_ref().into(),
         };
-        execute_wrapper!(self, synonyms_api::delete_synonym_set_item, params)
+        execute_wrapper!(self, write synonyms_api::delete_synonym_set_item, params)
     }
 }
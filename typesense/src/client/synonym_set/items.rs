@@ -6,36 +6,38 @@
 
 use ::std::borrow::Cow;
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use typesense_codegen::{apis::synonyms_api, models};
 
 /// Provides methods for managing items of a synonym set.
 ///
 /// This struct is created by calling `client.synonym_set("synonym_set_name").items()`.
 pub struct SynonymSetItems<'a> {
-    pub(super) client: &'a Client,
-    pub(super) synonym_set_name: &'a str,
+    pub(super) client: ClientRef<'a>,
+    pub(super) synonym_set_name: Cow<'a, str>,
 }
 
 impl<'a> SynonymSetItems<'a> {
     /// Creates a new `SynonymSetItems` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client, synonym_set_name: &'a str) -> Self {
+    pub(super) fn new(client: ClientRef<'a>, synonym_set_name: Cow<'a, str>) -> Self {
         Self {
             client,
             synonym_set_name,
         }
     }
 
+    with_options!();
+
     /// Retrieves all the items of this synonym set.
     pub async fn retrieve(
         &self,
     ) -> Result<Vec<models::SynonymItemSchema>, Error<synonyms_api::RetrieveSynonymSetItemsError>>
     {
         let params = synonyms_api::RetrieveSynonymSetItemsParams {
-            synonym_set_name: self.synonym_set_name.into(),
+            synonym_set_name: self.synonym_set_name.as_ref().into(),
         };
-        execute_wrapper!(self, synonyms_api::retrieve_synonym_set_items, params)
+        execute_wrapper!(self, read synonyms_api::retrieve_synonym_set_items, params)
     }
 
     /// Creates or updates an existing item of a synonym set.
@@ -50,9 +52,9 @@ impl<'a> SynonymSetItems<'a> {
     ) -> Result<models::SynonymItemSchema, Error<synonyms_api::UpsertSynonymSetItemError>> {
         let params = synonyms_api::UpsertSynonymSetItemParams {
             item_id: item_id.into(),
-            synonym_set_name: self.synonym_set_name.into(),
+            synonym_set_name: self.synonym_set_name.as_ref().into(),
             synonym_item_upsert_schema: schema,
         };
-        execute_wrapper!(self, synonyms_api::upsert_synonym_set_item, params)
+        execute_wrapper!(self, write synonyms_api::upsert_synonym_set_item, params)
     }
 }
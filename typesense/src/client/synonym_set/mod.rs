@@ -7,7 +7,8 @@
 mod item;
 mod items;
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
+use ::std::borrow::Cow;
 use item::SynonymSetItem;
 use items::SynonymSetItems;
 use typesense_codegen::{apis::synonyms_api, models};
@@ -16,30 +17,32 @@ use typesense_codegen::{apis::synonyms_api, models};
 ///
 /// This struct is created by calling `client.synonym_set("synonym_set_name")`.
 pub struct SynonymSet<'a> {
-    pub(super) client: &'a Client,
-    pub(super) synonym_set_name: &'a str,
+    pub(super) client: ClientRef<'a>,
+    pub(super) synonym_set_name: Cow<'a, str>,
 }
 
 impl<'a> SynonymSet<'a> {
     /// Creates a new `SynonymSet` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client, synonym_set_name: &'a str) -> Self {
+    pub(super) fn new(client: ClientRef<'a>, synonym_set_name: impl Into<Cow<'a, str>>) -> Self {
         Self {
             client,
-            synonym_set_name,
+            synonym_set_name: synonym_set_name.into(),
         }
     }
 
+    with_options!();
+
     /// Provides access to the items of this synonym set.
     #[inline]
-    pub fn items(&self) -> SynonymSetItems<'_> {
-        SynonymSetItems::new(self.client, self.synonym_set_name)
+    pub fn items(&self) -> SynonymSetItems<'a> {
+        SynonymSetItems::new(self.client.clone(), self.synonym_set_name.clone())
     }
 
     /// Provides access to this specific item of this synonym set.
     #[inline]
-    pub fn item(&self, item_id: &'a str) -> SynonymSetItem<'a> {
-        SynonymSetItem::new(self.client, self.synonym_set_name, item_id)
+    pub fn item(&self, item_id: impl Into<Cow<'a, str>>) -> SynonymSetItem<'a> {
+        SynonymSetItem::new(self.client.clone(), self.synonym_set_name.clone(), item_id)
     }
 
     /// Retrieves the details of this synonym set.
@@ -47,9 +50,9 @@ impl<'a> SynonymSet<'a> {
         &self,
     ) -> Result<models::SynonymSetSchema, Error<synonyms_api::RetrieveSynonymSetError>> {
         let params = synonyms_api::RetrieveSynonymSetParams {
-            synonym_set_name: self.synonym_set_name.into(),
+            synonym_set_name: self.synonym_set_name.as_ref().into(),
         };
-        execute_wrapper!(self, synonyms_api::retrieve_synonym_set, params)
+        execute_wrapper!(self, read synonyms_api::retrieve_synonym_set, params)
     }
 
     /// Delete this synonym set.
@@ -57,8 +60,8 @@ impl<'a> SynonymSet<'a> {
         &self,
     ) -> Result<models::SynonymSetDeleteSchema, Error<synonyms_api::DeleteSynonymSetError>> {
         let params = synonyms_api::DeleteSynonymSetParams {
-            synonym_set_name: self.synonym_set_name.into(),
+            synonym_set_name: self.synonym_set_name.as_ref().into(),
         };
-        execute_wrapper!(self, synonyms_api::delete_synonym_set, params)
+        execute_wrapper!(self, write synonyms_api::delete_synonym_set, params)
     }
 }
@@ -4,7 +4,7 @@
 //!
 //! A `SynonymSets` instance is created via the main `client.synonym_sets()` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{Error, client::ClientRef, execute_wrapper};
 use ::std::borrow::Cow;
 use typesense_codegen::{apis::synonyms_api, models};
 
@@ -12,21 +12,23 @@ use typesense_codegen::{apis::synonyms_api, models};
 ///
 /// This struct is created by calling `client.synonym_sets()`.
 pub struct SynonymSets<'a> {
-    pub(super) client: &'a Client,
+    pub(super) client: ClientRef<'a>,
 }
 
 impl<'a> SynonymSets<'a> {
     /// Creates a new `SynonymSets` instance.
     #[inline]
-    pub(super) fn new(client: &'a Client) -> Self {
+    pub(super) fn new(client: ClientRef<'a>) -> Self {
         Self { client }
     }
 
+    with_options!();
+
     /// Retrieves the details of all synonym sets.
     pub async fn retrieve(
         &self,
     ) -> Result<Vec<models::SynonymSetSchema>, Error<synonyms_api::RetrieveSynonymSetsError>> {
-        execute_wrapper!(self, synonyms_api::retrieve_synonym_sets)
+        execute_wrapper!(self, read synonyms_api::retrieve_synonym_sets)
     }
 
     /// Creates or updates an existing synonym set.
@@ -43,6 +45,6 @@ impl<'a> SynonymSets<'a> {
             synonym_set_name: name.into(),
             synonym_set_create_schema: schema,
         };
-        execute_wrapper!(self, synonyms_api::upsert_synonym_set, params)
+        execute_wrapper!(self, write synonyms_api::upsert_synonym_set, params)
     }
 }
@@ -0,0 +1,16 @@
+//! Runtime-agnostic timers for the client's background work and backoffs.
+
+use web_time::Duration;
+
+/// Waits for `duration` without blocking the executor.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Waits for `duration` without blocking the executor.
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    // The delay only fails if the timer is dropped, which cannot happen while it is awaited.
+    let _ = wasm_timer::Delay::new(duration).await;
+}
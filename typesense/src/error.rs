@@ -1,5 +1,7 @@
 //! Contains the error types for the Typesense client
 
+use reqwest::StatusCode;
+use std::time::Duration;
 use thiserror::Error;
 pub use typesense_codegen::apis::{Error as ApiError, ResponseContent};
 
@@ -10,7 +12,10 @@ pub use typesense_codegen::apis::{Error as ApiError, ResponseContent};
 ///
 /// The generic parameter `E` represents the specific error type associated with a
 /// particular API operation (e.g., `SearchCollectionError`, `GetDocumentError`).
+///
+/// New variants may be added in minor releases, so matches must have a wildcard arm.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum Error<E>
 where
     E: std::fmt::Debug + 'static,
@@ -22,12 +27,18 @@ where
     /// and every attempt failed. The user should check the health and connectivity
     /// of all their Typesense nodes.
     ///
-    /// The `source` field contains the error from the *last* node that was attempted.
-    #[error("All configured Typesense nodes failed to respond. Last error: {source}")]
+    /// The `source` field contains the error from the *last* node that was attempted, and
+    /// `attempts` lists the failures of every node in the order they occurred.
+    #[error(
+        "All configured Typesense nodes failed to respond. {}",
+        DisplayAttempts(attempts, source)
+    )]
     AllNodesFailed {
         /// The underlying API or network error from the last node attempt.
         #[source]
         source: ApiError<E>,
+        /// The failed attempts, the last one being the attempt that returned `source`.
+        attempts: Vec<NodeAttempt>,
     },
 
     /// Wraps an error returned by the Typesense API or the underlying network stack.
@@ -37,9 +48,17 @@ where
     /// - A client-side mistake (e.g., HTTP 4xx errors like `404 Not Found` or `401 Unauthorized`).
     /// - A network connectivity problem (e.g., connection refused, timeout, DNS failure).
     ///
-    /// You should inspect the wrapped error to get specific details about the HTTP status code and response body.
-    #[error("An API or network error occurred: {0}")]
-    Api(#[from] ApiError<E>),
+    /// The [`status`](Self::status) and [`message`](Self::message) methods give the HTTP status
+    /// code and the message of the response, if any.
+    #[error("An API or network error occurred: {source}")]
+    Api {
+        /// The underlying API or network error.
+        #[source]
+        source: ApiError<E>,
+        /// The URL of the node that returned the error, or `None` if the error occurred before
+        /// a request was sent.
+        node: Option<String>,
+    },
 
     /// Occurs when the JSON response from Typesense cannot be deserialized into the target Rust struct.
     ///
@@ -54,6 +73,290 @@ where
     Deserialization(#[from] serde_json::Error),
 }
 
+impl<E> From<ApiError<E>> for Error<E>
+where
+    E: std::fmt::Debug + 'static,
+    ApiError<E>: std::error::Error + 'static,
+{
+    fn from(source: ApiError<E>) -> Self {
+        Self::Api { source, node: None }
+    }
+}
+
+impl<E> Error<E>
+where
+    E: std::fmt::Debug + 'static,
+    ApiError<E>: std::error::Error + 'static,
+{
+    /// Returns the underlying API or network error, from the last node for `AllNodesFailed`.
+    pub fn api_error(&self) -> Option<&ApiError<E>> {
+        match self {
+            Self::AllNodesFailed { source, .. } | Self::Api { source, .. } => Some(source),
+            Self::Deserialization(_) => None,
+        }
+    }
+
+    /// Returns the URL of the node that returned the error, the last node that was attempted
+    /// for `AllNodesFailed`.
+    pub fn node(&self) -> Option<&str> {
+        match self {
+            Self::AllNodesFailed { attempts, .. } => {
+                attempts.last().map(|attempt| attempt.node.as_str())
+            }
+            Self::Api { node, .. } => node.as_deref(),
+            Self::Deserialization(_) => None,
+        }
+    }
+
+    /// Returns the failed attempts of `AllNodesFailed`, and an empty slice for other errors.
+    pub fn attempts(&self) -> &[NodeAttempt] {
+        match self {
+            Self::AllNodesFailed { attempts, .. } => attempts,
+            _ => &[],
+        }
+    }
+
+    /// Returns the HTTP status code of the response, if the node answered.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self.api_error()? {
+            ApiError::ResponseError(content) => Some(content.status),
+            ApiError::Reqwest(e) => e.status(),
+            ApiError::ReqwestMiddleware(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Returns the error message of the response, if the node answered.
+    ///
+    /// This is the `message` field of the JSON body Typesense returns with errors, or the raw
+    /// body if it has none.
+    pub fn message(&self) -> Option<String> {
+        let ApiError::ResponseError(content) = self.api_error()? else {
+            return None;
+        };
+        #[derive(serde::Deserialize)]
+        struct Body {
+            message: String,
+        }
+        match serde_json::from_str::<Body>(&content.content) {
+            Ok(body) => Some(body.message),
+            Err(_) => Some(content.content.trim())
+                .filter(|content| !content.is_empty())
+                .map(str::to_owned),
+        }
+    }
+
+    /// Returns whether the node answered `400 Bad Request`, e.g. for an invalid parameter.
+    pub fn is_bad_request(&self) -> bool {
+        self.status() == Some(StatusCode::BAD_REQUEST)
+    }
+
+    /// Returns whether the node answered `401 Unauthorized`, e.g. for a missing or invalid API
+    /// key.
+    pub fn is_unauthorized(&self) -> bool {
+        self.status() == Some(StatusCode::UNAUTHORIZED)
+    }
+
+    /// Returns whether the node answered `404 Not Found`, e.g. for a missing collection or
+    /// document.
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Returns whether the node answered `409 Conflict`, e.g. for an existing collection or
+    /// document ID.
+    pub fn is_conflict(&self) -> bool {
+        self.status() == Some(StatusCode::CONFLICT)
+    }
+
+    /// Returns whether the error is transient, so that the call may succeed if it is made
+    /// again later: all nodes failed, or a node failed with a server or network error.
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            Self::AllNodesFailed { .. } => true,
+            Self::Api { source, .. } => crate::client::is_retriable(source),
+            Self::Deserialization(_) => false,
+        }
+    }
+}
+
+/// A request that failed on a node, recorded in [`Error::AllNodesFailed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeAttempt {
+    /// The URL of the node.
+    pub node: String,
+    /// The error the request failed with.
+    pub error: String,
+    /// The time spent on the node, including the retries on it.
+    pub elapsed: Duration,
+}
+
+/// Formats the attempts of `AllNodesFailed`, or its last error if no attempt was recorded.
+struct DisplayAttempts<'a, S>(&'a [NodeAttempt], &'a S);
+
+impl<S: std::fmt::Display> std::fmt::Display for DisplayAttempts<'_, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "Last error: {}", self.1);
+        }
+        f.write_str("Attempts: ")?;
+        for (i, attempt) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(
+                f,
+                "`{}` failed after {:?}: {}",
+                attempt.node, attempt.elapsed, attempt.error
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The kind of an [`AnyError`], matching the variant of the [`Error`](enum@Error) it was converted from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// See [`Error::AllNodesFailed`].
+    AllNodesFailed,
+    /// See [`Error::Api`].
+    Api,
+    /// See [`Error::Deserialization`].
+    Deserialization,
+}
+
+/// A client error that is not generic over the operation that failed.
+///
+/// Every [`Error<E>`](enum@Error) converts into an `AnyError`, so functions that call several endpoints can
+/// use `?` with a single error type. The status, message, node and retriability of the
+/// original error are kept, and the original error is its [`Display`](std::fmt::Display) and
+/// [`source`](std::error::Error::source).
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(not(target_family = "wasm"))]
+/// # {
+/// use typesense::{AnyError, Client};
+///
+/// async fn recreate(client: &Client, name: &str) -> Result<(), AnyError> {
+///     if let Err(e) = client.collection_schemaless(name).delete().await {
+///         if !e.is_not_found() {
+///             return Err(e.into());
+///         }
+///     }
+///     let schema = typesense::models::CollectionSchema {
+///         name: name.into(),
+///         ..Default::default()
+///     };
+///     client.collections().create(schema).await?;
+///     Ok(())
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AnyError {
+    kind: ErrorKind,
+    status: Option<StatusCode>,
+    message: Option<String>,
+    node: Option<String>,
+    is_retriable: bool,
+    attempts: Vec<NodeAttempt>,
+    error: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl AnyError {
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// See [`Error::status`].
+    pub fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+
+    /// See [`Error::message`].
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// See [`Error::node`].
+    pub fn node(&self) -> Option<&str> {
+        self.node.as_deref()
+    }
+
+    /// See [`Error::is_retriable`].
+    pub fn is_retriable(&self) -> bool {
+        self.is_retriable
+    }
+
+    /// See [`Error::attempts`].
+    pub fn attempts(&self) -> &[NodeAttempt] {
+        &self.attempts
+    }
+
+    /// See [`Error::is_bad_request`].
+    pub fn is_bad_request(&self) -> bool {
+        self.status == Some(StatusCode::BAD_REQUEST)
+    }
+
+    /// See [`Error::is_unauthorized`].
+    pub fn is_unauthorized(&self) -> bool {
+        self.status == Some(StatusCode::UNAUTHORIZED)
+    }
+
+    /// See [`Error::is_not_found`].
+    pub fn is_not_found(&self) -> bool {
+        self.status == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// See [`Error::is_conflict`].
+    pub fn is_conflict(&self) -> bool {
+        self.status == Some(StatusCode::CONFLICT)
+    }
+
+    /// Returns the original error, which can be downcast to the [`Error<E>`](enum@Error) of the operation.
+    pub fn get_ref(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        &*self.error
+    }
+}
+
+impl std::fmt::Display for AnyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for AnyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> From<Error<E>> for AnyError
+where
+    E: std::fmt::Debug + Send + Sync + 'static,
+    ApiError<E>: std::error::Error + Send + Sync + 'static,
+{
+    fn from(error: Error<E>) -> Self {
+        Self {
+            kind: match error {
+                Error::AllNodesFailed { .. } => ErrorKind::AllNodesFailed,
+                Error::Api { .. } => ErrorKind::Api,
+                Error::Deserialization(_) => ErrorKind::Deserialization,
+            },
+            status: error.status(),
+            message: error.message(),
+            node: error.node().map(str::to_owned),
+            is_retriable: error.is_retriable(),
+            attempts: error.attempts().to_vec(),
+            error: Box::new(error),
+        }
+    }
+}
+
 /// Represents the possible errors that can occur when parsing a `multi_search` response.
 ///
 /// This error enum is returned by the `MultiSearchResultExt::parse_at` method when it
@@ -106,3 +409,105 @@ pub enum MultiSearchParseError {
         source: serde_json::Error,
     },
 }
+
+/// Represents the possible errors that can occur when converting a `SortBy` into a `sort_by` string.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SortByError {
+    /// Indicates that no sort clause was added.
+    #[error("sort_by must contain at least one sort clause.")]
+    Empty,
+
+    /// Indicates that more clauses were added than Typesense accepts.
+    ///
+    /// # Fields
+    /// * `0` - The number of clauses that were added.
+    #[error("sort_by accepts at most 3 sort clauses, got {0}.")]
+    TooManyClauses(usize),
+}
+
+/// Represents the possible errors that can occur when creating a client from a `ClientConfig`,
+/// environment variables or a connection URL.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConfigError {
+    /// Indicates that a required environment variable is not set.
+    #[error("the environment variable {0} is not set")]
+    MissingVariable(&'static str),
+
+    /// Indicates that an environment variable has a value that cannot be parsed.
+    #[error("invalid value `{value}` for the environment variable {name}")]
+    InvalidVariable {
+        /// The name of the environment variable.
+        name: &'static str,
+        /// The value that cannot be parsed.
+        value: String,
+    },
+
+    /// Indicates that a connection URL cannot be parsed.
+    #[error("invalid connection URL: {0}")]
+    InvalidUrl(String),
+
+    /// Indicates that the configuration was rejected by the client builder, e.g. because it
+    /// has no node.
+    #[error("invalid client configuration: {0}")]
+    Client(&'static str),
+}
+
+/// Represents the possible errors that can occur when building a `Filter`.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum FilterError {
+    /// Indicates that the filter has no condition, e.g. `Filter::all([])`.
+    #[error("filter_by must contain at least one condition.")]
+    Empty,
+
+    /// Indicates that a list condition such as `in_` was given no value.
+    #[error("the condition on `{0}` has an empty list of values.")]
+    EmptyValues(String),
+
+    /// Indicates that a number or a coordinate of a condition is NaN or infinite.
+    #[error("the condition on `{0}` has a NaN or infinite number.")]
+    NonFinite(String),
+
+    /// Indicates that a geo polygon condition was given fewer than 3 vertices.
+    #[error("the polygon on `{0}` needs at least 3 points.")]
+    TooFewPoints(String),
+
+    /// Indicates that a string value contains a backtick, which Typesense cannot escape.
+    #[error("the value `{0}` contains a backtick, which cannot be escaped.")]
+    Backtick(String),
+
+    /// Indicates that a negated condition has no equivalent in the Typesense syntax, e.g. the
+    /// negation of a geo or raw filter.
+    #[error("the negation of `{0}` cannot be expressed in filter_by.")]
+    UnsupportedNegation(String),
+}
+
+/// A document that Typesense rejected during an import.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportFailure {
+    /// The position of the document in the imported batch.
+    pub index: usize,
+    /// The reason the document was rejected.
+    pub error: String,
+    /// The HTTP status code describing the failure, e.g. `409` for a conflicting ID.
+    pub code: Option<u16>,
+    /// The JSON line that was rejected, as sent to Typesense.
+    pub document: Option<String>,
+}
+
+/// Returned by `ImportResultsExt::ensure_all_succeeded` when some documents of an import
+/// were rejected.
+///
+/// Typesense imports are not atomic: the documents that are not listed in `failures`
+/// have been imported.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error(
+    "{} of {total} documents failed to import. First error: {}",
+    failures.len(),
+    failures.first().map_or("", |failure| failure.error.as_str())
+)]
+pub struct PartialImportError {
+    /// The number of documents in the import.
+    pub total: usize,
+    /// The rejected documents, in import order. Never empty.
+    pub failures: Vec<ImportFailure>,
+}
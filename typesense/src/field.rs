@@ -0,0 +1,436 @@
+//! Typed field handles for building queries.
+//!
+//! `#[derive(Typesense)]` generates a `{struct_name}Fields` type with one associated constant
+//! per schema field. Each constant is a [`FieldHandle`] that carries the field name (after
+//! `rename`) and, in its type, whether the field is indexed, facetable, sortable and searchable.
+//!
+//! The handles can be used to build `query_by` ([`QueryBy`]), `facet_by` ([`FacetBy`]),
+//! `sort_by` ([`SortBy`](crate::models::SortBy)),
+//! `include_fields` / `exclude_fields` ([`FieldList`]) and filters
+//! ([`Filter::field`](crate::filter::Filter::field)) without string literals that can drift
+//! from the struct. Using a field in a position its schema does not allow is a compile-time error.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use typesense::{
+//!     Typesense,
+//!     field::{FacetBy, QueryBy},
+//!     filter::Filter,
+//!     models::SearchParameters,
+//! };
+//!
+//! #[derive(Typesense, Serialize, Deserialize)]
+//! struct Book {
+//!     title: String,
+//!     #[typesense(facet)]
+//!     genre: String,
+//!     #[typesense(rename = "year")]
+//!     #[serde(rename = "year")]
+//!     publication_year: i32,
+//! }
+//!
+//! let params = SearchParameters::builder()
+//!     .q("dune")
+//!     .query_by(QueryBy::new().field(BookFields::title).field(BookFields::genre))
+//!     .facet_by(FacetBy::new().field(BookFields::genre))
+//!     .filter_by(Filter::field(BookFields::publication_year).gt(1960)?)
+//!     .build();
+//!
+//! assert_eq!(params.query_by.as_deref(), Some("title,genre"));
+//! assert_eq!(params.filter_by.as_deref(), Some("year:>1960"));
+//! # Ok::<(), typesense::FilterError>(())
+//! ```
+//!
+//! The sub-fields of a `#[typesense(flatten)]` field are reached through methods of the
+//! constant of that field, named after the sub-fields; `object()` returns the handle of the
+//! object field itself. Their names are built at compile time:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use typesense::{Typesense, field::FacetBy};
+//!
+//! #[derive(Typesense, Serialize, Deserialize)]
+//! struct Address {
+//!     #[typesense(facet)]
+//!     city: String,
+//! }
+//!
+//! #[derive(Typesense, Serialize, Deserialize)]
+//! #[typesense(enable_nested_fields = true)]
+//! struct Shop {
+//!     #[typesense(flatten)]
+//!     address: Address,
+//! }
+//!
+//! let facet_by = FacetBy::new().field(ShopFields::address.city());
+//! assert_eq!(facet_by.to_string(), "address.city");
+//! assert_eq!(ShopFields::address.object().name(), "address");
+//! ```
+//!
+//! Faceting on a field that is not marked with `#[typesense(facet)]` does not compile:
+//!
+//! ```compile_fail
+//! # use serde::{Deserialize, Serialize};
+//! # use typesense::{Typesense, field::FacetBy};
+//! #[derive(Typesense, Serialize, Deserialize)]
+//! struct Book {
+//!     title: String,
+//! }
+//!
+//! let facet_by = FacetBy::new().field(BookFields::title);
+//! ```
+
+use ::std::{borrow::Cow, fmt, marker::PhantomData};
+
+// Referenced by the derive macro when adding bounds to generic documents.
+pub use crate::traits::ToTypesenseField;
+
+/// Type-level marker for a capability a field has.
+#[derive(Debug, Clone, Copy)]
+pub struct Yes;
+
+/// Type-level marker for a capability a field does not have.
+#[derive(Debug, Clone, Copy)]
+pub struct No;
+
+/// A typed reference to a field of the collection described by `D`.
+///
+/// `D` is the `{struct_name}Fields` type generated by the derive macro, which keeps fields of
+/// different collections from being mixed. The `Index`, `Facet` and `Sort` parameters are
+/// either [`Yes`] or [`No`] and mirror the `index`, `facet` and `sort` settings of the field.
+/// `Text` is [`Yes`] for `string` and `string[]` fields, the only ones that can be searched
+/// with `query_by`.
+///
+/// Numeric and geopoint fields are sortable by default, like in Typesense; every other field
+/// must be marked with `#[typesense(sort)]`. Fields with `#[typesense(index = false)]` can be
+/// neither faceted nor sorted on.
+pub struct FieldHandle<D, Index = Yes, Facet = No, Sort = No, Text = No> {
+    name: &'static str,
+    _collection: PhantomData<fn() -> D>,
+    _capabilities: PhantomData<(Index, Facet, Sort, Text)>,
+}
+
+impl<D, I, F, S, T> FieldHandle<D, I, F, S, T> {
+    /// Creates a handle for the field with the given name.
+    #[inline]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _collection: PhantomData,
+            _capabilities: PhantomData,
+        }
+    }
+
+    /// The name of the field in the collection schema.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<D, I, F, S, T> Clone for FieldHandle<D, I, F, S, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D, I, F, S, T> Copy for FieldHandle<D, I, F, S, T> {}
+
+impl<D, I, F, S, T> fmt::Debug for FieldHandle<D, I, F, S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FieldHandle").field(&self.name).finish()
+    }
+}
+
+impl<D, I, F, S, T> fmt::Display for FieldHandle<D, I, F, S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// Only indexed fields can be used in filters.
+impl<D, F, S, T> From<FieldHandle<D, Yes, F, S, T>> for String {
+    fn from(field: FieldHandle<D, Yes, F, S, T>) -> Self {
+        field.name.to_owned()
+    }
+}
+
+/// A field that is indexed in memory, and can therefore be searched and filtered on.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an indexed field",
+    label = "this field has `#[typesense(index = false)]`"
+)]
+pub trait IndexedField: Copy {
+    /// The `{struct_name}Fields` type the field belongs to.
+    type Collection;
+    /// The name of the field in the collection schema.
+    fn name(&self) -> &'static str;
+}
+
+/// A `string` or `string[]` field that is indexed, and can therefore be used in `query_by`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an indexed string field",
+    label = "only indexed `string` and `string[]` fields can be searched"
+)]
+pub trait SearchableField: Copy {
+    /// The `{struct_name}Fields` type the field belongs to.
+    type Collection;
+    /// The name of the field in the collection schema.
+    fn name(&self) -> &'static str;
+}
+
+/// A field that can be used in `facet_by`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a facetable field",
+    label = "mark the field with `#[typesense(facet)]` to facet on it"
+)]
+pub trait FacetableField: Copy {
+    /// The `{struct_name}Fields` type the field belongs to.
+    type Collection;
+    /// The name of the field in the collection schema.
+    fn name(&self) -> &'static str;
+}
+
+/// A field that can be used in `sort_by`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a sortable field",
+    label = "mark the field with `#[typesense(sort)]` to sort on it"
+)]
+pub trait SortableField: Copy {
+    /// The `{struct_name}Fields` type the field belongs to.
+    type Collection;
+    /// The name of the field in the collection schema.
+    fn name(&self) -> &'static str;
+}
+
+impl<D, F, S, T> IndexedField for FieldHandle<D, Yes, F, S, T> {
+    type Collection = D;
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<D, F, S> SearchableField for FieldHandle<D, Yes, F, S, Yes> {
+    type Collection = D;
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<D, I, S, T> FacetableField for FieldHandle<D, I, Yes, S, T> {
+    type Collection = D;
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<D, I, F, T> SortableField for FieldHandle<D, I, F, Yes, T> {
+    type Collection = D;
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Resolves the sortability of a sub-field of a flattened object: sub-fields of a list of
+/// objects are arrays, which cannot be sorted on.
+#[doc(hidden)]
+pub trait SortInArray<Array> {
+    type Output;
+}
+
+impl<S> SortInArray<No> for S {
+    type Output = S;
+}
+
+impl<S> SortInArray<Yes> for S {
+    type Output = No;
+}
+
+/// The dotted path of a field, known at compile time.
+#[doc(hidden)]
+pub trait FieldPath {
+    const PATH: &'static str;
+}
+
+/// Implemented by the `{struct_name}Fields` type for the `I`-th field of the struct, with the
+/// name of the field in the schema.
+#[doc(hidden)]
+pub trait NamedField<const I: usize> {
+    const NAME: &'static str;
+}
+
+/// The path of the `I`-th field of the struct whose `{struct_name}Fields` type is `D`.
+#[doc(hidden)]
+pub struct FieldAt<D, const I: usize>(PhantomData<fn() -> D>);
+
+impl<D: NamedField<I>, const I: usize> FieldPath for FieldAt<D, I> {
+    const PATH: &'static str = D::NAME;
+}
+
+/// The path `{Parent::PATH}.{Child::PATH}` of a sub-field of a flattened object.
+#[doc(hidden)]
+pub struct Nested<Parent, Child>(PhantomData<fn() -> (Parent, Child)>);
+
+impl<P: FieldPath, C: FieldPath> Nested<P, C> {
+    const JOINED: &'static JoinedPath = &JoinedPath::new(P::PATH, C::PATH);
+}
+
+impl<P: FieldPath, C: FieldPath> FieldPath for Nested<P, C> {
+    const PATH: &'static str = Self::JOINED.as_str();
+}
+
+/// The longest path of a sub-field, in bytes.
+const MAX_PATH_LEN: usize = 256;
+
+/// A `{prefix}.{name}` path built by a const evaluation.
+struct JoinedPath {
+    bytes: [u8; MAX_PATH_LEN],
+    len: usize,
+}
+
+impl JoinedPath {
+    const fn new(prefix: &str, name: &str) -> Self {
+        let (prefix, name) = (prefix.as_bytes(), name.as_bytes());
+        let len = prefix.len() + 1 + name.len();
+        assert!(
+            len <= MAX_PATH_LEN,
+            "the path of a sub-field is longer than 256 bytes"
+        );
+
+        let mut bytes = [0; MAX_PATH_LEN];
+        let mut i = 0;
+        while i < prefix.len() {
+            bytes[i] = prefix[i];
+            i += 1;
+        }
+        bytes[i] = b'.';
+        let mut j = 0;
+        while j < name.len() {
+            bytes[i + 1 + j] = name[j];
+            j += 1;
+        }
+        Self { bytes, len }
+    }
+
+    const fn as_str(&'static self) -> &'static str {
+        match ::std::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(path) => path,
+            // Both parts are `str`s joined by an ASCII dot.
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+/// Implements the shared constructors and conversions of a comma-separated field list.
+macro_rules! impl_field_list {
+    ($name:ident) => {
+        impl<D> $name<D> {
+            /// Creates an empty list.
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    names: Vec::new(),
+                    _marker: PhantomData,
+                }
+            }
+        }
+
+        impl<D> Default for $name<D> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<D> Clone for $name<D> {
+            fn clone(&self) -> Self {
+                Self {
+                    names: self.names.clone(),
+                    _marker: PhantomData,
+                }
+            }
+        }
+
+        impl<D> fmt::Debug for $name<D> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.names).finish()
+            }
+        }
+
+        impl<D> fmt::Display for $name<D> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.names.join(","))
+            }
+        }
+
+        impl<D> From<$name<D>> for String {
+            fn from(list: $name<D>) -> Self {
+                list.to_string()
+            }
+        }
+
+        impl<'a, D> From<$name<D>> for Cow<'a, str> {
+            fn from(list: $name<D>) -> Self {
+                Cow::Owned(list.to_string())
+            }
+        }
+    };
+}
+
+/// The fields to search in, for the `query_by` parameter. Only indexed `string` and `string[]`
+/// fields are accepted.
+pub struct QueryBy<D> {
+    names: Vec<&'static str>,
+    _marker: PhantomData<fn() -> D>,
+}
+
+impl_field_list!(QueryBy);
+
+impl<D> QueryBy<D> {
+    /// Appends a field to the list.
+    pub fn field(mut self, field: impl SearchableField<Collection = D>) -> Self {
+        self.names.push(field.name());
+        self
+    }
+}
+
+/// The fields to facet on, for the `facet_by` parameter. Only facetable fields are accepted.
+pub struct FacetBy<D> {
+    names: Vec<&'static str>,
+    _marker: PhantomData<fn() -> D>,
+}
+
+impl_field_list!(FacetBy);
+
+impl<D> FacetBy<D> {
+    /// Appends a field to the list.
+    pub fn field(mut self, field: impl FacetableField<Collection = D>) -> Self {
+        self.names.push(field.name());
+        self
+    }
+}
+
+/// Any field of the collection, for the `include_fields` and `exclude_fields` parameters.
+pub struct FieldList<D> {
+    names: Vec<&'static str>,
+    _marker: PhantomData<fn() -> D>,
+}
+
+impl_field_list!(FieldList);
+
+impl<D> FieldList<D> {
+    /// Appends a field to the list.
+    pub fn field<I, F, S, T>(mut self, field: FieldHandle<D, I, F, S, T>) -> Self {
+        self.names.push(field.name);
+        self
+    }
+}
@@ -0,0 +1,679 @@
+//! A typed builder for `filter_by` expressions.
+//!
+//! Typesense filters are plain strings such as `price:>=10 && brand:=[Nike, Adidas]`.
+//! Building them by hand is error-prone: a missing operand, a forgotten backtick around
+//! a value containing a comma, or mismatched parentheses only show up as a `400` from the server.
+//!
+//! The [`Filter`] type is a small composable AST which renders to the exact Typesense syntax,
+//! escaping string values with backticks whenever needed.
+//!
+//! Conditions are checked when they are created: a condition that Typesense would reject, such
+//! as an `in_` without any value, returns a [`FilterError`]. A [`Filter`] can then be converted
+//! into a `String` or `Cow<str>` and used anywhere a `filter_by` value is accepted
+//! (`SearchParameters`, `MultiSearchCollectionParameters`, `ExportDocumentsParameters`,
+//! `DeleteDocumentsParameters`, ...).
+//!
+//! # Example
+//!
+//! ```
+//! use typesense::{filter::Filter, models::SearchParameters};
+//!
+//! let filter = Filter::field("price")
+//!     .gte(10)?
+//!     .and(Filter::field("brand").in_(["Nike", "Adidas, Inc."])?);
+//!
+//! assert_eq!(
+//!     filter.to_string(),
+//!     "price:>=10 && brand:=[Nike, `Adidas, Inc.`]"
+//! );
+//!
+//! let params = SearchParameters::builder()
+//!     .q("shoes")
+//!     .query_by("name")
+//!     .filter_by(filter)
+//!     .build();
+//! # Ok::<(), typesense::FilterError>(())
+//! ```
+//!
+//! Docs: <https://typesense.org/docs/latest/api/search.html#filter-parameters>
+
+use crate::FilterError;
+use ::std::{borrow::Cow, fmt};
+
+/// A single value used on the right-hand side of a filter condition.
+///
+/// Values are created through the `From` implementations for strings, numbers and booleans.
+/// String values that contain anything other than alphanumeric characters, `_`, `-` or `.`
+/// are wrapped in backticks when rendered. Typesense cannot escape a backtick inside a value, so
+/// conditions on a value that contains one are rejected, as are NaN and infinite numbers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterValue(ValueRepr);
+
+#[derive(Debug, Clone, PartialEq)]
+enum ValueRepr {
+    String(String),
+    Number(String),
+    /// A NaN or infinite float, which Typesense cannot parse.
+    NonFinite(String),
+    Bool(bool),
+}
+
+impl fmt::Display for FilterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            ValueRepr::String(s) if needs_escaping(s) => write!(f, "`{s}`"),
+            ValueRepr::String(s) | ValueRepr::Number(s) | ValueRepr::NonFinite(s) => f.write_str(s),
+            ValueRepr::Bool(b) => write!(f, "{b}"),
+        }
+    }
+}
+
+impl FilterValue {
+    fn validate(&self, field: &str) -> Result<(), FilterError> {
+        match &self.0 {
+            ValueRepr::String(s) if s.contains('`') => Err(FilterError::Backtick(s.clone())),
+            ValueRepr::NonFinite(_) => Err(FilterError::NonFinite(field.to_owned())),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Whether a string value must be wrapped in backticks to be parsed as a single token.
+fn needs_escaping(value: &str) -> bool {
+    value.is_empty()
+        || !value
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        Self(ValueRepr::String(value.to_owned()))
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        Self(ValueRepr::String(value))
+    }
+}
+
+impl From<&String> for FilterValue {
+    fn from(value: &String) -> Self {
+        Self(ValueRepr::String(value.clone()))
+    }
+}
+
+impl From<Cow<'_, str>> for FilterValue {
+    fn from(value: Cow<'_, str>) -> Self {
+        Self(ValueRepr::String(value.into_owned()))
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        Self(ValueRepr::Bool(value))
+    }
+}
+
+macro_rules! impl_number_filter_value {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for FilterValue {
+                fn from(value: $ty) -> Self {
+                    Self(ValueRepr::Number(value.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float_filter_value {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for FilterValue {
+                fn from(value: $ty) -> Self {
+                    if value.is_finite() {
+                        Self(ValueRepr::Number(value.to_string()))
+                    } else {
+                        Self(ValueRepr::NonFinite(value.to_string()))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_number_filter_value!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+impl_float_filter_value!(f32, f64);
+
+/// The unit of the radius used in a geo radius filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoUnit {
+    /// Kilometers (`km`).
+    Kilometers,
+    /// Miles (`mi`).
+    Miles,
+}
+
+impl fmt::Display for GeoUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GeoUnit::Kilometers => "km",
+            GeoUnit::Miles => "mi",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operator {
+    /// `field:value`, `field:[a, b]`
+    Matches(Vec<FilterValue>),
+    /// `field:=value`, `field:=[a, b]`
+    Equals(Vec<FilterValue>),
+    /// `field:!=value`, `field:!=[a, b]`
+    NotEquals(Vec<FilterValue>),
+    Gt(FilterValue),
+    Gte(FilterValue),
+    Lt(FilterValue),
+    Lte(FilterValue),
+    /// `field:[min..max]`
+    Range(FilterValue, FilterValue),
+    /// `field:(lat, lng, radius unit)`
+    GeoRadius {
+        lat: f64,
+        lng: f64,
+        radius: f64,
+        unit: GeoUnit,
+    },
+    /// `field:(lat1, lng1, lat2, lng2, ...)`
+    GeoPolygon(Vec<(f64, f64)>),
+}
+
+impl Operator {
+    /// Checks the operands of a condition on `field`.
+    fn validate(&self, field: &str) -> Result<(), FilterError> {
+        let finite = |coordinates: &[f64]| {
+            if coordinates.iter().all(|c| c.is_finite()) {
+                Ok(())
+            } else {
+                Err(FilterError::NonFinite(field.to_owned()))
+            }
+        };
+        match self {
+            Operator::Matches(values) | Operator::Equals(values) | Operator::NotEquals(values) => {
+                if values.is_empty() {
+                    return Err(FilterError::EmptyValues(field.to_owned()));
+                }
+                values.iter().try_for_each(|value| value.validate(field))
+            }
+            Operator::Gt(value)
+            | Operator::Gte(value)
+            | Operator::Lt(value)
+            | Operator::Lte(value) => value.validate(field),
+            Operator::Range(min, max) => min.validate(field).and_then(|()| max.validate(field)),
+            Operator::GeoRadius {
+                lat, lng, radius, ..
+            } => finite(&[*lat, *lng, *radius]),
+            Operator::GeoPolygon(points) => {
+                if points.len() < 3 {
+                    return Err(FilterError::TooFewPoints(field.to_owned()));
+                }
+                points
+                    .iter()
+                    .try_for_each(|(lat, lng)| finite(&[*lat, *lng]))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Condition {
+        field: String,
+        operator: Operator,
+    },
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+    Join {
+        collection: String,
+        filter: Box<Filter>,
+        negated: bool,
+    },
+    Raw(String),
+}
+
+/// A `filter_by` expression.
+///
+/// Conditions are started with [`Filter::field`], combined with [`Filter::and`] and
+/// [`Filter::or`], and negated with [`Filter::not`]. Every condition is checked when it is
+/// created, so a `Filter` always renders to an expression that Typesense can parse, except for
+/// the strings given to [`Filter::raw`], which are used verbatim.
+///
+/// The expression is rendered by [`Filter::build`], by its `Display` implementation, or by
+/// converting it into a `String` / `Cow<str>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter(Expr);
+
+impl Filter {
+    /// Starts a condition on the given field.
+    ///
+    /// Nested fields are addressed with a dot, e.g. `Filter::field("details.color")`.
+    pub fn field(name: impl Into<String>) -> FieldFilter {
+        FieldFilter { field: name.into() }
+    }
+
+    /// Filters documents by a condition on a referenced collection, e.g.
+    /// `$authors(country:=France)`.
+    ///
+    /// Docs: <https://typesense.org/docs/latest/api/joins.html>
+    pub fn join(collection: impl Into<String>, filter: impl Into<Filter>) -> Self {
+        Self(Expr::Join {
+            collection: collection.into(),
+            filter: Box::new(filter.into()),
+            negated: false,
+        })
+    }
+
+    /// Filters documents that do **not** have a reference matching the condition, e.g.
+    /// `!$authors(country:=France)`.
+    pub fn not_join(collection: impl Into<String>, filter: impl Into<Filter>) -> Self {
+        Self(Expr::Join {
+            collection: collection.into(),
+            filter: Box::new(filter.into()),
+            negated: true,
+        })
+    }
+
+    /// Uses a pre-built filter string verbatim.
+    ///
+    /// This is an escape hatch for syntax that is not covered by the builder.
+    /// The string is wrapped in parentheses when combined with other filters.
+    pub fn raw(filter_by: impl Into<String>) -> Self {
+        Self(Expr::Raw(filter_by.into()))
+    }
+
+    /// Combines all the given filters with `&&`.
+    ///
+    /// Returns [`FilterError::Empty`] if no filter is given.
+    pub fn all(filters: impl IntoIterator<Item = impl Into<Filter>>) -> Result<Self, FilterError> {
+        Self::group(filters, Expr::And)
+    }
+
+    /// Combines all the given filters with `||`.
+    ///
+    /// Returns [`FilterError::Empty`] if no filter is given.
+    pub fn any(filters: impl IntoIterator<Item = impl Into<Filter>>) -> Result<Self, FilterError> {
+        Self::group(filters, Expr::Or)
+    }
+
+    fn group(
+        filters: impl IntoIterator<Item = impl Into<Filter>>,
+        expr: fn(Vec<Filter>) -> Expr,
+    ) -> Result<Self, FilterError> {
+        let filter = Self(expr(filters.into_iter().map(Into::into).collect()));
+        if filter.is_empty() {
+            return Err(FilterError::Empty);
+        }
+        Ok(filter)
+    }
+
+    /// Combines this filter with another one using `&&`.
+    pub fn and(self, other: impl Into<Filter>) -> Self {
+        match self.0 {
+            Expr::And(mut filters) => {
+                filters.push(other.into());
+                Self(Expr::And(filters))
+            }
+            expr => Self(Expr::And(vec![Self(expr), other.into()])),
+        }
+    }
+
+    /// Combines this filter with another one using `||`.
+    pub fn or(self, other: impl Into<Filter>) -> Self {
+        match self.0 {
+            Expr::Or(mut filters) => {
+                filters.push(other.into());
+                Self(Expr::Or(filters))
+            }
+            expr => Self(Expr::Or(vec![Self(expr), other.into()])),
+        }
+    }
+
+    /// Negates this filter.
+    ///
+    /// Typesense has no general negation operator, so the negation is pushed down into the
+    /// conditions: `&&` and `||` are swapped, `:=` becomes `:!=`, `:>` becomes `:<=`, a range
+    /// becomes `field:<min || field:>max`, and a join becomes a negated join. Conditions
+    /// without a negated form, i.e. non-exact matches, geo and raw filters, return
+    /// [`FilterError::UnsupportedNegation`].
+    ///
+    /// As with any Typesense condition, documents without the field match neither a
+    /// comparison nor its negation.
+    ///
+    /// ```
+    /// use typesense::filter::Filter;
+    ///
+    /// let filter = Filter::field("price").range(10, 20)?.or(Filter::field("brand").eq("Nike")?);
+    /// assert_eq!(
+    ///     filter.not()?.to_string(),
+    ///     "(price:<10 || price:>20) && brand:!=Nike"
+    /// );
+    /// # Ok::<(), typesense::FilterError>(())
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Result<Self, FilterError> {
+        let negate_all = |filters: Vec<Filter>| -> Result<Vec<Filter>, FilterError> {
+            filters.into_iter().map(Filter::not).collect()
+        };
+        let unsupported =
+            |filter: Filter| Err(FilterError::UnsupportedNegation(filter.to_string()));
+        Ok(match self.0 {
+            Expr::Condition { field, operator } => {
+                let condition = |operator| {
+                    Self(Expr::Condition {
+                        field: field.clone(),
+                        operator,
+                    })
+                };
+                match operator {
+                    Operator::Equals(values) => condition(Operator::NotEquals(values)),
+                    Operator::NotEquals(values) => condition(Operator::Equals(values)),
+                    Operator::Gt(value) => condition(Operator::Lte(value)),
+                    Operator::Gte(value) => condition(Operator::Lt(value)),
+                    Operator::Lt(value) => condition(Operator::Gte(value)),
+                    Operator::Lte(value) => condition(Operator::Gt(value)),
+                    Operator::Range(min, max) => Self(Expr::Or(vec![
+                        condition(Operator::Lt(min)),
+                        condition(Operator::Gt(max)),
+                    ])),
+                    operator => return unsupported(condition(operator)),
+                }
+            }
+            Expr::And(filters) => Self(Expr::Or(negate_all(filters)?)),
+            Expr::Or(filters) => Self(Expr::And(negate_all(filters)?)),
+            Expr::Join {
+                collection,
+                filter,
+                negated,
+            } => Self(Expr::Join {
+                collection,
+                filter,
+                negated: !negated,
+            }),
+            expr @ Expr::Raw(_) => return unsupported(Self(expr)),
+        })
+    }
+
+    /// Renders the `filter_by` string.
+    pub fn build(&self) -> String {
+        self.to_string()
+    }
+
+    /// Whether this expression renders to an empty string.
+    fn is_empty(&self) -> bool {
+        match &self.0 {
+            Expr::And(filters) | Expr::Or(filters) => filters.iter().all(Filter::is_empty),
+            Expr::Raw(s) => s.trim().is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Writes `filters` joined by `separator`, grouping the operands that need parentheses.
+    fn fmt_group(
+        f: &mut fmt::Formatter<'_>,
+        filters: &[Filter],
+        separator: &str,
+        needs_parens: impl Fn(&Expr) -> bool,
+    ) -> fmt::Result {
+        let operands: Vec<&Filter> = filters.iter().filter(|filter| !filter.is_empty()).collect();
+        let single = operands.len() == 1;
+        for (i, filter) in operands.into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(separator)?;
+            }
+            if !single && needs_parens(&filter.0) {
+                write!(f, "({filter})")?;
+            } else {
+                write!(f, "{filter}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Expr::Condition { field, operator } => {
+                write!(f, "{field}:")?;
+                match operator {
+                    Operator::Matches(values) => fmt_values(f, values),
+                    Operator::Equals(values) => {
+                        f.write_str("=")?;
+                        fmt_values(f, values)
+                    }
+                    Operator::NotEquals(values) => {
+                        f.write_str("!=")?;
+                        fmt_values(f, values)
+                    }
+                    Operator::Gt(value) => write!(f, ">{value}"),
+                    Operator::Gte(value) => write!(f, ">={value}"),
+                    Operator::Lt(value) => write!(f, "<{value}"),
+                    Operator::Lte(value) => write!(f, "<={value}"),
+                    Operator::Range(min, max) => write!(f, "[{min}..{max}]"),
+                    Operator::GeoRadius {
+                        lat,
+                        lng,
+                        radius,
+                        unit,
+                    } => write!(f, "({lat}, {lng}, {radius} {unit})"),
+                    Operator::GeoPolygon(points) => {
+                        f.write_str("(")?;
+                        for (i, (lat, lng)) in points.iter().enumerate() {
+                            if i > 0 {
+                                f.write_str(", ")?;
+                            }
+                            write!(f, "{lat}, {lng}")?;
+                        }
+                        f.write_str(")")
+                    }
+                }
+            }
+            Expr::And(filters) => Self::fmt_group(f, filters, " && ", |expr| {
+                matches!(expr, Expr::Or(_) | Expr::Raw(_))
+            }),
+            Expr::Or(filters) => Self::fmt_group(f, filters, " || ", |expr| {
+                matches!(expr, Expr::And(_) | Expr::Raw(_))
+            }),
+            Expr::Join {
+                collection,
+                filter,
+                negated,
+            } => {
+                if *negated {
+                    f.write_str("!")?;
+                }
+                write!(f, "${collection}({filter})")
+            }
+            Expr::Raw(s) => f.write_str(s),
+        }
+    }
+}
+
+/// Writes a single value as-is, or several values as a `[a, b]` list.
+fn fmt_values(f: &mut fmt::Formatter<'_>, values: &[FilterValue]) -> fmt::Result {
+    if let [value] = values {
+        return write!(f, "{value}");
+    }
+    f.write_str("[")?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{value}")?;
+    }
+    f.write_str("]")
+}
+
+impl From<&str> for Filter {
+    /// Uses a filter string verbatim, see [`Filter::raw`].
+    fn from(filter_by: &str) -> Self {
+        Self::raw(filter_by)
+    }
+}
+
+impl From<String> for Filter {
+    /// Uses a filter string verbatim, see [`Filter::raw`].
+    fn from(filter_by: String) -> Self {
+        Self::raw(filter_by)
+    }
+}
+
+impl From<Filter> for String {
+    fn from(filter: Filter) -> Self {
+        filter.to_string()
+    }
+}
+
+impl<'a> From<Filter> for Cow<'a, str> {
+    fn from(filter: Filter) -> Self {
+        Cow::Owned(filter.to_string())
+    }
+}
+
+impl<'a> From<&Filter> for Cow<'a, str> {
+    fn from(filter: &Filter) -> Self {
+        Cow::Owned(filter.to_string())
+    }
+}
+
+/// A field on which a filter condition is being built.
+///
+/// Created with [`Filter::field`]. Every method consumes the field and returns a [`Filter`], or
+/// a [`FilterError`] if Typesense would reject the condition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldFilter {
+    field: String,
+}
+
+impl FieldFilter {
+    #[inline]
+    fn condition(self, operator: Operator) -> Result<Filter, FilterError> {
+        operator.validate(&self.field)?;
+        Ok(Filter(Expr::Condition {
+            field: self.field,
+            operator,
+        }))
+    }
+
+    /// Non-exact match on a single value: `field:value`.
+    ///
+    /// For string fields this matches documents where the field contains the value as a word.
+    pub fn matches(self, value: impl Into<FilterValue>) -> Result<Filter, FilterError> {
+        self.condition(Operator::Matches(vec![value.into()]))
+    }
+
+    /// Non-exact match on any of the given values: `field:[a, b]`.
+    ///
+    /// Returns [`FilterError::EmptyValues`] if no value is given.
+    pub fn matches_any(
+        self,
+        values: impl IntoIterator<Item = impl Into<FilterValue>>,
+    ) -> Result<Filter, FilterError> {
+        self.condition(Operator::Matches(
+            values.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Exact match: `field:=value`.
+    pub fn eq(self, value: impl Into<FilterValue>) -> Result<Filter, FilterError> {
+        self.condition(Operator::Equals(vec![value.into()]))
+    }
+
+    /// Exact match on any of the given values: `field:=[a, b]`.
+    ///
+    /// Returns [`FilterError::EmptyValues`] if no value is given.
+    pub fn in_(
+        self,
+        values: impl IntoIterator<Item = impl Into<FilterValue>>,
+    ) -> Result<Filter, FilterError> {
+        self.condition(Operator::Equals(
+            values.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Negated exact match: `field:!=value`.
+    pub fn not_eq(self, value: impl Into<FilterValue>) -> Result<Filter, FilterError> {
+        self.condition(Operator::NotEquals(vec![value.into()]))
+    }
+
+    /// Matches documents whose field is none of the given values: `field:!=[a, b]`.
+    ///
+    /// Returns [`FilterError::EmptyValues`] if no value is given.
+    pub fn not_in(
+        self,
+        values: impl IntoIterator<Item = impl Into<FilterValue>>,
+    ) -> Result<Filter, FilterError> {
+        self.condition(Operator::NotEquals(
+            values.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Greater than: `field:>value`.
+    pub fn gt(self, value: impl Into<FilterValue>) -> Result<Filter, FilterError> {
+        self.condition(Operator::Gt(value.into()))
+    }
+
+    /// Greater than or equal: `field:>=value`.
+    pub fn gte(self, value: impl Into<FilterValue>) -> Result<Filter, FilterError> {
+        self.condition(Operator::Gte(value.into()))
+    }
+
+    /// Less than: `field:<value`.
+    pub fn lt(self, value: impl Into<FilterValue>) -> Result<Filter, FilterError> {
+        self.condition(Operator::Lt(value.into()))
+    }
+
+    /// Less than or equal: `field:<=value`.
+    pub fn lte(self, value: impl Into<FilterValue>) -> Result<Filter, FilterError> {
+        self.condition(Operator::Lte(value.into()))
+    }
+
+    /// Inclusive range: `field:[min..max]`.
+    pub fn range(
+        self,
+        min: impl Into<FilterValue>,
+        max: impl Into<FilterValue>,
+    ) -> Result<Filter, FilterError> {
+        self.condition(Operator::Range(min.into(), max.into()))
+    }
+
+    /// Matches geopoints within `radius` of the given coordinates:
+    /// `field:(lat, lng, radius unit)`.
+    pub fn within_radius(
+        self,
+        lat: f64,
+        lng: f64,
+        radius: f64,
+        unit: GeoUnit,
+    ) -> Result<Filter, FilterError> {
+        self.condition(Operator::GeoRadius {
+            lat,
+            lng,
+            radius,
+            unit,
+        })
+    }
+
+    /// Matches geopoints inside the polygon formed by the given `(lat, lng)` vertices:
+    /// `field:(lat1, lng1, lat2, lng2, ...)`.
+    ///
+    /// Returns [`FilterError::TooFewPoints`] if fewer than 3 vertices are given.
+    pub fn within_polygon(
+        self,
+        points: impl IntoIterator<Item = (f64, f64)>,
+    ) -> Result<Filter, FilterError> {
+        self.condition(Operator::GeoPolygon(points.into_iter().collect()))
+    }
+}
@@ -67,7 +67,8 @@
 //! ### WebAssembly (Wasm)
 //!
 //! This example is tailored for a WebAssembly target.
-//! Key difference: Tokio-dependent features like `.retry_policy()` are disabled.
+//! Key differences: `.retry_policy()` is disabled, and per-node HTTP client options such as
+//! connect timeouts are not supported.
 //!
 //! ```no_run
 //! #[cfg(target_family = "wasm")]
@@ -113,12 +114,21 @@ mod client;
 mod traits;
 
 pub mod error;
+pub mod field;
+pub mod filter;
 pub mod models;
 pub mod prelude;
 
-pub use client::{Client, ExponentialBackoff, NodeConfig};
+pub use client::{
+    CircuitBreaker, CircuitState, Client, ClientConfig, Collection, ExponentialBackoff,
+    HealthChecker, HealthCheckerHandle, LatencyPercentiles, NodeConfig, NodeStats, RateLimiter,
+    RequestOptions, middleware, node_selector,
+};
 pub use error::*;
 
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub use client::blocking;
+
 pub use typesense_codegen as legacy;
 
 #[cfg(feature = "typesense_derive")]
@@ -0,0 +1,93 @@
+use crate::{ImportFailure, PartialImportError};
+use typesense_codegen::models::ImportDocumentsParameters;
+use web_time::Duration;
+
+/// Options for `Documents::bulk_import`.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use typesense::models::{BulkImportOptions, ImportDocumentsParameters, IndexAction};
+///
+/// let options = BulkImportOptions::builder()
+///     .batch_size(5_000)
+///     .concurrency(8)
+///     .retry_backoff(Duration::from_millis(200))
+///     .params(ImportDocumentsParameters {
+///         action: Some(IndexAction::Upsert),
+///         ..Default::default()
+///     })
+///     .build();
+/// ```
+#[derive(Clone, Debug, PartialEq, bon::Builder)]
+pub struct BulkImportOptions {
+    /// The number of documents sent in each import request. Defaults to 1000.
+    #[builder(default = 1000)]
+    pub batch_size: usize,
+    /// The number of import requests in flight at the same time. Defaults to 4.
+    ///
+    /// Requests are spread over the healthy nodes of the client.
+    #[builder(default = 4)]
+    pub concurrency: usize,
+    /// How many times a batch is retried after every node failed to import it. Defaults to 3.
+    #[builder(default = 3)]
+    pub max_retries: u32,
+    /// The delay before the first retry of a batch, doubled for every following retry.
+    /// Defaults to 1 second.
+    #[builder(default = Duration::from_secs(1))]
+    pub retry_backoff: Duration,
+    /// The parameters sent with every import request.
+    ///
+    /// Retried batches are imported again as a whole, so prefer the `upsert` action:
+    /// with `create`, documents that were imported before the failure are rejected on retry.
+    #[builder(default)]
+    pub params: ImportDocumentsParameters,
+}
+
+impl Default for BulkImportOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// The progress of a `Documents::bulk_import`, reported after every completed batch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BulkImportProgress {
+    /// The number of batches that have been imported.
+    pub batches: usize,
+    /// The number of documents that have been sent and acknowledged.
+    pub processed: usize,
+    /// The number of documents that were imported.
+    pub imported: usize,
+    /// The number of documents that Typesense rejected.
+    pub failed: usize,
+}
+
+/// The outcome of a `Documents::bulk_import`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BulkImportReport {
+    /// The number of documents that were sent.
+    pub total: usize,
+    /// The number of documents that were imported.
+    pub imported: usize,
+    /// The number of import requests that were made, excluding retries.
+    pub batches: usize,
+    /// The rejected documents, ordered by their position in the input.
+    pub failures: Vec<ImportFailure>,
+}
+
+impl BulkImportReport {
+    /// Returns the report unchanged if every document was imported, or a
+    /// [`PartialImportError`] listing the rejected documents otherwise.
+    pub fn ensure_all_succeeded(self) -> Result<Self, PartialImportError> {
+        if self.failures.is_empty() {
+            Ok(self)
+        } else {
+            Err(PartialImportError {
+                total: self.total,
+                failures: self.failures,
+            })
+        }
+    }
+}
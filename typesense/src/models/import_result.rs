@@ -0,0 +1,82 @@
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::Value;
+
+/// The outcome of importing a single document, as returned by `Documents::import`.
+///
+/// Results are in the same order as the imported documents.
+///
+/// See the official Typesense documentation:
+/// <https://typesense.org/docs/latest/api/documents.html#index-multiple-documents>
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportResult<D> {
+    /// Whether the document was imported.
+    pub success: bool,
+    /// The reason the document was rejected.
+    pub error: Option<String>,
+    /// The HTTP status code describing the failure, e.g. `409` for a conflicting ID.
+    pub code: Option<u16>,
+    /// The ID of the document, when `return_id` is set.
+    pub id: Option<String>,
+    /// The indexed document, when `return_doc` is set and the import succeeded.
+    pub document: Option<D>,
+    /// The JSON line that was rejected, as sent to Typesense.
+    pub failed_document: Option<String>,
+    /// Why the result could not be parsed.
+    ///
+    /// When the line returned by Typesense is not a valid result, `success` is `false` and
+    /// the other fields are empty. When only the returned document could not be deserialized
+    /// into `D`, the other fields are kept and `document` is `None`.
+    pub parse_error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawImportResult {
+    success: bool,
+    error: Option<String>,
+    code: Option<u16>,
+    id: Option<String>,
+    document: Option<Value>,
+}
+
+impl<D: DeserializeOwned> ImportResult<D> {
+    /// Parses one line of the JSONL response of an import.
+    ///
+    /// A line that cannot be parsed is reported in `parse_error`, so that it does not hide
+    /// the results of the other documents.
+    pub(crate) fn from_line(line: &str) -> Self {
+        let raw: RawImportResult = match serde_json::from_str(line) {
+            Ok(raw) => raw,
+            Err(e) => {
+                return Self {
+                    success: false,
+                    error: None,
+                    code: None,
+                    id: None,
+                    document: None,
+                    failed_document: None,
+                    parse_error: Some(format!("invalid import result `{line}`: {e}")),
+                };
+            }
+        };
+
+        // Rejected documents are echoed back as the raw line that was sent.
+        let (document, failed_document, parse_error) = match raw.document {
+            Some(Value::String(line)) if !raw.success => (None, Some(line), None),
+            Some(document) => match serde_json::from_value(document) {
+                Ok(document) => (Some(document), None, None),
+                Err(e) => (None, None, Some(format!("invalid imported document: {e}"))),
+            },
+            None => (None, None, None),
+        };
+
+        Self {
+            success: raw.success,
+            error: raw.error,
+            code: raw.code,
+            id: raw.id,
+            document,
+            failed_document,
+            parse_error,
+        }
+    }
+}
@@ -1,10 +1,18 @@
 //! # Typesense generic models
+mod bulk_import;
 mod document_index_parameters;
+mod import_result;
 mod multi_search;
+mod runtime_config;
 mod scoped_key_parameters;
+mod sort_by;
 
+pub use bulk_import::*;
 pub use document_index_parameters::*;
+pub use import_result::*;
+pub use runtime_config::*;
 pub use scoped_key_parameters::*;
+pub use sort_by::*;
 pub use typesense_codegen::{
     apis::{analytics_api::GetAnalyticsEventsParams, operations_api::TakeSnapshotParams},
     models::{curation_rule::Match as CurationRuleMatch, *},
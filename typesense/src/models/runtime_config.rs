@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// Server settings that can be changed at runtime through the `/config` endpoint.
+///
+/// Only the settings that are set are sent, so the other settings of the node keep their
+/// current values. Settings that are not covered by a field can be passed through `extra`.
+///
+/// # Example
+///
+/// ```
+/// use typesense::models::RuntimeConfig;
+///
+/// let config = RuntimeConfig::builder()
+///     .log_slow_requests_time_ms(2000)
+///     .cache_num_entries(5000)
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, bon::Builder)]
+#[serde(rename_all = "kebab-case")]
+pub struct RuntimeConfig {
+    /// Requests that take longer than this many milliseconds are logged with the
+    /// `SLOW REQUEST` prefix. `-1` disables slow request logging.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_slow_requests_time_ms: Option<i32>,
+    /// Searches that take longer than this many milliseconds are written to the search log.
+    /// `-1` disables slow search logging.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_slow_searches_time_ms: Option<i32>,
+    /// Whether every search request is written to the search log.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_search_logging: Option<bool>,
+    /// The number of entries kept in the search cache.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_num_entries: Option<i32>,
+    /// The replication lag, in number of operations, above which the node stops serving reads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub healthy_read_lag: Option<i32>,
+    /// The replication lag, in number of operations, above which the node stops accepting writes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub healthy_write_lag: Option<i32>,
+    /// Whether the node rejects writes, for example while it is being restored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_writes: Option<bool>,
+    /// Additional settings, sent as they are.
+    #[serde(flatten)]
+    #[builder(default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,312 @@
+use crate::{
+    SortByError,
+    field::{FieldHandle, Yes},
+    filter::{Filter, GeoUnit},
+};
+use ::std::{borrow::Cow, fmt};
+
+/// The maximum number of sort clauses accepted by Typesense in a single `sort_by`.
+pub const MAX_SORT_CLAUSES: usize = 3;
+
+/// The direction of a sort clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Ascending order (`asc`).
+    Asc,
+    /// Descending order (`desc`).
+    Desc,
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        })
+    }
+}
+
+/// A field that can be sorted on.
+///
+/// Implemented for plain field names and for the typed handles of sortable fields
+/// generated by `#[derive(Typesense)]`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as a sort field",
+    label = "mark the field with `#[typesense(sort)]` to sort on it"
+)]
+pub trait IntoSortField {
+    /// Returns the name of the field to sort on.
+    fn into_sort_field(self) -> String;
+}
+
+impl IntoSortField for &str {
+    fn into_sort_field(self) -> String {
+        self.to_owned()
+    }
+}
+
+impl IntoSortField for String {
+    fn into_sort_field(self) -> String {
+        self
+    }
+}
+
+impl<D, I, F, T> IntoSortField for FieldHandle<D, I, F, Yes, T> {
+    fn into_sort_field(self) -> String {
+        self.name().to_owned()
+    }
+}
+
+/// Sorting by the distance to a geopoint, used with [`SortBy::geo`].
+///
+/// Docs: <https://typesense.org/docs/latest/api/geosearch.html#sorting-by-distance>
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoSort {
+    field: String,
+    lat: f64,
+    lng: f64,
+    exclude_radius: Option<(f64, GeoUnit)>,
+    precision: Option<(f64, GeoUnit)>,
+}
+
+impl GeoSort {
+    /// Sorts the geopoint `field` by its distance to the given coordinates.
+    pub fn new(field: impl IntoSortField, lat: f64, lng: f64) -> Self {
+        Self {
+            field: field.into_sort_field(),
+            lat,
+            lng,
+            exclude_radius: None,
+            precision: None,
+        }
+    }
+
+    /// Treats all points within this radius as being at the same distance,
+    /// so that they can be ordered by the next sort clause.
+    pub fn exclude_radius(mut self, radius: f64, unit: GeoUnit) -> Self {
+        self.exclude_radius = Some((radius, unit));
+        self
+    }
+
+    /// Buckets the distances into groups of this size,
+    /// so that points in the same bucket can be ordered by the next sort clause.
+    pub fn precision(mut self, precision: f64, unit: GeoUnit) -> Self {
+        self.precision = Some((precision, unit));
+        self
+    }
+}
+
+impl fmt::Display for GeoSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({}, {}", self.field, self.lat, self.lng)?;
+        if let Some((radius, unit)) = self.exclude_radius {
+            write!(f, ", exclude_radius: {radius}{unit}")?;
+        }
+        if let Some((precision, unit)) = self.precision {
+            write!(f, ", precision: {precision}{unit}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SortClause {
+    Field(String),
+    TextMatch { buckets: Option<u32> },
+    Eval(Filter),
+    EvalWeighted(Vec<(Filter, i64)>),
+    Geo(GeoSort),
+    VectorDistance,
+}
+
+impl fmt::Display for SortClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortClause::Field(field) => f.write_str(field),
+            SortClause::TextMatch { buckets: None } => f.write_str("_text_match"),
+            SortClause::TextMatch {
+                buckets: Some(buckets),
+            } => write!(f, "_text_match(buckets: {buckets})"),
+            SortClause::Eval(filter) => write!(f, "_eval({filter})"),
+            SortClause::EvalWeighted(conditions) => {
+                f.write_str("_eval([")?;
+                for (i, (filter, weight)) in conditions.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "({filter}):{weight}")?;
+                }
+                f.write_str("])")
+            }
+            SortClause::Geo(geo) => write!(f, "{geo}"),
+            SortClause::VectorDistance => f.write_str("_vector_distance"),
+        }
+    }
+}
+
+/// A typed builder for the `sort_by` search parameter.
+///
+/// Clauses are applied in the order they are added. Typesense accepts at most
+/// [`MAX_SORT_CLAUSES`] clauses, which is checked by [`SortBy::build`] and when converting
+/// the builder into a `String` or `Cow<str>` with `TryFrom`.
+///
+/// # Example
+///
+/// ```
+/// use typesense::{
+///     filter::{Filter, GeoUnit},
+///     models::{GeoSort, SearchParameters, SortBy, SortOrder},
+/// };
+///
+/// let sort_by = SortBy::new()
+///     .text_match_buckets(10, SortOrder::Desc)
+///     .eval(Filter::field("brand").eq("Nike")?, SortOrder::Desc)
+///     .geo(
+///         GeoSort::new("location", 48.85, 2.29).exclude_radius(2.0, GeoUnit::Miles),
+///         SortOrder::Asc,
+///     );
+///
+/// assert_eq!(
+///     sort_by.to_string(),
+///     "_text_match(buckets: 10):desc,_eval(brand:=Nike):desc,location(48.85, 2.29, exclude_radius: 2mi):asc"
+/// );
+///
+/// let params = SearchParameters::builder()
+///     .q("shoes")
+///     .query_by("name")
+///     .sort_by(sort_by.build()?)
+///     .build();
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Docs: <https://typesense.org/docs/latest/api/search.html#ranking-and-sorting-parameters>
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SortBy {
+    clauses: Vec<(SortClause, SortOrder)>,
+}
+
+impl SortBy {
+    /// Creates an empty `SortBy`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    fn push(mut self, clause: SortClause, order: SortOrder) -> Self {
+        self.clauses.push((clause, order));
+        self
+    }
+
+    /// Sorts by a field in ascending order: `field:asc`.
+    pub fn asc(self, field: impl IntoSortField) -> Self {
+        self.push(SortClause::Field(field.into_sort_field()), SortOrder::Asc)
+    }
+
+    /// Sorts by a field in descending order: `field:desc`.
+    pub fn desc(self, field: impl IntoSortField) -> Self {
+        self.push(SortClause::Field(field.into_sort_field()), SortOrder::Desc)
+    }
+
+    /// Sorts by the text relevance score: `_text_match:desc`.
+    pub fn text_match(self, order: SortOrder) -> Self {
+        self.push(SortClause::TextMatch { buckets: None }, order)
+    }
+
+    /// Sorts by the text relevance score, divided into `buckets` groups so that
+    /// results in the same bucket can be ordered by the next clause:
+    /// `_text_match(buckets: 10):desc`.
+    pub fn text_match_buckets(self, buckets: u32, order: SortOrder) -> Self {
+        self.push(
+            SortClause::TextMatch {
+                buckets: Some(buckets),
+            },
+            order,
+        )
+    }
+
+    /// Sorts by whether documents match a filter expression: `_eval(brand:=Nike):desc`.
+    ///
+    /// The filter can be a [`Filter`](crate::filter::Filter) or a raw filter string.
+    pub fn eval(self, filter: impl Into<Filter>, order: SortOrder) -> Self {
+        self.push(SortClause::Eval(filter.into()), order)
+    }
+
+    /// Sorts by a weighted list of filter expressions:
+    /// `_eval([(brand:=Nike):3, (brand:=Adidas):2]):desc`.
+    pub fn eval_weighted(
+        self,
+        conditions: impl IntoIterator<Item = (impl Into<Filter>, i64)>,
+        order: SortOrder,
+    ) -> Self {
+        let conditions = conditions
+            .into_iter()
+            .map(|(filter, weight)| (filter.into(), weight))
+            .collect();
+        self.push(SortClause::EvalWeighted(conditions), order)
+    }
+
+    /// Sorts by the distance of a geopoint field to a location:
+    /// `location(48.85, 2.29):asc`.
+    pub fn geo(self, geo: GeoSort, order: SortOrder) -> Self {
+        self.push(SortClause::Geo(geo), order)
+    }
+
+    /// Sorts by the vector distance computed in a vector or hybrid search:
+    /// `_vector_distance:asc`.
+    pub fn vector_distance(self, order: SortOrder) -> Self {
+        self.push(SortClause::VectorDistance, order)
+    }
+
+    /// The number of clauses added so far.
+    pub fn len(&self) -> usize {
+        self.clauses.len()
+    }
+
+    /// Whether no clause has been added.
+    pub fn is_empty(&self) -> bool {
+        self.clauses.is_empty()
+    }
+
+    /// Checks that the number of clauses is accepted by Typesense.
+    pub fn validate(&self) -> Result<(), SortByError> {
+        match self.clauses.len() {
+            0 => Err(SortByError::Empty),
+            n if n > MAX_SORT_CLAUSES => Err(SortByError::TooManyClauses(n)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates the clauses and renders the `sort_by` string.
+    pub fn build(&self) -> Result<String, SortByError> {
+        self.validate()?;
+        Ok(self.to_string())
+    }
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (clause, order)) in self.clauses.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{clause}:{order}")?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<SortBy> for String {
+    type Error = SortByError;
+
+    fn try_from(sort_by: SortBy) -> Result<Self, Self::Error> {
+        sort_by.build()
+    }
+}
+
+impl<'a> TryFrom<SortBy> for Cow<'a, str> {
+    type Error = SortByError;
+
+    fn try_from(sort_by: SortBy) -> Result<Self, Self::Error> {
+        sort_by.build().map(Cow::Owned)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{ImportFailure, PartialImportError, models::ImportResult};
+
+/// An extension trait for the results of `Documents::import`.
+pub trait ImportResultsExt: Sized {
+    /// Returns the results unchanged if every document was imported, or a
+    /// [`PartialImportError`] listing the rejected documents otherwise.
+    fn ensure_all_succeeded(self) -> Result<Self, PartialImportError>;
+}
+
+impl<D> ImportResultsExt for Vec<ImportResult<D>> {
+    fn ensure_all_succeeded(self) -> Result<Self, PartialImportError> {
+        let failures: Vec<_> = self
+            .iter()
+            .enumerate()
+            .filter(|(_, result)| !result.success)
+            .map(|(index, result)| ImportFailure {
+                index,
+                error: result
+                    .error
+                    .clone()
+                    .or_else(|| result.parse_error.clone())
+                    .unwrap_or_default(),
+                code: result.code,
+                document: result.failed_document.clone(),
+            })
+            .collect();
+
+        if failures.is_empty() {
+            Ok(self)
+        } else {
+            Err(PartialImportError {
+                total: self.len(),
+                failures,
+            })
+        }
+    }
+}
@@ -2,8 +2,10 @@
 
 mod document;
 mod field_type;
+mod import_results_ext;
 mod multi_search_ext;
 
 pub use document::*;
 pub use field_type::*;
+pub use import_results_ext::ImportResultsExt;
 pub use multi_search_ext::MultiSearchResultExt;
@@ -0,0 +1,81 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest_retry::policies::ExponentialBackoff;
+use serde_json::json;
+use std::time::Duration;
+use typesense::{Client, Error};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn get_client(nodes: Vec<String>) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
+#[tokio::test]
+async fn test_analytics_flush() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/analytics/flush"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ok": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = get_client(vec![server.uri()]);
+    let response = client.analytics().flush().await.unwrap();
+
+    assert!(response.ok);
+}
+
+#[tokio::test]
+async fn test_analytics_status_fails_over_to_healthy_node() {
+    let unhealthy_server = MockServer::start().await;
+    let healthy_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/analytics/status"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&unhealthy_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/analytics/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "popular_prefix_queries": 2,
+            "query_counter_events": 5,
+            "doc_log_events": 0,
+        })))
+        .expect(1)
+        .mount(&healthy_server)
+        .await;
+
+    let client = get_client(vec![unhealthy_server.uri(), healthy_server.uri()]);
+    let status = client.analytics().status().await.unwrap();
+
+    assert_eq!(status.popular_prefix_queries, Some(2));
+    assert_eq!(status.query_counter_events, Some(5));
+    assert_eq!(status.doc_log_events, Some(0));
+    assert_eq!(status.nohits_prefix_queries, None);
+}
+
+#[tokio::test]
+async fn test_analytics_status_reports_all_nodes_failed() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/analytics/status"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let client = get_client(vec![server.uri()]);
+    let result = client.analytics().status().await;
+
+    assert!(matches!(result, Err(Error::AllNodesFailed { .. })));
+}
@@ -0,0 +1,133 @@
+#![cfg(all(feature = "blocking", not(target_family = "wasm")))]
+
+use reqwest_retry::policies::ExponentialBackoff;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use typesense::{blocking, models::SearchParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Book {
+    id: String,
+    title: String,
+}
+
+fn get_client(servers: &[&MockServer]) -> blocking::Client {
+    blocking::Client::new(
+        typesense::Client::builder()
+            .nodes(
+                servers
+                    .iter()
+                    .map(|server| server.uri())
+                    .collect::<Vec<_>>(),
+            )
+            .api_key("test-key")
+            .healthcheck_interval(Duration::from_secs(60))
+            .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+            .build()
+            .expect("Failed to create client"),
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_blocking_client_fails_over_to_the_next_node() {
+    let failing = MockServer::start().await;
+    let healthy = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/1"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&failing)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "id": "1", "title": "Dune" })),
+        )
+        .expect(1)
+        .mount(&healthy)
+        .await;
+
+    let client = get_client(&[&failing, &healthy]);
+    let book = tokio::task::spawn_blocking(move || {
+        client
+            .collection_named::<Book>("books")
+            .document("1")
+            .retrieve()
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(
+        book,
+        Book {
+            id: "1".to_owned(),
+            title: "Dune".to_owned(),
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_blocking_search_stream_fetches_every_page() {
+    let server = MockServer::start().await;
+    for (page, titles) in [(1, vec!["Dune", "Emma"]), (2, vec!["Ulysses"])] {
+        let hits: Vec<_> = titles
+            .iter()
+            .enumerate()
+            .map(|(index, title)| {
+                serde_json::json!({ "document": { "id": format!("{page}-{index}"), "title": title } })
+            })
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/collections/books/documents/search"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "found": 3,
+                "page": page,
+                "hits": hits,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = get_client(&[&server]);
+    let titles = tokio::task::spawn_blocking(move || {
+        let books = client.collection_named::<Book>("books");
+        let params = SearchParameters::builder().q("*").per_page(2).build();
+        books
+            .documents()
+            .search_stream(params)
+            .map(|hit| hit.unwrap().document.unwrap().title)
+            .collect::<Vec<_>>()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(titles, ["Dune", "Emma", "Ulysses"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_blocking_operations_and_escape_hatch() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "ok": true })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = get_client(&[&server]);
+    tokio::task::spawn_blocking(move || {
+        assert!(client.operations().health().unwrap().ok);
+        let health = client.block_on(client.as_async().operations().health());
+        assert!(health.unwrap().ok);
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,160 @@
+#![cfg(not(target_family = "wasm"))]
+
+use futures::stream;
+use reqwest_retry::policies::ExponentialBackoff;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::time::Duration;
+use typesense::{Client, Error, Typesense, models::BulkImportOptions};
+use wiremock::{
+    Mock, MockServer, Request, Respond, ResponseTemplate,
+    matchers::{method, path},
+};
+
+#[derive(Typesense, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[typesense(collection_name = "books")]
+struct Book {
+    id: String,
+}
+
+/// Acknowledges every imported line, rejecting the documents whose id is "3".
+struct ImportResponder;
+
+impl Respond for ImportResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let body = String::from_utf8(request.body.clone()).unwrap();
+        let results: Vec<String> = body
+            .lines()
+            .map(|line| {
+                let document: Value = serde_json::from_str(line).unwrap();
+                if document["id"] == "3" {
+                    json!({ "success": false, "error": "Bad document.", "code": 400, "document": line })
+                } else {
+                    json!({ "success": true })
+                }
+                .to_string()
+            })
+            .collect();
+        ResponseTemplate::new(200).set_body_string(results.join("\n"))
+    }
+}
+
+async fn mount_import(server: &MockServer, collection_name: &str) {
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/collections/{collection_name}/documents/import"
+        )))
+        .respond_with(ImportResponder)
+        .mount(server)
+        .await;
+}
+
+fn get_client(nodes: Vec<String>) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
+fn options() -> BulkImportOptions {
+    BulkImportOptions::builder()
+        .batch_size(2)
+        .concurrency(2)
+        .retry_backoff(Duration::from_millis(10))
+        .build()
+}
+
+#[tokio::test]
+async fn test_bulk_import_batches_across_nodes_and_collects_failures() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    mount_import(&server1, "books").await;
+    mount_import(&server2, "books").await;
+
+    let client = get_client(vec![server1.uri(), server2.uri()]);
+    let books = (0..5).map(|i| Book { id: i.to_string() });
+    let mut reported = Vec::new();
+
+    let report = client
+        .collection::<Book>()
+        .documents()
+        .bulk_import(stream::iter(books), options(), |progress| {
+            reported.push(*progress)
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(report.total, 5);
+    assert_eq!(report.imported, 4);
+    assert_eq!(report.batches, 3);
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].index, 3);
+    assert_eq!(report.failures[0].code, Some(400));
+
+    assert_eq!(reported.len(), 3);
+    assert_eq!(reported.last().unwrap().processed, 5);
+    assert_eq!(reported.last().unwrap().failed, 1);
+
+    // Batches are spread over both nodes.
+    let requests1 = server1.received_requests().await.unwrap().len();
+    let requests2 = server2.received_requests().await.unwrap().len();
+    assert_eq!(requests1 + requests2, 3);
+    assert!(requests1 > 0 && requests2 > 0);
+
+    assert!(report.ensure_all_succeeded().is_err());
+}
+
+#[tokio::test]
+async fn test_bulk_import_retries_failed_batches() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/collections/products/documents/import"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    mount_import(&server, "products").await;
+
+    let client = get_client(vec![server.uri()]);
+    let products = client.collection_schemaless("products");
+    let documents = vec![json!({ "id": "1" }), json!({ "id": "2" })];
+
+    let report = products
+        .documents()
+        .bulk_import(stream::iter(documents), options(), |_| {})
+        .await
+        .unwrap()
+        .ensure_all_succeeded()
+        .unwrap();
+
+    assert_eq!(report.imported, 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_bulk_import_gives_up_after_max_retries() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/collections/products/documents/import"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let client = get_client(vec![server.uri()]);
+    let products = client.collection_schemaless("products");
+    let options = BulkImportOptions {
+        max_retries: 2,
+        ..options()
+    };
+
+    let result = products
+        .documents()
+        .bulk_import(stream::iter(vec![json!({ "id": "1" })]), options, |_| {})
+        .await;
+
+    assert!(matches!(result, Err(Error::AllNodesFailed { .. })));
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
@@ -0,0 +1,232 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest_retry::policies::ExponentialBackoff;
+use std::{sync::Arc, time::Duration};
+use typesense::{CircuitBreaker, CircuitState, Client, Error, HealthChecker};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn get_client(nodes: Vec<String>, circuit_breaker: CircuitBreaker) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .circuit_breaker(circuit_breaker)
+        .build()
+        .expect("Failed to create client")
+}
+
+async fn mount_health(server: &MockServer, status: u16) {
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(
+            ResponseTemplate::new(status).set_body_json(serde_json::json!({ "ok": status == 200 })),
+        )
+        .mount(server)
+        .await;
+}
+
+async fn requests_to(server: &MockServer) -> usize {
+    server.received_requests().await.unwrap().len()
+}
+
+fn circuits(client: &Client) -> Vec<Option<CircuitState>> {
+    client
+        .node_stats()
+        .into_iter()
+        .map(|node| node.circuit)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_circuit_opens_at_failure_rate() {
+    let flapping_server = MockServer::start().await;
+    let healthy_server = MockServer::start().await;
+    // The flapping node fails every other request.
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&flapping_server)
+        .await;
+    mount_health(&healthy_server, 200).await;
+    let circuit_breaker = CircuitBreaker::builder()
+        .failure_rate(0.5)
+        .minimum_requests(2)
+        .build();
+    let client = get_client(
+        vec![flapping_server.uri(), healthy_server.uri()],
+        circuit_breaker,
+    );
+    assert_eq!(
+        circuits(&client),
+        [Some(CircuitState::Closed), Some(CircuitState::Closed)]
+    );
+
+    // The first request fails on the flapping node, which stays available: one failure is not
+    // enough to open its circuit, unlike `set_health(false)` without a circuit breaker.
+    assert!(client.operations().health().await.is_ok());
+    assert_eq!(requests_to(&flapping_server).await, 1);
+    assert_eq!(circuits(&client)[0], Some(CircuitState::Closed));
+
+    flapping_server.reset().await;
+    mount_health(&flapping_server, 200).await;
+    for _ in 0..2 {
+        assert!(client.operations().health().await.is_ok());
+    }
+    // One failure out of two requests: the circuit is open.
+    assert_eq!(requests_to(&flapping_server).await, 1);
+    assert_eq!(circuits(&client)[0], Some(CircuitState::Open));
+
+    for _ in 0..4 {
+        assert!(client.operations().health().await.is_ok());
+    }
+    assert_eq!(requests_to(&flapping_server).await, 1);
+    let stats = &client.node_stats()[0];
+    assert!(stats.retry_in.unwrap() > Duration::from_secs(20));
+}
+
+#[tokio::test]
+async fn test_half_open_circuit_closes_after_successful_probes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    mount_health(&server, 200).await;
+    let circuit_breaker = CircuitBreaker::builder()
+        .minimum_requests(1)
+        .open_duration(Duration::from_millis(100))
+        .half_open_successes(2)
+        .build();
+    let client = get_client(vec![server.uri()], circuit_breaker);
+
+    let result = client.operations().health().await;
+    assert!(matches!(result, Err(Error::AllNodesFailed { .. })));
+    assert_eq!(circuits(&client), [Some(CircuitState::Open)]);
+
+    // While the circuit is open, requests fail without being sent.
+    let result = client.operations().health().await;
+    match result {
+        Err(Error::AllNodesFailed { source, .. }) => {
+            assert!(source.to_string().contains("circuit breaker"), "{source}");
+        }
+        other => panic!("Expected AllNodesFailed, got {other:?}"),
+    }
+    assert_eq!(requests_to(&server).await, 1);
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(circuits(&client), [Some(CircuitState::HalfOpen)]);
+
+    assert!(client.operations().health().await.is_ok());
+    assert_eq!(circuits(&client), [Some(CircuitState::HalfOpen)]);
+    assert!(client.operations().health().await.is_ok());
+    assert_eq!(circuits(&client), [Some(CircuitState::Closed)]);
+    assert_eq!(requests_to(&server).await, 3);
+}
+
+#[tokio::test]
+async fn test_half_open_circuit_limits_concurrent_probes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "ok": true }))
+                .set_delay(Duration::from_millis(200)),
+        )
+        .mount(&server)
+        .await;
+    let circuit_breaker = CircuitBreaker::builder()
+        .minimum_requests(1)
+        .open_duration(Duration::from_millis(50))
+        .half_open_probes(1)
+        .half_open_successes(1)
+        .build();
+    let client = get_client(vec![server.uri()], circuit_breaker);
+
+    assert!(client.operations().health().await.is_err());
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let operations = client.operations();
+    let (first, second) = tokio::join!(operations.health(), async {
+        // Sent while the first probe is in flight.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        operations.health().await
+    });
+
+    assert!(first.is_ok());
+    assert!(matches!(second, Err(Error::AllNodesFailed { .. })));
+    assert_eq!(requests_to(&server).await, 2);
+    assert_eq!(circuits(&client), [Some(CircuitState::Closed)]);
+}
+
+#[tokio::test]
+async fn test_half_open_circuit_reopens_on_failure() {
+    let server = MockServer::start().await;
+    mount_health(&server, 503).await;
+    let circuit_breaker = CircuitBreaker::builder()
+        .minimum_requests(1)
+        .open_duration(Duration::from_millis(50))
+        .build();
+    let client = get_client(vec![server.uri()], circuit_breaker);
+
+    assert!(client.operations().health().await.is_err());
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(circuits(&client), [Some(CircuitState::HalfOpen)]);
+
+    assert!(client.operations().health().await.is_err());
+    assert_eq!(circuits(&client), [Some(CircuitState::Open)]);
+    assert_eq!(requests_to(&server).await, 2);
+}
+
+#[tokio::test]
+async fn test_node_marked_down_by_health_checker_is_skipped() {
+    let down_server = MockServer::start().await;
+    let healthy_server = MockServer::start().await;
+    mount_health(&down_server, 503).await;
+    mount_health(&healthy_server, 200).await;
+    let client = Arc::new(get_client(
+        vec![down_server.uri(), healthy_server.uri()],
+        CircuitBreaker::default(),
+    ));
+
+    HealthChecker::builder()
+        .client(client.clone())
+        .all_nodes(true)
+        .build()
+        .check()
+        .await;
+    let checks = requests_to(&down_server).await;
+
+    // The circuit of the node is still closed, but the node is marked down.
+    let stats = &client.node_stats()[0];
+    assert_eq!(stats.circuit, Some(CircuitState::Closed));
+    assert!(!stats.is_healthy);
+    for _ in 0..4 {
+        assert!(client.operations().health().await.is_ok());
+    }
+    assert_eq!(requests_to(&down_server).await, checks);
+}
+
+#[tokio::test]
+async fn test_node_stats_without_circuit_breaker() {
+    let client = Client::builder()
+        .nodes(vec!["http://localhost:8108"])
+        .api_key("test-key")
+        .build()
+        .unwrap();
+
+    assert_eq!(circuits(&client), [None]);
+}
@@ -266,7 +266,10 @@ async fn test_fail_fast_on_non_retriable_error() {
 
     // Check that the error is the non-retriable API error.
     match result.err().unwrap() {
-        Error::Api(typesense_codegen::apis::Error::ResponseError(content)) => {
+        Error::Api {
+            source: typesense_codegen::apis::Error::ResponseError(content),
+            ..
+        } => {
             assert_eq!(content.status, reqwest::StatusCode::NOT_FOUND);
         }
         e => panic!("Expected an API error, but got {:?}", e),
@@ -0,0 +1,187 @@
+#![cfg(not(target_family = "wasm"))]
+
+use std::time::Duration;
+use typesense::{Client, ClientConfig, ConfigError};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+#[test]
+fn test_from_url_parses_hosts_and_parameters() {
+    let config = ClientConfig::from_url(
+        "https://s3cr%40t@node-1,node-2:8443,node-3?nearest=lb:443&healthcheck=30s\
+         &max_retries=1&connection_timeout=500ms&timeout=2m",
+    )
+    .unwrap();
+
+    assert_eq!(
+        config,
+        ClientConfig {
+            nodes: vec![
+                "https://node-1:8443".to_owned(),
+                "https://node-2:8443".to_owned(),
+                "https://node-3:8443".to_owned(),
+            ],
+            nearest_node: Some("https://lb:443".to_owned()),
+            api_key: "s3cr@t".to_owned(),
+            healthcheck_interval: Some(Duration::from_secs(30)),
+            max_retries: Some(1),
+            connection_timeout: Some(Duration::from_millis(500)),
+            timeout: Some(Duration::from_secs(120)),
+        }
+    );
+}
+
+#[test]
+fn test_from_url_keeps_path_and_default_port() {
+    let config: ClientConfig = "http://xyz@localhost/typesense/".parse().unwrap();
+    assert_eq!(config.nodes, vec!["http://localhost/typesense"]);
+    assert_eq!(config.api_key, "xyz");
+    assert_eq!(config.healthcheck_interval, None);
+}
+
+#[test]
+fn test_from_url_accepts_bracketed_ipv6_hosts() {
+    let config = ClientConfig::from_url("http://xyz@[::1],[fe80::1]:8108/typesense").unwrap();
+    assert_eq!(
+        config.nodes,
+        vec![
+            "http://[::1]:8108/typesense",
+            "http://[fe80::1]:8108/typesense"
+        ]
+    );
+
+    let config = ClientConfig::from_url("https://xyz@[::1]?nearest=[::2]:443").unwrap();
+    assert_eq!(config.nodes, vec!["https://[::1]"]);
+    assert_eq!(config.nearest_node.as_deref(), Some("https://[::2]:443"));
+}
+
+#[test]
+fn test_from_url_rejects_invalid_urls() {
+    for url in [
+        "localhost:8108",
+        "ftp://xyz@localhost:8108",
+        "http://localhost:8108",
+        "http://xyz@localhost:port",
+        "http://xyz@[::1]:port",
+        "http://xyz@node-1,,node-2",
+        "http://xyz@localhost?healthcheck=soon",
+        "http://xyz@localhost?region=eu",
+    ] {
+        assert!(
+            matches!(ClientConfig::from_url(url), Err(ConfigError::InvalidUrl(_))),
+            "{url} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_deserialize_config() {
+    let config: ClientConfig = serde_json::from_value(serde_json::json!({
+        "nodes": ["http://node-1:8108"],
+        "api_key": "xyz",
+        "healthcheck_interval": 15,
+        "timeout": "1.5s",
+    }))
+    .unwrap();
+    assert_eq!(config.healthcheck_interval, Some(Duration::from_secs(15)));
+    assert_eq!(config.timeout, Some(Duration::from_millis(1500)));
+    assert_eq!(config.connection_timeout, None);
+
+    let error = serde_json::from_value::<ClientConfig>(serde_json::json!({
+        "nodes": [],
+        "api_key": "xyz",
+        "timeout": "forever",
+    }))
+    .unwrap_err();
+    assert!(error.to_string().contains("invalid duration `forever`"));
+}
+
+#[test]
+fn test_from_config_without_nodes_fails() {
+    let error = Client::from_config(ClientConfig {
+        api_key: "xyz".to_owned(),
+        ..ClientConfig::default()
+    })
+    .unwrap_err();
+    assert!(matches!(error, ConfigError::Client(_)));
+}
+
+#[test]
+fn test_debug_redacts_api_key() {
+    let config: ClientConfig = "http://s3cr3t-key@node-1:8108".parse().unwrap();
+    let debug = format!("{config:?}");
+    assert!(debug.contains("http://node-1:8108"));
+    assert!(!debug.contains("s3cr3t-key"));
+
+    let client = Client::from_config(config).unwrap();
+    let debug = format!("{client:?}");
+    assert!(debug.contains("http://node-1:8108"));
+    assert!(!debug.contains("s3cr3t-key"));
+}
+
+#[tokio::test]
+async fn test_client_from_url_sends_requests() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .and(header("X-TYPESENSE-API-KEY", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "ok": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let address = server.address();
+    let client = Client::from_url(&format!(
+        "http://test-key@{}:{}?max_retries=0&timeout=5s",
+        address.ip(),
+        address.port()
+    ))
+    .unwrap();
+    assert!(client.operations().health().await.unwrap().ok);
+}
+
+#[tokio::test]
+async fn test_client_from_env() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .and(header("X-TYPESENSE-API-KEY", "env-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "ok": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // SAFETY: no other test reads or writes these variables.
+    unsafe {
+        std::env::set_var("TYPESENSE_API_KEY", "env-key");
+        std::env::set_var("TYPESENSE_HOST", server.address().ip().to_string());
+        std::env::set_var("TYPESENSE_PORT", server.address().port().to_string());
+        std::env::set_var("TYPESENSE_HEALTHCHECK_INTERVAL", "45s");
+    }
+    let config = ClientConfig::from_env().unwrap();
+    assert_eq!(config.nodes, vec![server.uri()]);
+    assert_eq!(config.healthcheck_interval, Some(Duration::from_secs(45)));
+
+    // A host with its own port keeps it, and a bracketed IPv6 host gets the default one.
+    unsafe {
+        std::env::set_var("TYPESENSE_HOST", format!("{},[::1]", server.address()));
+    }
+    let config = ClientConfig::from_env().unwrap();
+    assert_eq!(
+        config.nodes,
+        vec![
+            server.uri(),
+            format!("http://[::1]:{}", server.address().port())
+        ]
+    );
+
+    unsafe {
+        std::env::set_var("TYPESENSE_HOST", server.address().to_string());
+        std::env::remove_var("TYPESENSE_PORT");
+    }
+
+    let client = Client::from_env().unwrap();
+    assert!(client.operations().health().await.unwrap().ok);
+}
@@ -74,7 +74,7 @@ async fn test_create_model_with_invalid_key_fails_as_expected() {
         "Model creation should have failed due to an invalid API key, but it succeeded."
     );
     match create_result.err() {
-        Some(TypesenseError::Api(response_content)) => match response_content {
+        Some(TypesenseError::Api { source, .. }) => match source {
             typesense::ApiError::ResponseError(api_error) => {
                 assert_eq!(
                     api_error.status.as_u16(),
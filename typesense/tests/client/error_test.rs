@@ -0,0 +1,192 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest::StatusCode;
+use reqwest_retry::policies::ExponentialBackoff;
+use std::time::Duration;
+use typesense::{AnyError, Client, Error, ErrorKind};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn get_client(nodes: Vec<String>) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
+async fn mount_error(server: &MockServer, http_method: &str, url: &str, status: u16) {
+    Mock::given(method(http_method))
+        .and(path(url))
+        .respond_with(
+            ResponseTemplate::new(status)
+                .set_body_json(serde_json::json!({ "message": format!("Error {status}") })),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_client_errors_expose_status_message_and_node() {
+    let server = MockServer::start().await;
+    mount_error(&server, "GET", "/collections/books", 404).await;
+    mount_error(&server, "GET", "/keys/1", 401).await;
+    mount_error(&server, "POST", "/collections", 409).await;
+    mount_error(&server, "DELETE", "/aliases/books", 400).await;
+    let client = get_client(vec![server.uri()]);
+
+    let error = client
+        .collection_schemaless("books")
+        .retrieve()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::Api { .. }));
+    assert!(error.is_not_found());
+    assert!(!error.is_conflict());
+    assert_eq!(error.status(), Some(StatusCode::NOT_FOUND));
+    assert_eq!(error.message().as_deref(), Some("Error 404"));
+    assert_eq!(error.node(), Some(server.uri().as_str()));
+
+    let error = client.key(1).retrieve().await.unwrap_err();
+    assert!(error.is_unauthorized());
+    assert_eq!(error.message().as_deref(), Some("Error 401"));
+
+    let schema = typesense::models::CollectionSchema {
+        name: "books".into(),
+        ..Default::default()
+    };
+    let error = client.collections().create(schema).await.unwrap_err();
+    assert!(error.is_conflict());
+
+    let error = client.alias("books").delete().await.unwrap_err();
+    assert!(error.is_bad_request());
+    assert!(!error.is_not_found());
+}
+
+#[tokio::test]
+async fn test_all_nodes_failed_exposes_the_last_node() {
+    let first_node = MockServer::start().await;
+    let last_node = MockServer::start().await;
+    for server in [&first_node, &last_node] {
+        Mock::given(method("GET"))
+            .and(path("/collections/books"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Service Unavailable\n"))
+            .mount(server)
+            .await;
+    }
+    let client = get_client(vec![first_node.uri(), last_node.uri()]);
+
+    let error = client
+        .collection_schemaless("books")
+        .retrieve()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, Error::AllNodesFailed { .. }));
+    assert_eq!(error.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+    assert_eq!(error.message().as_deref(), Some("Service Unavailable"));
+    assert_eq!(error.node(), Some(last_node.uri().as_str()));
+    assert!(!error.is_not_found());
+}
+
+#[tokio::test]
+async fn test_all_nodes_failed_records_every_attempt() {
+    let refused_node = "http://127.0.0.1:1".to_owned();
+    let unavailable_node = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/collections/books"))
+        .respond_with(ResponseTemplate::new(503).set_delay(Duration::from_millis(50)))
+        .mount(&unavailable_node)
+        .await;
+    let client = get_client(vec![refused_node.clone(), unavailable_node.uri()]);
+
+    let error = client
+        .collection_schemaless("books")
+        .retrieve()
+        .await
+        .unwrap_err();
+
+    let Error::AllNodesFailed { source, attempts } = &error else {
+        panic!("Expected AllNodesFailed, got {error:?}");
+    };
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[0].node, refused_node);
+    assert_eq!(attempts[1].node, unavailable_node.uri());
+    assert!(attempts[1].error.contains("503"));
+    assert!(attempts[1].elapsed >= Duration::from_millis(50));
+    assert_eq!(attempts[1].error, source.to_string());
+
+    let message = error.to_string();
+    assert!(message.contains(&refused_node));
+    assert!(message.contains(&unavailable_node.uri()));
+    assert!(message.contains("503"));
+}
+
+#[tokio::test]
+async fn test_network_errors_have_no_status_or_message() {
+    let client = get_client(vec!["http://127.0.0.1:1".to_owned()]);
+
+    let error = client
+        .collection_schemaless("books")
+        .retrieve()
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status(), None);
+    assert_eq!(error.message(), None);
+    assert_eq!(error.node(), Some("http://127.0.0.1:1"));
+}
+
+/// Calls two endpoints with different error types, and returns the first error.
+async fn retrieve_collection_and_key(client: &Client) -> Result<(), AnyError> {
+    client.collection_schemaless("books").retrieve().await?;
+    client.key(1).retrieve().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_any_error_keeps_the_error_details() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/collections/books"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "books",
+            "fields": [],
+            "num_documents": 0,
+            "created_at": 0,
+        })))
+        .mount(&server)
+        .await;
+    mount_error(&server, "GET", "/keys/1", 404).await;
+    let client = get_client(vec![server.uri()]);
+
+    let error = retrieve_collection_and_key(&client).await.unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::Api);
+    assert!(error.is_not_found());
+    assert!(!error.is_retriable());
+    assert_eq!(error.message(), Some("Error 404"));
+    assert_eq!(error.node(), Some(server.uri().as_str()));
+    let original = error
+        .get_ref()
+        .downcast_ref::<Error<typesense::legacy::apis::keys_api::GetKeyError>>()
+        .expect("The original error should be kept");
+    assert_eq!(error.to_string(), original.to_string());
+}
+
+#[tokio::test]
+async fn test_any_error_of_all_nodes_failed_is_retriable() {
+    let client = get_client(vec!["http://127.0.0.1:1".to_owned()]);
+
+    let error = retrieve_collection_and_key(&client).await.unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::AllNodesFailed);
+    assert!(error.is_retriable());
+    assert_eq!(error.attempts().len(), 1);
+    assert_eq!(error.attempts()[0].node, "http://127.0.0.1:1");
+    assert_eq!(error.status(), None);
+}
@@ -0,0 +1,149 @@
+#![cfg(not(target_family = "wasm"))]
+
+use futures::{StreamExt, TryStreamExt};
+use reqwest_retry::policies::ExponentialBackoff;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use typesense::{Client, Error, models::ExportDocumentsParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+const EXPORT_PATH: &str = "/collections/products/documents/export";
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Product {
+    id: String,
+    price: u32,
+}
+
+async fn mount_export(server: &MockServer, body: &str) {
+    Mock::given(method("GET"))
+        .and(path(EXPORT_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .mount(server)
+        .await;
+}
+
+fn get_client(nodes: Vec<String>) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
+#[tokio::test]
+async fn test_export_stream_yields_typed_documents_and_line_errors() {
+    let server = MockServer::start().await;
+    mount_export(
+        &server,
+        "{\"id\":\"1\",\"price\":10}\n{\"id\":\"2\",\"price\":\"oops\"}\n{\"id\":\"3\",\"price\":30}",
+    )
+    .await;
+
+    let client = get_client(vec![server.uri()]);
+    let products = client.collection_named::<Product>("products");
+    let stream = products
+        .documents()
+        .export_stream(ExportDocumentsParameters::default())
+        .await
+        .unwrap();
+    let results: Vec<_> = stream.collect().await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results[0].as_ref().unwrap(),
+        &Product {
+            id: "1".to_owned(),
+            price: 10
+        }
+    );
+    assert!(matches!(results[1], Err(Error::Deserialization(_))));
+    assert_eq!(results[2].as_ref().unwrap().id, "3");
+}
+
+#[tokio::test]
+async fn test_export_jsonl_stream_forwards_params_and_skips_blank_lines() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(EXPORT_PATH))
+        .and(query_param("filter_by", "price:>10"))
+        .and(query_param("include_fields", "id"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string("{\"id\":\"2\"}\r\n\n{\"id\":\"3\"}\n"),
+        )
+        .mount(&server)
+        .await;
+
+    let client = get_client(vec![server.uri()]);
+    let products = client.collection_schemaless("products");
+    let params = ExportDocumentsParameters {
+        filter_by: Some("price:>10".into()),
+        include_fields: Some("id".into()),
+        ..Default::default()
+    };
+    let lines: Vec<String> = products
+        .documents()
+        .export_jsonl_stream(params)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(lines, ["{\"id\":\"2\"}", "{\"id\":\"3\"}"]);
+}
+
+#[tokio::test]
+async fn test_export_to_writer_copies_the_body() {
+    let server = MockServer::start().await;
+    let body = "{\"id\":\"1\",\"price\":10}\n{\"id\":\"2\",\"price\":20}";
+    mount_export(&server, body).await;
+
+    let client = get_client(vec![server.uri()]);
+    let products = client.collection_schemaless("products");
+    let mut output = Vec::new();
+    let written = products
+        .documents()
+        .export_to_writer(ExportDocumentsParameters::default(), &mut output)
+        .await
+        .unwrap();
+
+    assert_eq!(written, body.len() as u64);
+    assert_eq!(output, body.as_bytes());
+}
+
+#[tokio::test]
+async fn test_export_stream_fails_over_and_reports_errors_upfront() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(EXPORT_PATH))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server1)
+        .await;
+    mount_export(&server2, "{\"id\":\"1\",\"price\":10}\n").await;
+
+    let client = get_client(vec![server1.uri(), server2.uri()]);
+    let products = client.collection_named::<Product>("products");
+    let documents: Vec<_> = products
+        .documents()
+        .export_stream(ExportDocumentsParameters::default())
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(documents.len(), 1);
+
+    let missing = client.collection_schemaless("missing");
+    let result = missing
+        .documents()
+        .export_jsonl_stream(ExportDocumentsParameters::default())
+        .await;
+    assert!(matches!(result, Err(Error::Api { .. })));
+}
@@ -0,0 +1,240 @@
+use typesense::{
+    FilterError,
+    filter::{Filter, GeoUnit},
+    models::{DeleteDocumentsParameters, ExportDocumentsParameters, SearchParameters},
+};
+
+#[test]
+fn test_filter_renders_conditions() -> Result<(), FilterError> {
+    assert_eq!(
+        Filter::field("brand").matches("Nike")?.to_string(),
+        "brand:Nike"
+    );
+    assert_eq!(
+        Filter::field("brand").eq("Nike")?.to_string(),
+        "brand:=Nike"
+    );
+    assert_eq!(
+        Filter::field("brand").not_eq("Nike")?.to_string(),
+        "brand:!=Nike"
+    );
+    assert_eq!(Filter::field("price").gt(10)?.to_string(), "price:>10");
+    assert_eq!(
+        Filter::field("price").gte(10.5)?.to_string(),
+        "price:>=10.5"
+    );
+    assert_eq!(Filter::field("price").lt(-3)?.to_string(), "price:<-3");
+    assert_eq!(
+        Filter::field("price").lte(100u64)?.to_string(),
+        "price:<=100"
+    );
+    assert_eq!(
+        Filter::field("in_stock").eq(true)?.to_string(),
+        "in_stock:=true"
+    );
+    assert_eq!(
+        Filter::field("price").range(10, 100)?.to_string(),
+        "price:[10..100]"
+    );
+    assert_eq!(
+        Filter::field("brand").in_(["Nike", "Adidas"])?.to_string(),
+        "brand:=[Nike, Adidas]"
+    );
+    assert_eq!(
+        Filter::field("brand")
+            .not_in(["Nike", "Adidas"])?
+            .to_string(),
+        "brand:!=[Nike, Adidas]"
+    );
+    assert_eq!(
+        Filter::field("tags")
+            .matches_any(["red", "blue"])?
+            .to_string(),
+        "tags:[red, blue]"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_filter_escapes_string_values() -> Result<(), FilterError> {
+    assert_eq!(
+        Filter::field("title").eq("Hello, World")?.to_string(),
+        "title:=`Hello, World`"
+    );
+    assert_eq!(
+        Filter::field("title").eq("a && b")?.to_string(),
+        "title:=`a && b`"
+    );
+    assert_eq!(Filter::field("title").eq("")?.to_string(), "title:=``");
+    assert_eq!(
+        Filter::field("details.color").eq("Red")?.to_string(),
+        "details.color:=Red"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_filter_combines_with_grouping() -> Result<(), FilterError> {
+    let filter = Filter::field("price")
+        .gte(10)?
+        .and(Filter::field("brand").in_(["a", "b"])?);
+    assert_eq!(filter.to_string(), "price:>=10 && brand:=[a, b]");
+
+    let filter = Filter::field("a")
+        .eq(1)?
+        .or(Filter::field("b").eq(2)?)
+        .and(Filter::field("c").eq(3)?);
+    assert_eq!(filter.to_string(), "(a:=1 || b:=2) && c:=3");
+
+    let filter = Filter::any([
+        Filter::field("a").eq(1)?.and(Filter::field("b").eq(2)?),
+        Filter::field("c").eq(3)?,
+    ])?;
+    assert_eq!(filter.to_string(), "(a:=1 && b:=2) || c:=3");
+
+    let filter = Filter::all([Filter::raw("x:1 || y:2"), Filter::field("z").eq(3)?])?;
+    assert_eq!(filter.to_string(), "(x:1 || y:2) && z:=3");
+
+    let filter = Filter::all([Filter::raw(" "), Filter::field("z").eq(3)?])?;
+    assert_eq!(filter.to_string(), "z:=3");
+    Ok(())
+}
+
+#[test]
+fn test_filter_renders_geo_and_joins() -> Result<(), FilterError> {
+    assert_eq!(
+        Filter::field("location")
+            .within_radius(48.8, 2.3, 5.1, GeoUnit::Kilometers)?
+            .to_string(),
+        "location:(48.8, 2.3, 5.1 km)"
+    );
+    assert_eq!(
+        Filter::field("location")
+            .within_polygon([(48.8662, 2.3255), (48.8581, 2.3209), (48.8561, 2.3448)])?
+            .to_string(),
+        "location:(48.8662, 2.3255, 48.8581, 2.3209, 48.8561, 2.3448)"
+    );
+    assert_eq!(
+        Filter::join("authors", Filter::field("country").eq("France")?).to_string(),
+        "$authors(country:=France)"
+    );
+    assert_eq!(
+        Filter::not_join("authors", Filter::field("country").eq("France")?)
+            .and(Filter::field("year").gt(2000)?)
+            .to_string(),
+        "!$authors(country:=France) && year:>2000"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_filter_converts_into_filter_by_params() -> Result<(), FilterError> {
+    let filter = Filter::field("year").gt(1960)?;
+
+    let search = SearchParameters::builder().filter_by(&filter).build();
+    assert_eq!(search.filter_by.as_deref(), Some("year:>1960"));
+
+    let export = ExportDocumentsParameters {
+        filter_by: Some(filter.build().into()),
+        ..Default::default()
+    };
+    assert_eq!(export.filter_by.as_deref(), Some("year:>1960"));
+
+    let delete = DeleteDocumentsParameters::new(filter.into());
+    assert_eq!(delete.filter_by, "year:>1960");
+    Ok(())
+}
+
+#[test]
+fn test_filter_rejects_what_typesense_cannot_parse() {
+    let no_values: [&str; 0] = [];
+    assert_eq!(
+        Filter::field("brand").in_(no_values),
+        Err(FilterError::EmptyValues("brand".to_owned()))
+    );
+    assert_eq!(
+        Filter::field("brand").not_in(no_values),
+        Err(FilterError::EmptyValues("brand".to_owned()))
+    );
+    assert_eq!(
+        Filter::field("tags").matches_any(no_values),
+        Err(FilterError::EmptyValues("tags".to_owned()))
+    );
+    assert_eq!(Filter::all(Vec::<Filter>::new()), Err(FilterError::Empty));
+    assert_eq!(Filter::any([Filter::raw("")]), Err(FilterError::Empty));
+    assert_eq!(
+        Filter::field("title").eq("a `quoted` word"),
+        Err(FilterError::Backtick("a `quoted` word".to_owned()))
+    );
+    assert_eq!(
+        Filter::field("price").gt(f64::NAN),
+        Err(FilterError::NonFinite("price".to_owned()))
+    );
+    assert_eq!(
+        Filter::field("price").range(0.0, f32::INFINITY),
+        Err(FilterError::NonFinite("price".to_owned()))
+    );
+    assert_eq!(
+        Filter::field("location").within_radius(f64::NAN, 2.3, 5.0, GeoUnit::Kilometers),
+        Err(FilterError::NonFinite("location".to_owned()))
+    );
+    assert_eq!(
+        Filter::field("location").within_polygon([
+            (48.8, 2.3),
+            (48.9, f64::NEG_INFINITY),
+            (48.7, 2.4)
+        ]),
+        Err(FilterError::NonFinite("location".to_owned()))
+    );
+    assert_eq!(
+        Filter::field("location").within_polygon([(48.8, 2.3), (48.9, 2.4)]),
+        Err(FilterError::TooFewPoints("location".to_owned()))
+    );
+}
+
+#[test]
+fn test_filter_negation_is_pushed_down() -> Result<(), FilterError> {
+    assert_eq!(
+        Filter::field("brand").eq("Nike")?.not()?.to_string(),
+        "brand:!=Nike"
+    );
+    assert_eq!(
+        Filter::field("brand")
+            .not_in(["a", "b"])?
+            .not()?
+            .to_string(),
+        "brand:=[a, b]"
+    );
+    assert_eq!(
+        Filter::field("price").gt(10)?.not()?.to_string(),
+        "price:<=10"
+    );
+    assert_eq!(
+        Filter::field("price").lte(10)?.not()?.to_string(),
+        "price:>10"
+    );
+    assert_eq!(
+        Filter::field("a")
+            .eq(1)?
+            .and(Filter::field("b").range(2, 5)?)
+            .not()?
+            .to_string(),
+        "a:!=1 || b:<2 || b:>5"
+    );
+    assert_eq!(
+        Filter::join("authors", Filter::field("country").eq("France")?)
+            .not()?
+            .to_string(),
+        "!$authors(country:=France)"
+    );
+    assert_eq!(Filter::field("a").eq(1)?.not()?.not()?.to_string(), "a:=1");
+    assert_eq!(
+        Filter::raw("x:1").not(),
+        Err(FilterError::UnsupportedNegation("x:1".to_owned()))
+    );
+    assert_eq!(
+        Filter::field("tags").matches("red")?.not(),
+        Err(FilterError::UnsupportedNegation("tags:red".to_owned()))
+    );
+    Ok(())
+}
@@ -0,0 +1,138 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest_retry::policies::ExponentialBackoff;
+use std::{sync::Arc, time::Duration};
+use typesense::{Client, HealthChecker};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn get_client(nodes: Vec<String>) -> Arc<Client> {
+    Arc::new(
+        Client::builder()
+            .nodes(nodes)
+            .api_key("test-key")
+            .healthcheck_interval(Duration::from_secs(60))
+            .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+            .build()
+            .expect("Failed to create client"),
+    )
+}
+
+fn health(ok: bool) -> ResponseTemplate {
+    ResponseTemplate::new(if ok { 200 } else { 503 }).set_body_json(serde_json::json!({ "ok": ok }))
+}
+
+fn health_of(client: &Client) -> Vec<bool> {
+    client
+        .node_stats()
+        .into_iter()
+        .map(|node| node.is_healthy)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_check_all_nodes_marks_them_unhealthy_and_recovered() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(health(false))
+        .up_to_n_times(1)
+        .mount(&server1)
+        .await;
+    for server in [&server1, &server2] {
+        Mock::given(method("GET"))
+            .and(path("/health"))
+            .respond_with(health(true))
+            .mount(server)
+            .await;
+    }
+
+    let client = get_client(vec![server1.uri(), server2.uri()]);
+    let checker = HealthChecker::builder()
+        .client(client.clone())
+        .all_nodes(true)
+        .build();
+
+    checker.check().await;
+    assert_eq!(health_of(&client), [false, true]);
+
+    checker.check().await;
+    assert_eq!(health_of(&client), [true, true]);
+}
+
+#[tokio::test]
+async fn test_check_only_probes_unhealthy_nodes_by_default() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/debug"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "version": "30.0" })),
+        )
+        .mount(&server1)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/debug"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server2)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(health(true))
+        .expect(0)
+        .mount(&server1)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(health(true))
+        .expect(1)
+        .mount(&server2)
+        .await;
+
+    let client = get_client(vec![server1.uri(), server2.uri()]);
+    // The second request is sent to the second node, which fails and is marked unhealthy.
+    for _ in 0..2 {
+        assert!(client.operations().debug().await.is_ok());
+    }
+    assert_eq!(health_of(&client), [true, false]);
+
+    HealthChecker::builder()
+        .client(client.clone())
+        .build()
+        .check()
+        .await;
+    assert_eq!(health_of(&client), [true, true]);
+}
+
+#[tokio::test]
+async fn test_run_until_shutdown() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(health(true))
+        .mount(&server)
+        .await;
+
+    let checker = HealthChecker::builder()
+        .client(get_client(vec![server.uri()]))
+        .interval(Duration::from_millis(10))
+        .all_nodes(true)
+        .build();
+    let handle = checker.handle();
+    let task = tokio::spawn(checker.run());
+
+    while server.received_requests().await.unwrap().len() < 3 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert!(!handle.is_shutdown());
+    handle.shutdown();
+
+    tokio::time::timeout(Duration::from_secs(1), task)
+        .await
+        .expect("The checker did not stop")
+        .unwrap();
+    assert!(handle.is_shutdown());
+}
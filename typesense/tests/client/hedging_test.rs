@@ -0,0 +1,146 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest_retry::policies::ExponentialBackoff;
+use std::time::{Duration, Instant};
+use typesense::{Client, models::SearchParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn get_client(nodes: Vec<String>, hedge_delay: Duration) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .hedge_delay(hedge_delay)
+        .build()
+        .expect("Failed to create client")
+}
+
+async fn mount_health(server: &MockServer, delay: Duration) {
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "ok": true }))
+                .set_delay(delay),
+        )
+        .mount(server)
+        .await;
+}
+
+async fn requests_to(server: &MockServer) -> usize {
+    server.received_requests().await.unwrap().len()
+}
+
+#[tokio::test]
+async fn test_slow_read_is_hedged() {
+    let slow_node = MockServer::start().await;
+    let fast_node = MockServer::start().await;
+    mount_health(&slow_node, Duration::from_secs(5)).await;
+    mount_health(&fast_node, Duration::ZERO).await;
+    let client = get_client(
+        vec![slow_node.uri(), fast_node.uri()],
+        Duration::from_millis(100),
+    );
+
+    let started = Instant::now();
+    let health = client.operations().health().await.unwrap();
+
+    assert!(health.ok);
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert_eq!(requests_to(&slow_node).await, 1);
+    assert_eq!(requests_to(&fast_node).await, 1);
+}
+
+#[tokio::test]
+async fn test_fast_read_is_not_hedged() {
+    let first_node = MockServer::start().await;
+    let second_node = MockServer::start().await;
+    mount_health(&first_node, Duration::ZERO).await;
+    mount_health(&second_node, Duration::ZERO).await;
+    let client = get_client(
+        vec![first_node.uri(), second_node.uri()],
+        Duration::from_secs(1),
+    );
+
+    client.operations().health().await.unwrap();
+
+    assert_eq!(requests_to(&first_node).await, 1);
+    assert_eq!(requests_to(&second_node).await, 0);
+}
+
+#[tokio::test]
+async fn test_write_is_not_hedged() {
+    let slow_node = MockServer::start().await;
+    let other_node = MockServer::start().await;
+    for (server, delay) in [
+        (&slow_node, Duration::from_millis(500)),
+        (&other_node, Duration::ZERO),
+    ] {
+        Mock::given(method("DELETE"))
+            .and(path("/collections/books/documents/1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "id": "1" }))
+                    .set_delay(delay),
+            )
+            .mount(server)
+            .await;
+    }
+    let client = get_client(
+        vec![slow_node.uri(), other_node.uri()],
+        Duration::from_millis(50),
+    );
+
+    client
+        .collection_schemaless("books")
+        .document("1")
+        .delete()
+        .await
+        .unwrap();
+
+    assert_eq!(requests_to(&slow_node).await, 1);
+    assert_eq!(requests_to(&other_node).await, 0);
+}
+
+#[tokio::test]
+async fn test_conversational_search_is_not_hedged() {
+    let slow_node = MockServer::start().await;
+    let other_node = MockServer::start().await;
+    for (server, delay) in [
+        (&slow_node, Duration::from_millis(500)),
+        (&other_node, Duration::ZERO),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/collections/books/documents/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "found": 0, "hits": [] }))
+                    .set_delay(delay),
+            )
+            .mount(server)
+            .await;
+    }
+    let client = get_client(
+        vec![slow_node.uri(), other_node.uri()],
+        Duration::from_millis(50),
+    );
+
+    let params = SearchParameters::builder()
+        .q("what is dune about?")
+        .conversation(true)
+        .conversation_model_id("model")
+        .build();
+    client
+        .collection_schemaless("books")
+        .documents()
+        .search(params)
+        .await
+        .unwrap();
+
+    assert_eq!(requests_to(&slow_node).await, 1);
+    assert_eq!(requests_to(&other_node).await, 0);
+}
@@ -0,0 +1,190 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest_retry::policies::ExponentialBackoff;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use typesense::{
+    Client, ImportFailure, Typesense,
+    models::{ImportDocumentsParameters, IndexAction},
+    prelude::*,
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_string, method, path, query_param},
+};
+
+#[derive(Typesense, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[typesense(collection_name = "books")]
+struct Book {
+    id: String,
+    title: String,
+}
+
+fn book(id: &str, title: &str) -> Book {
+    Book {
+        id: id.to_owned(),
+        title: title.to_owned(),
+    }
+}
+
+fn get_client(server: &MockServer) -> Client {
+    Client::builder()
+        .nodes(vec![server.uri()])
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
+#[tokio::test]
+async fn test_import_serializes_documents_and_parses_results() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .and(query_param("action", "upsert"))
+        .and(query_param("return_doc", "true"))
+        .and(body_string(
+            "{\"id\":\"1\",\"title\":\"Dune\"}\n{\"id\":\"2\",\"title\":\"Emma\"}",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "{\"success\":true,\"id\":\"1\",\"document\":{\"id\":\"1\",\"title\":\"Dune\"}}\n\
+             {\"success\":true,\"id\":\"2\",\"document\":{\"id\":\"2\",\"title\":\"Emma\"}}",
+        ))
+        .mount(&server)
+        .await;
+
+    let client = get_client(&server);
+    let params = ImportDocumentsParameters {
+        action: Some(IndexAction::Upsert),
+        return_doc: Some(true),
+        return_id: Some(true),
+        ..Default::default()
+    };
+    let results = client
+        .collection::<Book>()
+        .documents()
+        .import(&[book("1", "Dune"), book("2", "Emma")], params)
+        .await
+        .unwrap()
+        .ensure_all_succeeded()
+        .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.success));
+    assert_eq!(results[1].id.as_deref(), Some("2"));
+    assert_eq!(results[1].document, Some(book("2", "Emma")));
+}
+
+#[tokio::test]
+async fn test_import_reports_partial_failures() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "{\"success\":true}\n\
+             {\"success\":false,\"error\":\"A document with id 2 already exists.\",\"code\":409,\"document\":\"{\\\"id\\\":\\\"2\\\",\\\"title\\\":\\\"Emma\\\"}\"}\n",
+        ))
+        .mount(&server)
+        .await;
+
+    let client = get_client(&server);
+    let results = client
+        .collection::<Book>()
+        .documents()
+        .import(
+            &[book("1", "Dune"), book("2", "Emma")],
+            ImportDocumentsParameters::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(results[0].success);
+    assert!(!results[1].success);
+    assert_eq!(results[1].code, Some(409));
+    assert_eq!(results[1].document, None);
+
+    let error = results.ensure_all_succeeded().unwrap_err();
+    assert_eq!(error.total, 2);
+    assert_eq!(
+        error.failures,
+        [ImportFailure {
+            index: 1,
+            error: "A document with id 2 already exists.".to_owned(),
+            code: Some(409),
+            document: Some("{\"id\":\"2\",\"title\":\"Emma\"}".to_owned()),
+        }]
+    );
+    assert_eq!(
+        error.to_string(),
+        "1 of 2 documents failed to import. First error: A document with id 2 already exists."
+    );
+}
+
+#[tokio::test]
+async fn test_import_keeps_results_around_unparseable_lines() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "{\"success\":true}\n\
+             not a result\n\
+             {\"success\":true,\"document\":{\"id\":\"3\"}}\n",
+        ))
+        .mount(&server)
+        .await;
+
+    let client = get_client(&server);
+    let results = client
+        .collection::<Book>()
+        .documents()
+        .import(
+            &[book("1", "Dune"), book("2", "Emma"), book("3", "Ulysses")],
+            ImportDocumentsParameters::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].success);
+    assert_eq!(results[0].parse_error, None);
+
+    assert!(!results[1].success);
+    assert!(
+        results[1]
+            .parse_error
+            .as_deref()
+            .unwrap()
+            .starts_with("invalid import result `not a result`")
+    );
+
+    // The document was imported, but could not be deserialized into `Book`
+    assert!(results[2].success);
+    assert_eq!(results[2].document, None);
+    assert!(results[2].parse_error.is_some());
+
+    let error = results.ensure_all_succeeded().unwrap_err();
+    assert_eq!(error.failures.len(), 1);
+    assert_eq!(error.failures[0].index, 1);
+}
+
+#[tokio::test]
+async fn test_import_of_no_documents_sends_no_request() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = get_client(&server);
+    let results = client
+        .collection::<Book>()
+        .documents()
+        .import(&[], ImportDocumentsParameters::default())
+        .await
+        .unwrap();
+
+    assert!(results.is_empty());
+}
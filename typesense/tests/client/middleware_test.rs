@@ -0,0 +1,210 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest::header::HeaderValue;
+use reqwest_retry::policies::ExponentialBackoff;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use typesense::{
+    Client, Error, RequestOptions,
+    middleware::{Extensions, Middleware, Next, Result},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, headers, method, path},
+};
+
+/// Adds a header to every request.
+struct Sign(&'static str);
+
+#[async_trait::async_trait]
+impl Middleware for Sign {
+    async fn handle(
+        &self,
+        mut request: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<reqwest::Response> {
+        request
+            .headers_mut()
+            .append("x-signature", HeaderValue::from_static(self.0));
+        next.run(request, extensions).await
+    }
+}
+
+/// Records the URL of every request and the status of its response.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<(String, u16)>>>);
+
+#[async_trait::async_trait]
+impl Middleware for Recorder {
+    async fn handle(
+        &self,
+        request: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<reqwest::Response> {
+        let url = request.url().to_string();
+        let response = next.run(request, extensions).await?;
+        self.0
+            .lock()
+            .unwrap()
+            .push((url, response.status().as_u16()));
+        Ok(response)
+    }
+}
+
+fn get_client(nodes: Vec<String>, max_retries: u32, recorder: &Recorder) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(
+            ExponentialBackoff::builder()
+                .retry_bounds(Duration::from_millis(1), Duration::from_millis(5))
+                .build_with_max_retries(max_retries),
+        )
+        .middleware(Sign("first"))
+        .middleware(recorder.clone())
+        .middleware(Sign("second"))
+        .build()
+        .expect("Failed to create client")
+}
+
+#[tokio::test]
+async fn test_middleware_runs_in_order_on_every_node() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    for server in [&server1, &server2] {
+        Mock::given(method("GET"))
+            .and(path("/health"))
+            .and(headers("x-signature", vec!["first", "second"]))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "ok": true })),
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    let recorder = Recorder::default();
+    let client = get_client(vec![server1.uri(), server2.uri()], 0, &recorder);
+    for _ in 0..2 {
+        assert!(client.operations().health().await.unwrap().ok);
+    }
+
+    let mut urls: Vec<_> = recorder.0.lock().unwrap().clone();
+    urls.sort();
+    let mut expected = vec![
+        (format!("{}/health", server1.uri()), 200),
+        (format!("{}/health", server2.uri()), 200),
+    ];
+    expected.sort();
+    assert_eq!(urls, expected);
+}
+
+#[tokio::test]
+async fn test_middleware_sees_every_retry_and_failover() {
+    let unhealthy_server = MockServer::start().await;
+    let healthy_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&unhealthy_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "ok": true })))
+        .mount(&healthy_server)
+        .await;
+
+    let recorder = Recorder::default();
+    let client = get_client(vec![unhealthy_server.uri()], 2, &recorder);
+    let result = client.operations().health().await;
+    assert!(matches!(result, Err(Error::AllNodesFailed { .. })));
+    // The first attempt and two retries.
+    assert_eq!(recorder.0.lock().unwrap().len(), 3);
+
+    let recorder = Recorder::default();
+    let client = get_client(
+        vec![unhealthy_server.uri(), healthy_server.uri()],
+        0,
+        &recorder,
+    );
+    assert!(client.operations().health().await.is_ok());
+    let statuses: Vec<_> = recorder.0.lock().unwrap().iter().map(|r| r.1).collect();
+    assert!(statuses == [200] || statuses == [503, 200], "{statuses:?}");
+}
+
+#[tokio::test]
+async fn test_middleware_runs_with_request_options() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .and(headers("x-signature", vec!["first", "second"]))
+        .and(header("x-request-id", "42"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let recorder = Recorder::default();
+    let client = get_client(vec![server.uri()], 2, &recorder);
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-request-id", HeaderValue::from_static("42"));
+    let options = RequestOptions::builder()
+        .headers(headers)
+        .disable_retry(true)
+        .build();
+
+    let result = client.operations().with_options(options).health().await;
+
+    assert!(matches!(result, Err(Error::AllNodesFailed { .. })));
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        [(format!("{}/health", server.uri()), 503)]
+    );
+}
+
+/// Rejects every request before it is sent.
+struct Reject;
+
+#[async_trait::async_trait]
+impl Middleware for Reject {
+    async fn handle(
+        &self,
+        _request: reqwest::Request,
+        _extensions: &mut Extensions,
+        _next: Next<'_>,
+    ) -> Result<reqwest::Response> {
+        Err(typesense::middleware::Error::Middleware(anyhow::anyhow!(
+            "request rejected"
+        )))
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_errors_do_not_fail_over() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+
+    let client = Client::builder()
+        .nodes(vec![server1.uri(), server2.uri()])
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .middleware(Reject)
+        .build()
+        .expect("Failed to create client");
+
+    let result = client.operations().health().await;
+
+    // The error is returned as is, without trying the other node.
+    let Err(Error::Api { source, .. }) = result else {
+        panic!("expected the middleware error, got {result:?}");
+    };
+    assert!(source.to_string().contains("request rejected"), "{source}");
+    assert!(client.node_stats().iter().all(|node| node.is_healthy));
+    assert!(server1.received_requests().await.unwrap().is_empty());
+    assert!(server2.received_requests().await.unwrap().is_empty());
+}
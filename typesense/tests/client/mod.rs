@@ -1,19 +1,41 @@
 mod aliases_test;
+mod analytics_status_test;
 mod analytics_test;
+mod blocking_test;
+mod bulk_import_test;
+mod circuit_breaker_test;
 mod client_test;
 mod collections_test;
+mod config_test;
 mod conversation_models_test;
 mod curation_sets_test;
 mod derive_integration_test;
 mod documents_test;
+mod error_test;
+mod export_stream_test;
+mod filter_test;
+mod health_checker_test;
+mod hedging_test;
 mod http_builder_test;
+mod import_test;
 mod keys_test;
+mod middleware_test;
 mod multi_search_test;
+mod nl_search_models_test;
+mod node_selector_test;
+mod node_stats_test;
 mod operations_test;
+mod owned_handles_test;
 mod presets_test;
+mod rate_limit_test;
+mod request_options_test;
+mod runtime_config_test;
+mod search_stream_test;
+mod sort_by_test;
 mod stemming_dictionaries_test;
 mod stopwords_test;
 mod synonym_sets_test;
+mod tracing_test;
 
 use std::time::Duration;
 use typesense::{Client, ExponentialBackoff};
@@ -0,0 +1,228 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::time::Duration;
+
+use typesense::{
+    Client, ExponentialBackoff,
+    models::{NlSearchModelCreateSchema, NlSearchModelUpdateSchema},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, method, path},
+};
+
+use super::new_id;
+
+// Helper to create a Typesense client configured for mock servers.
+fn get_test_client(uris: Vec<String>) -> Client {
+    Client::builder()
+        .nodes(uris)
+        .api_key("TEST_API_KEY")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
+fn model_response(model_id: &str, system_prompt: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": model_id,
+        "model_name": "google/gemini-2.5-flash",
+        "api_key": "AI**********************KEY", // Masked key
+        "max_bytes": 16000,
+        "system_prompt": system_prompt,
+    })
+}
+
+#[tokio::test]
+async fn test_create_nl_search_model_with_wiremock() {
+    // --- 1. Setup ---
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(vec![mock_server.uri()]);
+
+    let model_id = new_id("nl-model-test");
+    let create_schema = NlSearchModelCreateSchema {
+        id: Some(model_id.as_str().into()),
+        model_name: Some("google/gemini-2.5-flash".into()),
+        api_key: Some("A-FAKE-BUT-VALID-LOOKING-KEY".into()),
+        max_bytes: Some(16000),
+        system_prompt: Some("You are a search assistant.".into()),
+        ..Default::default()
+    };
+
+    // --- 2. Mocking ---
+    Mock::given(method("POST"))
+        .and(path("/nl_search_models"))
+        .and(body_json(&create_schema)) // Ensure the client sends the correct body
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(model_response(&model_id, "You are a search assistant.")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // --- 3. Action ---
+    let create_result = client.nl_search_models().create(create_schema).await;
+
+    // --- 4. Assertion ---
+    assert!(
+        create_result.is_ok(),
+        "The client should have successfully parsed the 200 response from the mock server. Error: {:?}",
+        create_result.err()
+    );
+    let created_model = create_result.unwrap();
+    assert_eq!(created_model.id, model_id);
+    assert_eq!(
+        created_model.model_name.as_deref(),
+        Some("google/gemini-2.5-flash")
+    );
+}
+
+#[tokio::test]
+async fn test_retrieve_all_nl_search_models_with_wiremock() {
+    // --- 1. Setup ---
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(vec![mock_server.uri()]);
+
+    // --- 2. Mocking ---
+    Mock::given(method("GET"))
+        .and(path("/nl_search_models"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            model_response("model-1", "First prompt."),
+            model_response("model-2", "Second prompt."),
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // --- 3. Action ---
+    let retrieve_result = client.nl_search_models().retrieve().await;
+
+    // --- 4. Assertion ---
+    assert!(retrieve_result.is_ok(), "Retrieving all models failed");
+    let models = retrieve_result.unwrap();
+    assert_eq!(models.len(), 2);
+    assert_eq!(models[0].id, "model-1");
+    assert_eq!(models[1].id, "model-2");
+}
+
+#[tokio::test]
+async fn test_retrieve_single_nl_search_model_with_wiremock() {
+    // --- 1. Setup ---
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(vec![mock_server.uri()]);
+    let model_id = new_id("nl-model");
+
+    // --- 2. Mocking ---
+    Mock::given(method("GET"))
+        .and(path(format!("/nl_search_models/{}", model_id)))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(model_response(&model_id, "A prompt.")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // --- 3. Action ---
+    let retrieve_result = client.nl_search_model(&model_id).retrieve().await;
+
+    // --- 4. Assertion ---
+    assert!(retrieve_result.is_ok());
+    assert_eq!(retrieve_result.unwrap().id, model_id);
+}
+
+#[tokio::test]
+async fn test_update_single_nl_search_model_with_wiremock() {
+    // --- 1. Setup ---
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(vec![mock_server.uri()]);
+    let model_id = new_id("nl-model");
+
+    let update_schema = NlSearchModelUpdateSchema {
+        system_prompt: Some("A new, updated prompt.".into()),
+        ..Default::default()
+    };
+
+    // --- 2. Mocking ---
+    Mock::given(method("PUT"))
+        .and(path(format!("/nl_search_models/{}", model_id)))
+        .and(body_json(&update_schema)) // Verify the client sends the correct update payload
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(model_response(&model_id, "A new, updated prompt.")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // --- 3. Action ---
+    let update_result = client
+        .nl_search_model(&model_id)
+        .update(update_schema)
+        .await;
+
+    // --- 4. Assertion ---
+    assert!(update_result.is_ok());
+    assert_eq!(
+        update_result.unwrap().system_prompt.as_deref(),
+        Some("A new, updated prompt.")
+    );
+}
+
+#[tokio::test]
+async fn test_delete_single_nl_search_model_with_wiremock() {
+    // --- 1. Setup ---
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(vec![mock_server.uri()]);
+    let model_id = new_id("nl-model-to-delete");
+
+    // --- 2. Mocking ---
+    Mock::given(method("DELETE"))
+        .and(path(format!("/nl_search_models/{}", model_id)))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": model_id })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // --- 3. Action ---
+    let delete_result = client.nl_search_model(&model_id).delete().await;
+
+    // --- 4. Assertion ---
+    assert!(delete_result.is_ok());
+    assert_eq!(delete_result.unwrap().id, model_id);
+}
+
+#[tokio::test]
+async fn test_nl_search_model_fails_over_to_healthy_node() {
+    // --- 1. Setup: the first node is down ---
+    let unhealthy_server = MockServer::start().await;
+    let healthy_server = MockServer::start().await;
+    let client = get_test_client(vec![unhealthy_server.uri(), healthy_server.uri()]);
+    let model_id = new_id("nl-model");
+
+    // --- 2. Mocking ---
+    Mock::given(method("GET"))
+        .and(path(format!("/nl_search_models/{}", model_id)))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&unhealthy_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nl_search_models/{}", model_id)))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(model_response(&model_id, "A prompt.")),
+        )
+        .expect(1)
+        .mount(&healthy_server)
+        .await;
+
+    // --- 3. Action ---
+    let retrieve_result = client.nl_search_model(&model_id).retrieve().await;
+
+    // --- 4. Assertion ---
+    assert!(retrieve_result.is_ok());
+    assert_eq!(retrieve_result.unwrap().id, model_id);
+}
@@ -0,0 +1,204 @@
+#![cfg(not(target_family = "wasm"))]
+
+use futures::future::join_all;
+use reqwest_retry::policies::ExponentialBackoff;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use typesense::{
+    Client,
+    node_selector::{Candidate, Ewma, LeastInFlight, NodeSelector, RandomAffinity},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn get_client(nodes: Vec<String>, selector: impl NodeSelector + 'static) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .node_selector(selector)
+        .build()
+        .expect("Failed to create client")
+}
+
+async fn mount_health(server: &MockServer, status: u16, delay: Duration) {
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(
+            ResponseTemplate::new(status)
+                .set_body_json(serde_json::json!({ "ok": status == 200 }))
+                .set_delay(delay),
+        )
+        .mount(server)
+        .await;
+}
+
+async fn requests_to(server: &MockServer) -> usize {
+    server.received_requests().await.unwrap().len()
+}
+
+/// Always selects the first candidate, and records the requests it is told about.
+#[derive(Debug, Default)]
+struct First {
+    events: Mutex<Vec<(usize, Option<bool>)>>,
+}
+
+impl NodeSelector for First {
+    fn select(&self, _candidates: &[Candidate<'_>]) -> usize {
+        0
+    }
+
+    fn request_started(&self, node: usize) {
+        self.events.lock().unwrap().push((node, None));
+    }
+
+    fn request_finished(&self, node: usize, _latency: Duration, succeeded: bool) {
+        self.events.lock().unwrap().push((node, Some(succeeded)));
+    }
+}
+
+#[tokio::test]
+async fn test_custom_selector_keeps_failover() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    mount_health(&server1, 503, Duration::ZERO).await;
+    mount_health(&server2, 200, Duration::ZERO).await;
+    let selector = Arc::new(First::default());
+    let client = get_client(vec![server1.uri(), server2.uri()], selector.clone());
+
+    for _ in 0..3 {
+        assert!(client.operations().health().await.is_ok());
+    }
+
+    // The first node is selected once; it is then unhealthy and skipped.
+    assert_eq!(requests_to(&server1).await, 1);
+    assert_eq!(requests_to(&server2).await, 3);
+    assert_eq!(
+        *selector.events.lock().unwrap(),
+        [
+            (0, None),
+            (0, Some(false)),
+            (1, None),
+            (1, Some(true)),
+            (1, None),
+            (1, Some(true)),
+            (1, None),
+            (1, Some(true)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_nearest_node_is_not_selected_by_selector() {
+    let nearest = MockServer::start().await;
+    let server = MockServer::start().await;
+    mount_health(&nearest, 200, Duration::ZERO).await;
+    mount_health(&server, 200, Duration::ZERO).await;
+    let selector = Arc::new(First::default());
+    let client = Client::builder()
+        .nodes(vec![server.uri()])
+        .nearest_node(nearest.uri())
+        .api_key("test-key")
+        .node_selector(selector.clone())
+        .build()
+        .unwrap();
+
+    assert!(client.operations().health().await.is_ok());
+
+    assert_eq!(requests_to(&nearest).await, 1);
+    assert!(selector.events.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_least_in_flight_spreads_concurrent_requests() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    for server in [&server1, &server2] {
+        mount_health(server, 200, Duration::from_millis(200)).await;
+    }
+    let selector = Arc::new(LeastInFlight::default());
+    let client = get_client(vec![server1.uri(), server2.uri()], selector.clone());
+
+    let results = join_all((0..6).map(|_| async { client.operations().health().await })).await;
+
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(requests_to(&server1).await, 3);
+    assert_eq!(requests_to(&server2).await, 3);
+    assert_eq!((selector.in_flight(0), selector.in_flight(1)), (0, 0));
+}
+
+#[tokio::test]
+async fn test_least_in_flight_counts_dropped_requests_as_finished() {
+    let server = MockServer::start().await;
+    mount_health(&server, 200, Duration::from_secs(5)).await;
+    let selector = Arc::new(LeastInFlight::default());
+    let client = get_client(vec![server.uri()], selector.clone());
+
+    let result =
+        tokio::time::timeout(Duration::from_millis(100), client.operations().health()).await;
+
+    assert!(result.is_err());
+    assert_eq!(selector.in_flight(0), 0);
+}
+
+#[tokio::test]
+async fn test_ewma_prefers_the_fastest_node() {
+    let slow_server = MockServer::start().await;
+    let fast_server = MockServer::start().await;
+    mount_health(&slow_server, 200, Duration::from_millis(200)).await;
+    mount_health(&fast_server, 200, Duration::ZERO).await;
+    let selector = Arc::new(Ewma::default());
+    let client = get_client(vec![slow_server.uri(), fast_server.uri()], selector.clone());
+
+    for _ in 0..20 {
+        assert!(client.operations().health().await.is_ok());
+    }
+
+    // Each node is measured once, then the fast node receives almost all requests.
+    assert!(requests_to(&slow_server).await <= 3);
+    assert!(requests_to(&fast_server).await >= 17);
+    assert!(selector.latency(0).unwrap() >= Duration::from_millis(200));
+    assert!(selector.latency(1).unwrap() < selector.latency(0).unwrap());
+}
+
+#[tokio::test]
+async fn test_random_affinity_sticks_to_a_node_until_it_fails() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    let server3 = MockServer::start().await;
+    let servers = [&server1, &server2, &server3];
+    for server in servers {
+        mount_health(server, 200, Duration::ZERO).await;
+    }
+    let client = get_client(
+        servers.iter().map(|server| server.uri()).collect(),
+        RandomAffinity::default(),
+    );
+
+    for _ in 0..5 {
+        assert!(client.operations().health().await.is_ok());
+    }
+    let mut counts = Vec::new();
+    for server in servers {
+        counts.push(requests_to(server).await);
+    }
+    let selected = counts.iter().position(|&count| count == 5).unwrap();
+    assert_eq!(counts.iter().sum::<usize>(), 5);
+
+    servers[selected].reset().await;
+    mount_health(servers[selected], 503, Duration::ZERO).await;
+    for _ in 0..5 {
+        assert!(client.operations().health().await.is_ok());
+    }
+    let mut counts = Vec::new();
+    for server in servers {
+        counts.push(requests_to(server).await);
+    }
+    assert_eq!(counts[selected], 1);
+    assert!(counts.contains(&5), "{counts:?}");
+}
@@ -0,0 +1,109 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest_retry::policies::ExponentialBackoff;
+use std::time::Duration;
+use typesense::Client;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn get_client(nodes: Vec<String>) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
+#[tokio::test]
+async fn test_node_stats_before_any_request() {
+    let client = get_client(vec![
+        "http://node-1:8108/".into(),
+        "http://node-2:8108".into(),
+    ]);
+
+    let stats = client.node_stats();
+
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].url, "http://node-1:8108");
+    assert_eq!(stats[1].url, "http://node-2:8108");
+    for node in stats {
+        assert!(node.is_healthy);
+        assert_eq!(node.retry_in, None);
+        assert_eq!((node.successes, node.failures), (0, 0));
+        assert_eq!(node.last_error, None);
+        assert_eq!(node.latency, None);
+    }
+}
+
+#[tokio::test]
+async fn test_node_stats_record_failover() {
+    let unhealthy_server = MockServer::start().await;
+    let healthy_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&unhealthy_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "ok": true }))
+                .set_delay(Duration::from_millis(50)),
+        )
+        .mount(&healthy_server)
+        .await;
+    let client = get_client(vec![unhealthy_server.uri(), healthy_server.uri()]);
+
+    for _ in 0..3 {
+        assert!(client.operations().health().await.is_ok());
+    }
+    let stats = client.node_stats();
+
+    let unhealthy = &stats[0];
+    assert!(!unhealthy.is_healthy);
+    let retry_in = unhealthy.retry_in.unwrap();
+    assert!(retry_in > Duration::from_secs(50) && retry_in <= Duration::from_secs(60));
+    assert_eq!((unhealthy.successes, unhealthy.failures), (0, 1));
+    assert!(unhealthy.last_error.as_deref().unwrap().contains("503"));
+    assert_eq!(unhealthy.latency.unwrap().samples, 1);
+
+    let healthy = &stats[1];
+    assert!(healthy.is_healthy);
+    assert_eq!(healthy.retry_in, None);
+    assert_eq!((healthy.successes, healthy.failures), (3, 0));
+    assert_eq!(healthy.last_error, None);
+    let latency = healthy.latency.unwrap();
+    assert_eq!(latency.samples, 3);
+    assert!(latency.p50 >= Duration::from_millis(50));
+    assert!(latency.p50 <= latency.p90 && latency.p90 <= latency.p99 && latency.p99 <= latency.max);
+}
+
+#[tokio::test]
+async fn test_node_stats_count_client_errors_as_successes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/collections/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "message": "Not Found"
+        })))
+        .mount(&server)
+        .await;
+    let client = get_client(vec![server.uri()]);
+
+    assert!(
+        client
+            .collection_schemaless("missing")
+            .retrieve()
+            .await
+            .is_err()
+    );
+    let stats = client.node_stats();
+
+    assert!(stats[0].is_healthy);
+    assert_eq!((stats[0].successes, stats[0].failures), (1, 0));
+}
@@ -0,0 +1,171 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest_retry::policies::ExponentialBackoff;
+use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Duration};
+use typesense::{Client, Collection, RequestOptions, models::ImportDocumentsParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Book {
+    id: String,
+    title: String,
+}
+
+fn get_client(server: &MockServer) -> Arc<Client> {
+    Arc::new(
+        Client::builder()
+            .nodes(vec![server.uri()])
+            .api_key("test-key")
+            .healthcheck_interval(Duration::from_secs(60))
+            .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+            .build()
+            .expect("Failed to create client"),
+    )
+}
+
+async fn mount_book(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "id": "1", "title": "Dune" })),
+        )
+        .mount(server)
+        .await;
+}
+
+fn assert_static<T: Clone + Send + Sync + 'static>(_: &T) {}
+
+#[tokio::test]
+async fn test_owned_collection_moves_into_spawned_task() {
+    let server = MockServer::start().await;
+    mount_book(&server).await;
+    let books: Collection<'static, Book> = get_client(&server).collection_named_owned("books");
+    assert_static(&books);
+
+    let tasks: Vec<_> = (0..2)
+        .map(|_| {
+            let books = books.clone();
+            tokio::spawn(async move { books.document("1").retrieve().await })
+        })
+        .collect();
+
+    for task in tasks {
+        let book = task.await.unwrap().unwrap();
+        assert_eq!(book.title, "Dune");
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_owned_collection_keeps_request_options() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/1"))
+        .and(header("x-typesense-api-key", "scoped-key"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "id": "1", "title": "Dune" })),
+        )
+        .mount(&server)
+        .await;
+    let client = get_client(&server);
+    let books = client
+        .collection_schemaless_owned("books")
+        .with_options(RequestOptions::builder().api_key("scoped-key").build());
+    drop(client);
+
+    let book = tokio::spawn(async move { books.document("1").retrieve().await })
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(book["title"], "Dune");
+}
+
+fn assert_static_send<T: Send + 'static>(_: &T) {}
+
+#[tokio::test]
+async fn test_owned_namespaces_are_static_and_send() {
+    let server = MockServer::start().await;
+    let client = get_client(&server);
+    let books = client.collection_named_owned::<Book>("books");
+
+    assert_static_send(&books.documents());
+    assert_static_send(&books.document("1"));
+    assert_static_send(&books.document(String::from("1")));
+    assert_static_send(&client.aliases_owned());
+    assert_static_send(&client.alias_owned("books_alias"));
+    assert_static_send(&client.analytics_owned());
+    assert_static_send(&client.collections_owned());
+    assert_static_send(&client.conversations_owned());
+    assert_static_send(&client.curation_sets_owned());
+    assert_static_send(&client.curation_set_owned("curations"));
+    assert_static_send(&client.keys_owned());
+    assert_static_send(&client.key_owned(1));
+    assert_static_send(&client.multi_search_owned());
+    assert_static_send(&client.nl_search_models_owned());
+    assert_static_send(&client.nl_search_model_owned("model"));
+    assert_static_send(&client.operations_owned());
+    assert_static_send(&client.presets_owned());
+    assert_static_send(&client.preset_owned("preset"));
+    assert_static_send(&client.stemming_owned());
+    assert_static_send(&client.stopwords_owned());
+    assert_static_send(&client.stopword_owned("stopwords"));
+    assert_static_send(&client.synonym_sets_owned());
+    assert_static_send(&client.synonym_set_owned("synonyms"));
+
+    let item_id = String::from("item");
+    assert_static_send(&client.analytics_owned().rule(item_id.clone()));
+    assert_static_send(&client.conversations_owned().model(item_id.clone()));
+    assert_static_send(&client.stemming_owned().dictionary(item_id.clone()));
+    assert_static_send(&client.synonym_set_owned("synonyms").item(item_id.clone()));
+    assert_static_send(&client.curation_set_owned("curations").item(item_id));
+}
+
+#[tokio::test]
+async fn test_owned_documents_move_into_spawned_task() {
+    let server = MockServer::start().await;
+    mount_book(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"success":true}"#))
+        .mount(&server)
+        .await;
+    let books = get_client(&server).collection_named_owned::<Book>("books");
+    let (documents, document) = (books.documents(), books.document("1"));
+    drop(books);
+
+    let imported = tokio::spawn(async move {
+        let emma = r#"{"id":"2","title":"Emma"}"#;
+        documents
+            .import_jsonl(emma, ImportDocumentsParameters::default())
+            .await
+    });
+    let retrieved = tokio::spawn(async move { document.retrieve().await });
+
+    assert_eq!(imported.await.unwrap().unwrap(), r#"{"success":true}"#);
+    assert_eq!(retrieved.await.unwrap().unwrap().title, "Dune");
+}
+
+#[tokio::test]
+async fn test_owned_keys_move_into_spawned_task() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/keys"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "keys": [] })))
+        .mount(&server)
+        .await;
+    let keys = get_client(&server).keys_owned();
+
+    let all_keys = tokio::spawn(async move { keys.retrieve().await })
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(all_keys.keys.is_empty());
+}
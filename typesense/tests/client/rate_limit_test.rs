@@ -0,0 +1,177 @@
+#![cfg(not(target_family = "wasm"))]
+
+use futures::future::join_all;
+use reqwest_retry::policies::ExponentialBackoff;
+use std::time::{Duration, Instant};
+use typesense::{Client, RateLimiter};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn get_client(nodes: Vec<String>, max_retries: u32, rate_limiter: Option<RateLimiter>) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(
+            ExponentialBackoff::builder()
+                .retry_bounds(Duration::from_millis(10), Duration::from_millis(10))
+                .build_with_max_retries(max_retries),
+        )
+        .maybe_rate_limiter(rate_limiter)
+        .build()
+        .expect("Failed to create client")
+}
+
+fn health_response(delay: Duration) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .set_body_json(serde_json::json!({ "ok": true }))
+        .set_delay(delay)
+}
+
+async fn requests_to(server: &MockServer) -> usize {
+    server.received_requests().await.unwrap().len()
+}
+
+#[tokio::test]
+async fn test_rate_limited_request_is_retried_after_retry_after() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(health_response(Duration::ZERO))
+        .mount(&server)
+        .await;
+    let client = get_client(vec![server.uri()], 2, None);
+
+    let started = Instant::now();
+    let health = client.operations().health().await.unwrap();
+
+    assert!(health.ok);
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    assert_eq!(requests_to(&server).await, 2);
+}
+
+#[tokio::test]
+async fn test_rate_limited_and_failed_requests_share_the_retry_budget() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+    let client = get_client(vec![server.uri()], 2, None);
+
+    assert!(client.operations().health().await.is_err());
+    // The first attempt and two retries, whatever made them fail.
+    assert_eq!(requests_to(&server).await, 3);
+}
+
+#[tokio::test]
+async fn test_rate_limited_request_fails_over_without_marking_node_unhealthy() {
+    let limited_node = MockServer::start().await;
+    let other_node = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(429))
+        .mount(&limited_node)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(health_response(Duration::ZERO))
+        .mount(&other_node)
+        .await;
+    let client = get_client(vec![limited_node.uri(), other_node.uri()], 0, None);
+
+    let health = client.operations().health().await.unwrap();
+
+    assert!(health.ok);
+    assert_eq!(requests_to(&limited_node).await, 1);
+    let stats = client.node_stats();
+    assert!(stats[0].is_healthy);
+    assert_eq!(stats[0].failures, 0);
+}
+
+#[tokio::test]
+async fn test_max_concurrency_limits_calls_in_progress() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(health_response(Duration::from_millis(200)))
+        .mount(&server)
+        .await;
+    let rate_limiter = RateLimiter::builder().max_concurrency(1).build();
+    let client = get_client(vec![server.uri()], 0, Some(rate_limiter));
+
+    let started = Instant::now();
+    let calls = (0..3).map(|_| async { client.operations().health().await });
+    for result in join_all(calls).await {
+        assert!(result.unwrap().ok);
+    }
+
+    assert!(started.elapsed() >= Duration::from_millis(600));
+}
+
+#[tokio::test]
+async fn test_cancelled_call_leaves_the_concurrency_queue() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(health_response(Duration::from_millis(200)))
+        .mount(&server)
+        .await;
+    let rate_limiter = RateLimiter::builder().max_concurrency(1).build();
+    let client = get_client(vec![server.uri()], 0, Some(rate_limiter));
+
+    let first = async { client.operations().health().await };
+    let cancelled = async {
+        let call = async { client.operations().health().await };
+        tokio::time::timeout(Duration::from_millis(50), call).await
+    };
+    let last = async {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        client.operations().health().await
+    };
+    let (first, cancelled, last) = tokio::join!(first, cancelled, last);
+
+    assert!(first.unwrap().ok);
+    assert!(cancelled.is_err());
+    // The slot freed by the first call goes to the last one, not to the cancelled call.
+    assert!(last.unwrap().ok);
+    assert_eq!(requests_to(&server).await, 2);
+}
+
+#[tokio::test]
+async fn test_requests_per_second_spaces_calls() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(health_response(Duration::ZERO))
+        .mount(&server)
+        .await;
+    let rate_limiter = RateLimiter::builder()
+        .requests_per_second(10.0)
+        .burst(1)
+        .build();
+    let client = get_client(vec![server.uri()], 0, Some(rate_limiter));
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        client.operations().health().await.unwrap();
+    }
+
+    assert!(started.elapsed() >= Duration::from_millis(190));
+    assert_eq!(requests_to(&server).await, 3);
+}
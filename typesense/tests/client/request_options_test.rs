@@ -0,0 +1,211 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest_retry::policies::ExponentialBackoff;
+use serde_json::json;
+use std::time::Duration;
+use typesense::{Client, Error, RequestOptions, models::SearchParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+fn get_client(nodes: Vec<String>, max_retries: u32) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(
+            ExponentialBackoff::builder()
+                .retry_bounds(Duration::from_millis(1), Duration::from_millis(5))
+                .build_with_max_retries(max_retries),
+        )
+        .build()
+        .expect("Failed to create client")
+}
+
+fn collection_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "name": "products",
+        "fields": [],
+        "num_documents": 0,
+        "created_at": 0,
+    }))
+}
+
+#[tokio::test]
+async fn test_options_add_headers_and_override_api_key() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/collections/products/documents/search"))
+        .and(header("X-TYPESENSE-API-KEY", "scoped-key"))
+        .and(header("x-request-id", "42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "found": 0,
+            "hits": [],
+            "out_of": 0,
+            "page": 1,
+            "request_params": { "collection_name": "products", "per_page": 10, "q": "phone" },
+            "search_time_ms": 0,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/collections/products"))
+        .and(header("X-TYPESENSE-API-KEY", "test-key"))
+        .respond_with(collection_response())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = get_client(vec![server.uri()], 0);
+    let mut headers = HeaderMap::new();
+    headers.insert("x-request-id", HeaderValue::from_static("42"));
+    let options = RequestOptions::builder()
+        .headers(headers)
+        .api_key("scoped-key")
+        .build();
+
+    // The options are inherited by the documents of the collection.
+    let result = client
+        .collection_schemaless("products")
+        .with_options(options)
+        .documents()
+        .search(SearchParameters {
+            q: Some("phone".into()),
+            ..Default::default()
+        })
+        .await;
+    assert!(result.is_ok(), "{:?}", result.err());
+
+    // Requests without options are unchanged.
+    let result = client.collection_schemaless("products").retrieve().await;
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_timeout_fails_over_to_next_node() {
+    let slow_server = MockServer::start().await;
+    let fast_server = MockServer::start().await;
+    for (server, delay) in [(&slow_server, 1000), (&fast_server, 0)] {
+        Mock::given(method("GET"))
+            .and(path("/collections/products"))
+            .respond_with(collection_response().set_delay(Duration::from_millis(delay)))
+            .mount(server)
+            .await;
+    }
+
+    let client = get_client(vec![slow_server.uri(), fast_server.uri()], 0);
+    let collection = client.collection_schemaless("products").with_options(
+        RequestOptions::builder()
+            .timeout(Duration::from_millis(200))
+            .build(),
+    );
+
+    // Whichever node is tried first, the request completes on the fast node.
+    for _ in 0..2 {
+        assert!(collection.retrieve().await.is_ok());
+    }
+}
+
+#[tokio::test]
+async fn test_pinned_node_receives_every_request() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/collections/products"))
+        .respond_with(collection_response())
+        .expect(0)
+        .mount(&server1)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/collections/products"))
+        .respond_with(collection_response())
+        .expect(3)
+        .mount(&server2)
+        .await;
+
+    let client = get_client(vec![server1.uri(), server2.uri()], 0);
+    let collection = client.collection_schemaless("products").with_options(
+        RequestOptions::builder()
+            .node(format!("{}/", server2.uri()))
+            .build(),
+    );
+
+    for _ in 0..3 {
+        assert!(collection.retrieve().await.is_ok());
+    }
+}
+
+#[tokio::test]
+async fn test_pinned_node_does_not_fail_over() {
+    let unhealthy_server = MockServer::start().await;
+    let healthy_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/collections/products"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&unhealthy_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/collections/products"))
+        .respond_with(collection_response())
+        .expect(0)
+        .mount(&healthy_server)
+        .await;
+
+    let client = get_client(vec![unhealthy_server.uri(), healthy_server.uri()], 0);
+    let options = RequestOptions::builder()
+        .node(unhealthy_server.uri())
+        .build();
+    let result = client
+        .collection_schemaless("products")
+        .with_options(options)
+        .retrieve()
+        .await;
+
+    assert!(matches!(result, Err(Error::AllNodesFailed { .. })));
+}
+
+#[tokio::test]
+async fn test_unknown_pinned_node_is_an_error() {
+    let server = MockServer::start().await;
+    let client = get_client(vec![server.uri()], 0);
+    let options = RequestOptions::builder()
+        .node("http://unknown:8108")
+        .build();
+
+    let result = client.operations().with_options(options).health().await;
+
+    assert!(matches!(result, Err(Error::Api { .. })));
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_disable_retry_sends_a_single_request() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let client = get_client(vec![server.uri()], 2);
+
+    // Without options, the request is retried on the node.
+    let result = client.operations().health().await;
+    assert!(matches!(result, Err(Error::AllNodesFailed { .. })));
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+    server.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let options = RequestOptions::builder().disable_retry(true).build();
+    let result = client.operations().with_options(options).health().await;
+    assert!(matches!(result, Err(Error::AllNodesFailed { .. })));
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
@@ -0,0 +1,109 @@
+#![cfg(not(target_family = "wasm"))]
+
+use reqwest_retry::policies::ExponentialBackoff;
+use serde_json::json;
+use std::time::Duration;
+use typesense::{
+    ApiError, Client, Error, legacy::apis::operations_api::UpdateConfigError, models::RuntimeConfig,
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, header, method, path},
+};
+
+fn get_client(nodes: Vec<String>) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
+#[tokio::test]
+async fn test_update_config_sends_only_set_settings() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/config"))
+        .and(header("X-TYPESENSE-API-KEY", "test-key"))
+        .and(body_json(json!({
+            "log-slow-requests-time-ms": 2000,
+            "cache-num-entries": 5000,
+            "enable-search-logging": true,
+            "new-setting": "value",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = get_client(vec![server.uri()]);
+    let mut config = RuntimeConfig::builder()
+        .log_slow_requests_time_ms(2000)
+        .cache_num_entries(5000)
+        .enable_search_logging(true)
+        .build();
+    config
+        .extra
+        .insert("new-setting".to_owned(), json!("value"));
+
+    let response = client.operations().update_config(&config).await.unwrap();
+
+    assert!(response.success);
+}
+
+#[tokio::test]
+async fn test_update_config_fails_over_to_healthy_node() {
+    let unhealthy_server = MockServer::start().await;
+    let healthy_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/config"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&unhealthy_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/config"))
+        .and(body_json(json!({ "healthy-read-lag": 1000 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(1)
+        .mount(&healthy_server)
+        .await;
+
+    let client = get_client(vec![unhealthy_server.uri(), healthy_server.uri()]);
+    let config = RuntimeConfig::builder().healthy_read_lag(1000).build();
+
+    assert!(client.operations().update_config(&config).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_update_config_returns_api_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/config"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(json!({ "message": "Invalid configuration." })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = get_client(vec![server.uri()]);
+    let result = client
+        .operations()
+        .update_config(&RuntimeConfig::default())
+        .await;
+
+    let Err(Error::Api {
+        source: ApiError::ResponseError(content),
+        ..
+    }) = result
+    else {
+        panic!("expected a response error, got {result:?}");
+    };
+    assert!(matches!(
+        content.entity,
+        Some(UpdateConfigError::UnknownValue(value)) if value["message"] == "Invalid configuration."
+    ));
+}
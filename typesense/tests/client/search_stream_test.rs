@@ -0,0 +1,155 @@
+#![cfg(not(target_family = "wasm"))]
+
+use futures::TryStreamExt;
+use reqwest_retry::policies::ExponentialBackoff;
+use serde_json::{Value, json};
+use std::time::Duration;
+use typesense::{Client, models::SearchParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+const SEARCH_PATH: &str = "/collections/products/documents/search";
+
+fn search_page(page: usize, per_page: usize, found: usize) -> Value {
+    let first = (page - 1) * per_page;
+    let hits: Vec<Value> = (first..found.min(first + per_page))
+        .map(|i| json!({ "document": { "id": i.to_string() } }))
+        .collect();
+    json!({ "found": found, "out_of": found, "page": page, "hits": hits })
+}
+
+async fn mount_page(server: &MockServer, page: usize, per_page: usize, found: usize) {
+    Mock::given(method("GET"))
+        .and(path(SEARCH_PATH))
+        .and(query_param("page", page.to_string()))
+        .and(query_param("per_page", per_page.to_string()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(search_page(page, per_page, found)))
+        .mount(server)
+        .await;
+}
+
+fn get_client(nodes: Vec<String>) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("test-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
+fn hit_ids(hits: &[typesense::models::SearchResultHit<Value>]) -> Vec<String> {
+    hits.iter()
+        .map(|hit| {
+            hit.document.as_ref().unwrap()["id"]
+                .as_str()
+                .unwrap()
+                .to_owned()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_search_stream_walks_all_pages() {
+    let server = MockServer::start().await;
+    for page in 1..=3 {
+        mount_page(&server, page, 2, 5).await;
+    }
+
+    let client = get_client(vec![server.uri()]);
+    let products = client.collection_schemaless("products");
+    let documents = products.documents();
+    let params = SearchParameters::builder().q("*").per_page(2).build();
+
+    let hits: Vec<_> = documents.search_stream(params).try_collect().await.unwrap();
+
+    assert_eq!(hit_ids(&hits), ["0", "1", "2", "3", "4"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_search_stream_caps_per_page_and_stops_at_found() {
+    let server = MockServer::start().await;
+    mount_page(&server, 1, 250, 250).await;
+
+    let client = get_client(vec![server.uri()]);
+    let products = client.collection_schemaless("products");
+    let documents = products.documents();
+    let params = SearchParameters::builder().q("*").per_page(1000).build();
+
+    let pages: Vec<_> = documents.search_pages(params).try_collect().await.unwrap();
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].hits.as_ref().unwrap().len(), 250);
+    // The last page was full, but `found` was reached so no further page is requested.
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_search_stream_fails_over_mid_iteration() {
+    let server1 = MockServer::start().await;
+    let server2 = MockServer::start().await;
+    // The first node only serves the first page and then goes down.
+    mount_page(&server1, 1, 2, 5).await;
+    Mock::given(method("GET"))
+        .and(path(SEARCH_PATH))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server1)
+        .await;
+    for page in 2..=3 {
+        mount_page(&server2, page, 2, 5).await;
+    }
+
+    let client = get_client(vec![server1.uri(), server2.uri()]);
+    let products = client.collection_schemaless("products");
+    let documents = products.documents();
+    let params = SearchParameters::builder().q("*").per_page(2).build();
+
+    let hits: Vec<_> = documents.search_stream(params).try_collect().await.unwrap();
+
+    assert_eq!(hit_ids(&hits), ["0", "1", "2", "3", "4"]);
+}
+
+#[tokio::test]
+async fn test_search_grouped_stream_walks_all_groups() {
+    let server = MockServer::start().await;
+    for (page, groups) in [(1, vec!["a", "b"]), (2, vec!["c"])] {
+        let grouped_hits: Vec<Value> = groups
+            .iter()
+            .map(|key| json!({ "group_key": [key], "hits": [{ "document": { "id": key } }] }))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path(SEARCH_PATH))
+            .and(query_param("page", page.to_string()))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(
+                    json!({ "found": 3, "page": page, "grouped_hits": grouped_hits }),
+                ),
+            )
+            .mount(&server)
+            .await;
+    }
+
+    let client = get_client(vec![server.uri()]);
+    let products = client.collection_schemaless("products");
+    let documents = products.documents();
+    let params = SearchParameters::builder()
+        .q("*")
+        .group_by("brand")
+        .per_page(2)
+        .build();
+
+    let groups: Vec<_> = documents
+        .search_grouped_stream(params)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let keys: Vec<_> = groups
+        .iter()
+        .map(|group| group.group_key[0].clone())
+        .collect();
+    assert_eq!(keys, [json!("a"), json!("b"), json!("c")]);
+}
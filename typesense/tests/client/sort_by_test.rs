@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+use typesense::{
+    FilterError, SortByError,
+    filter::{Filter, GeoUnit},
+    models::{GeoSort, SearchParameters, SortBy, SortOrder},
+};
+
+#[test]
+fn test_sort_by_renders_clauses() -> Result<(), FilterError> {
+    assert_eq!(
+        SortBy::new().desc("price").asc("name").to_string(),
+        "price:desc,name:asc"
+    );
+    assert_eq!(
+        SortBy::new().text_match(SortOrder::Desc).to_string(),
+        "_text_match:desc"
+    );
+    assert_eq!(
+        SortBy::new()
+            .text_match_buckets(10, SortOrder::Desc)
+            .to_string(),
+        "_text_match(buckets: 10):desc"
+    );
+    assert_eq!(
+        SortBy::new()
+            .eval(Filter::field("brand").eq("Nike")?, SortOrder::Desc)
+            .to_string(),
+        "_eval(brand:=Nike):desc"
+    );
+    assert_eq!(
+        SortBy::new()
+            .eval_weighted([("brand:=Nike", 3), ("brand:=Adidas", 2)], SortOrder::Desc)
+            .to_string(),
+        "_eval([(brand:=Nike):3, (brand:=Adidas):2]):desc"
+    );
+    assert_eq!(
+        SortBy::new().vector_distance(SortOrder::Asc).to_string(),
+        "_vector_distance:asc"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sort_by_renders_geo_clauses() {
+    assert_eq!(
+        SortBy::new()
+            .geo(GeoSort::new("location", 48.8, 2.3), SortOrder::Asc)
+            .to_string(),
+        "location(48.8, 2.3):asc"
+    );
+    assert_eq!(
+        SortBy::new()
+            .geo(
+                GeoSort::new("location", 48.8, 2.3).exclude_radius(2.0, GeoUnit::Miles),
+                SortOrder::Asc
+            )
+            .to_string(),
+        "location(48.8, 2.3, exclude_radius: 2mi):asc"
+    );
+    assert_eq!(
+        SortBy::new()
+            .geo(
+                GeoSort::new("location", 48.8, 2.3).precision(1.5, GeoUnit::Kilometers),
+                SortOrder::Desc
+            )
+            .to_string(),
+        "location(48.8, 2.3, precision: 1.5km):desc"
+    );
+}
+
+#[test]
+fn test_sort_by_validates_clause_count() {
+    let empty = SortBy::new();
+    assert_eq!(String::try_from(empty), Err(SortByError::Empty));
+
+    let too_many = SortBy::new().desc("a").desc("b").desc("c").desc("d");
+    assert_eq!(too_many.len(), 4);
+    assert_eq!(
+        Cow::<str>::try_from(too_many),
+        Err(SortByError::TooManyClauses(4))
+    );
+
+    let sort_by = SortBy::new().text_match(SortOrder::Desc).desc("price");
+    let params = SearchParameters::builder()
+        .sort_by(sort_by.build().unwrap())
+        .build();
+    assert_eq!(
+        params.sort_by.as_deref(),
+        Some("_text_match:desc,price:desc")
+    );
+
+    // the builder is borrowed, so it can be reused for other searches
+    let sort_by = sort_by.asc("name");
+    assert_eq!(
+        sort_by.build().unwrap(),
+        "_text_match:desc,price:desc,name:asc"
+    );
+}
@@ -0,0 +1,310 @@
+#![cfg(all(feature = "tracing", not(target_family = "wasm")))]
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest_retry::policies::ExponentialBackoff;
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+use tracing::{
+    Event, Metadata, Subscriber,
+    field::{Field, Visit},
+    span,
+};
+use typesense::{
+    Client, RequestOptions,
+    middleware::{Error, Extensions, Middleware, Next, Result},
+    models::{ScopedKeyParameters, SearchParameters},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+#[derive(Debug, Default)]
+struct RecordedSpan {
+    metadata: Option<&'static Metadata<'static>>,
+    parent: Option<u64>,
+    fields: HashMap<String, String>,
+}
+
+/// A subscriber that records all spans and events.
+#[derive(Clone, Default)]
+struct Recorder {
+    spans: Arc<Mutex<HashMap<u64, RecordedSpan>>>,
+    events: Arc<Mutex<Vec<HashMap<String, String>>>>,
+    stack: Arc<Mutex<Vec<u64>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+struct Fields<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for Fields<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(field.name().to_owned(), format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_owned(), value.to_owned());
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &span::Attributes<'_>) -> span::Id {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut span = RecordedSpan {
+            metadata: Some(attributes.metadata()),
+            parent: attributes
+                .parent()
+                .map(span::Id::into_u64)
+                .or_else(|| self.stack.lock().unwrap().last().copied()),
+            ..Default::default()
+        };
+        attributes.record(&mut Fields(&mut span.fields));
+        self.spans.lock().unwrap().insert(id, span);
+        span::Id::from_u64(id)
+    }
+
+    fn record(&self, id: &span::Id, values: &span::Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut Fields(
+            &mut spans.get_mut(&id.into_u64()).unwrap().fields,
+        ));
+    }
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = HashMap::new();
+        event.record(&mut Fields(&mut fields));
+        self.events.lock().unwrap().push(fields);
+    }
+
+    fn enter(&self, id: &span::Id) {
+        self.stack.lock().unwrap().push(id.into_u64());
+    }
+
+    fn exit(&self, _: &span::Id) {
+        self.stack.lock().unwrap().pop();
+    }
+
+    fn current_span(&self) -> tracing_core::span::Current {
+        match self.stack.lock().unwrap().last() {
+            Some(id) => tracing_core::span::Current::new(
+                span::Id::from_u64(*id),
+                self.spans.lock().unwrap()[id].metadata.unwrap(),
+            ),
+            None => tracing_core::span::Current::none(),
+        }
+    }
+}
+
+impl Recorder {
+    fn spans_named(&self, name: &str) -> Vec<(u64, HashMap<String, String>, Option<u64>)> {
+        let mut spans: Vec<_> = self
+            .spans
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, span)| {
+                span.metadata
+                    .is_some_and(|metadata| metadata.name() == name)
+            })
+            .map(|(id, span)| (*id, span.fields.clone(), span.parent))
+            .collect();
+        spans.sort_by_key(|span| span.0);
+        spans
+    }
+}
+
+fn get_client(nodes: Vec<String>) -> Client {
+    Client::builder()
+        .nodes(nodes)
+        .api_key("secret-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(
+            ExponentialBackoff::builder()
+                .retry_bounds(Duration::from_millis(1), Duration::from_millis(5))
+                .build_with_max_retries(1),
+        )
+        .build()
+        .expect("Failed to create client")
+}
+
+#[tokio::test]
+async fn test_spans_record_attempts_and_failover() {
+    let unhealthy_server = MockServer::start().await;
+    let healthy_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/collections/books"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("Service Unavailable"))
+        .expect(2)
+        .mount(&unhealthy_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/collections/books"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "books",
+            "fields": [],
+            "num_documents": 0,
+            "created_at": 0,
+        })))
+        .expect(1)
+        .mount(&healthy_server)
+        .await;
+
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+    let client = get_client(vec![unhealthy_server.uri(), healthy_server.uri()]);
+    let result = client.collection_schemaless("books").retrieve().await;
+    assert!(result.is_ok(), "{result:?}");
+
+    let requests = recorder.spans_named("typesense.request");
+    assert_eq!(requests.len(), 1);
+    let (request_id, request, _) = &requests[0];
+    assert_eq!(request["namespace"], "collection");
+    assert_eq!(request["operation"], "get_collection");
+    assert_eq!(request["collection"], "books");
+    assert_eq!(request["attempts"], "2");
+    assert!(request.contains_key("latency_ms"));
+
+    let attempts = recorder.spans_named("typesense.attempt");
+    assert_eq!(attempts.len(), 2);
+    let (_, first, parent) = &attempts[0];
+    assert_eq!(*parent, Some(*request_id));
+    assert_eq!(first["node"], unhealthy_server.uri());
+    assert_eq!(first["attempt"], "1");
+    assert_eq!(first["status_code"], "503");
+    assert_eq!(first["retries"], "1");
+    assert!(first["error"].contains("503"), "{}", first["error"]);
+    let (_, second, parent) = &attempts[1];
+    assert_eq!(*parent, Some(*request_id));
+    assert_eq!(second["node"], healthy_server.uri());
+    assert_eq!(second["attempt"], "2");
+    assert_eq!(second["status_code"], "200");
+    assert_eq!(second["retries"], "0");
+    assert!(!second.contains_key("error"));
+
+    let events = recorder.events.lock().unwrap();
+    let failovers: Vec<_> = events
+        .iter()
+        .filter(|event| event["message"] == "Request failed, trying the next node")
+        .collect();
+    assert_eq!(failovers.len(), 1);
+    assert_eq!(failovers[0]["node"], unhealthy_server.uri());
+
+    let recorded = format!("{:?}{:?}", recorder.spans.lock().unwrap(), *events);
+    assert!(!recorded.contains("secret-key"), "{recorded}");
+}
+
+#[tokio::test]
+async fn test_spans_without_collection() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/health"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "ok": true })))
+        .mount(&server)
+        .await;
+
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+    let client = get_client(vec![server.uri()]);
+    assert!(client.operations().health().await.unwrap().ok);
+
+    let requests = recorder.spans_named("typesense.request");
+    assert_eq!(requests.len(), 1);
+    let (_, request, _) = &requests[0];
+    assert_eq!(request["namespace"], "operations");
+    assert_eq!(request["operation"], "health");
+    assert!(!request.contains_key("collection"));
+    assert_eq!(request["attempts"], "1");
+}
+
+/// Rejects every request with an error that contains its API key, both as is and in the query
+/// string of its URL.
+struct RejectWithKey;
+
+#[async_trait::async_trait]
+impl Middleware for RejectWithKey {
+    async fn handle(
+        &self,
+        mut request: reqwest::Request,
+        _: &mut Extensions,
+        _: Next<'_>,
+    ) -> Result<reqwest::Response> {
+        let key = request.headers()["x-typesense-api-key"]
+            .to_str()
+            .unwrap()
+            .to_owned();
+        request
+            .url_mut()
+            .query_pairs_mut()
+            .append_pair("X-TYPESENSE-API-KEY", &key);
+        Err(Error::Middleware(anyhow::anyhow!(
+            "rejected {} sent with the key {key}",
+            request.url()
+        )))
+    }
+}
+
+#[tokio::test]
+async fn test_spans_redact_scoped_and_header_keys() {
+    let server = MockServer::start().await;
+    let client = Client::builder()
+        .nodes(vec![server.uri()])
+        .api_key("secret-key")
+        .healthcheck_interval(Duration::from_secs(60))
+        .middleware(RejectWithKey)
+        .build()
+        .expect("Failed to create client");
+    let params = ScopedKeyParameters {
+        search_params: Some(SearchParameters {
+            filter_by: Some("company_id:124".into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let scoped_key = client
+        .keys()
+        .generate_scoped_search_key("parent-key", &params)
+        .unwrap();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "x-typesense-api-key",
+        HeaderValue::from_static("header-key"),
+    );
+
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+    let books = client.collection_schemaless("books");
+    let scoped = books.with_options(RequestOptions::builder().api_key(&scoped_key).build());
+    assert!(scoped.retrieve().await.is_err());
+    let books = client.collection_schemaless("books");
+    let with_header = books.with_options(RequestOptions::builder().headers(headers).build());
+    assert!(with_header.retrieve().await.is_err());
+
+    let attempts = recorder.spans_named("typesense.attempt");
+    assert_eq!(attempts.len(), 2);
+    for (_, attempt, _) in &attempts {
+        assert!(attempt["error"].starts_with("error in reqwest-middleware: rejected"));
+        assert!(
+            attempt["error"]
+                .contains("?X-TYPESENSE-API-KEY=[REDACTED] sent with the key [REDACTED]"),
+            "{}",
+            attempt["error"]
+        );
+    }
+    let recorded = format!("{:?}", recorder.spans.lock().unwrap());
+    for key in [scoped_key.as_str(), "header-key", "secret-key"] {
+        assert!(!recorded.contains(key), "{recorded}");
+    }
+}
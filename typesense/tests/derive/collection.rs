@@ -383,3 +383,109 @@ fn derived_document_handles_boolean_shorthand() {
 
     assert_eq!(serde_json::to_value(schema).unwrap(), expected);
 }
+
+// Test 6: Typed Field Handles
+
+fn sortable_name(field: impl typesense::field::SortableField) -> &'static str {
+    field.name()
+}
+
+fn facetable_name(field: impl typesense::field::FacetableField) -> &'static str {
+    field.name()
+}
+
+fn indexed_name(field: impl typesense::field::IndexedField) -> &'static str {
+    field.name()
+}
+
+#[test]
+fn derived_document_generates_field_handles() {
+    use typesense::field::{FacetBy, FieldList, QueryBy};
+
+    // Names follow `rename` and strip raw identifier prefixes
+    assert_eq!(KitchenSinkProductFields::name.name(), "product_name");
+    assert_eq!(KitchenSinkProductFields::price.name(), "renamed_price");
+    assert_eq!(RawIdentDocFields::r#type.name(), "type");
+
+    // Capabilities follow the field attributes and Typesense defaults
+    assert_eq!(facetable_name(ShorthandProductFields::brand), "brand");
+    assert_eq!(sortable_name(ShorthandProductFields::name), "name");
+    assert_eq!(sortable_name(ShorthandProductFields::price), "price");
+    assert_eq!(sortable_name(RawIdentDocFields::r#type), "type");
+    assert_eq!(sortable_name(CompanyFields::num_employees), "num_employees");
+    assert_eq!(indexed_name(ShorthandProductFields::category), "category");
+
+    let query_by = QueryBy::new()
+        .field(ShorthandProductFields::title)
+        .field(ShorthandProductFields::tags);
+    assert_eq!(query_by.to_string(), "title,tags");
+
+    let facet_by = FacetBy::new().field(ShorthandProductFields::brand);
+    assert_eq!(String::from(facet_by), "brand");
+
+    // Non-indexed fields can still be included in the response
+    let include_fields = FieldList::new()
+        .field(KitchenSinkProductFields::internal_id)
+        .field(KitchenSinkProductFields::name);
+    assert_eq!(include_fields.to_string(), "internal_id,product_name");
+}
+
+#[test]
+fn derived_field_handles_build_sort_by() {
+    use typesense::models::SortBy;
+
+    let sort_by = SortBy::new()
+        .desc(ShorthandProductFields::price)
+        .asc(ShorthandProductFields::name);
+    assert_eq!(sort_by.to_string(), "price:desc,name:asc");
+}
+
+#[test]
+fn derived_field_handles_follow_schema_rules() {
+    use typesense::field::{FieldHandle, No};
+
+    // Fields that are not indexed can be neither faceted nor sorted on
+    let _: FieldHandle<KitchenSinkProductFields, No, No, No> =
+        KitchenSinkProductFields::internal_id;
+    // Geopoints are sortable by default
+    assert_eq!(
+        sortable_name(KitchenSinkProductFields::location),
+        "location"
+    );
+}
+
+#[test]
+fn derived_field_handles_reach_flattened_sub_fields() {
+    use typesense::field::{FacetBy, FieldHandle, No, Yes};
+
+    // Sub-fields keep the capabilities of the flattened struct
+    assert_eq!(facetable_name(UserFields::profile.name()), "profile.name");
+    assert_eq!(sortable_name(UserFields::profile.name()), "profile.name");
+    assert_eq!(indexed_name(UserFields::profile.object()), "profile");
+
+    // `flatten, skip` only indexes the sub-fields
+    assert_eq!(
+        indexed_name(UserFields::sub_fields_only.email()),
+        "sub_fields_only.email"
+    );
+    let _: FieldHandle<UserFields, No> = UserFields::sub_fields_only.object();
+
+    // Flattening nests, and sub-fields of a list of objects are arrays, which are not sortable
+    assert_eq!(
+        indexed_name(UserFields::nested_struct.address().primary_city()),
+        "nested_struct.address.primary_city"
+    );
+    let _: FieldHandle<UserFields, Yes, No, No> = UserFields::previous_addresses.number();
+    let _: FieldHandle<UserFields, Yes> = UserFields::nested_struct_vec.address().object();
+    assert_eq!(
+        indexed_name(UserFields::nested_struct_vec.address().work_zips()),
+        "nested_struct_vec.address.work_zips"
+    );
+
+    // Nested names are joined at compile time
+    const PRIMARY_CITY: &str = UserFields::nested_struct.address().primary_city().name();
+    assert_eq!(PRIMARY_CITY, "nested_struct.address.primary_city");
+
+    let facet_by = FacetBy::new().field(UserFields::profile.name());
+    assert_eq!(facet_by.to_string(), "profile.name");
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use typesense::{Typesense, field::FacetBy};
+
+#[derive(Typesense, Serialize, Deserialize)]
+struct Company {
+    company_name: String,
+    #[typesense(sort)]
+    country: String,
+}
+
+fn main() {
+    let _ = FacetBy::new().field(CompanyFields::country);
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use typesense::{Typesense, field::QueryBy};
+
+#[derive(Typesense, Serialize, Deserialize)]
+struct Company {
+    company_name: String,
+    #[typesense(index = false)]
+    internal_notes: String,
+}
+
+fn main() {
+    let _ = QueryBy::new().field(CompanyFields::internal_notes);
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use typesense::{Typesense, field::QueryBy};
+
+#[derive(Typesense, Serialize, Deserialize)]
+struct Company {
+    company_name: String,
+    num_employees: i32,
+}
+
+fn main() {
+    let _ = QueryBy::new().field(CompanyFields::num_employees);
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use typesense::{Typesense, models::SortBy};
+
+#[derive(Typesense, Serialize, Deserialize)]
+struct Company {
+    company_name: String,
+    num_employees: i32,
+}
+
+fn main() {
+    let _ = SortBy::new()
+        .desc(CompanyFields::num_employees)
+        .asc(CompanyFields::company_name);
+}
@@ -9,10 +9,6 @@
  */
 // This file was added to .openapi-generator-ignore so we can freely modify it.
 
-#[cfg(target_arch = "wasm32")]
-pub type HttpClient = reqwest::Client;
-
-#[cfg(not(target_arch = "wasm32"))]
 pub type HttpClient = reqwest_middleware::ClientWithMiddleware;
 
 #[derive(Debug, Clone)]
@@ -44,10 +40,6 @@ impl Configuration {
 impl Default for Configuration {
     #[inline]
     fn default() -> Self {
-        #[cfg(target_arch = "wasm32")]
-        let client = reqwest::Client::new();
-
-        #[cfg(not(target_arch = "wasm32"))]
         let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
         Configuration {
             base_path: "http://localhost".to_owned(),
@@ -21,10 +21,11 @@ pub struct TakeSnapshotParams<'p> {
     pub snapshot_path: Cow<'p, str>,
 }
 
-/// struct for passing parameters to the method [`toggle_slow_request_log`]
+/// struct for passing parameters to the method [`update_config`]
 #[derive(Clone, Debug)]
-pub struct ToggleSlowRequestLogParams {
-    pub toggle_slow_request_log_request: Option<models::ToggleSlowRequestLogRequest>,
+pub struct UpdateConfigParams<B> {
+    /// The runtime settings to change
+    pub body: B,
 }
 
 /// struct for typed errors of method [`clear_cache`]
@@ -69,10 +70,10 @@ pub enum TakeSnapshotError {
     UnknownValue(serde_json::Value),
 }
 
-/// struct for typed errors of method [`toggle_slow_request_log`]
+/// struct for typed errors of method [`update_config`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum ToggleSlowRequestLogError {
+pub enum UpdateConfigError {
     UnknownValue(serde_json::Value),
 }
 
@@ -427,11 +428,11 @@ pub async fn take_snapshot(
     }
 }
 
-/// Enable logging of requests that take over a defined threshold of time. Default is `-1` which disables slow request logging. Slow requests are logged to the primary log file, with the prefix SLOW REQUEST.
-pub async fn toggle_slow_request_log(
+/// Update the settings of a Typesense node that can be changed at runtime, such as `log-slow-requests-time-ms`, `log-slow-searches-time-ms`, `enable-search-logging`, `cache-num-entries`, `healthy-read-lag`, `healthy-write-lag` and `skip-writes`. Only the settings in the request body are changed. Setting `log-slow-requests-time-ms` enables logging of requests that take over a defined threshold of time. Default is `-1` which disables slow request logging. Slow requests are logged to the primary log file, with the prefix SLOW REQUEST.
+pub async fn update_config<B: Serialize>(
     configuration: &configuration::Configuration,
-    params: &ToggleSlowRequestLogParams,
-) -> Result<models::SuccessStatus, Error<ToggleSlowRequestLogError>> {
+    params: &UpdateConfigParams<B>,
+) -> Result<models::SuccessStatus, Error<UpdateConfigError>> {
     let uri_str = format!("{}/config", configuration.base_path);
     let mut req_builder = configuration
         .client
@@ -448,7 +449,7 @@ pub async fn toggle_slow_request_log(
         };
         req_builder = req_builder.header("X-TYPESENSE-API-KEY", value);
     };
-    req_builder = req_builder.json(&params.toggle_slow_request_log_request);
+    req_builder = req_builder.json(&params.body);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -478,7 +479,7 @@ pub async fn toggle_slow_request_log(
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<ToggleSlowRequestLogError> = serde_json::from_str(&content).ok();
+        let entity: Option<UpdateConfigError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
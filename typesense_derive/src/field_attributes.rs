@@ -330,9 +330,7 @@ pub(crate) fn process_field(
         };
 
         let inner_type = get_inner_type(&field.ty);
-        let is_vec = ty_inner_type(&field.ty, "Vec").is_some()
-            || ty_inner_type(&field.ty, "Option")
-                .is_some_and(|t| ty_inner_type(t, "Vec").is_some());
+        let is_vec = is_vec_field(field);
 
         let flattened_fields = quote! {
             <#inner_type as ::typesense::prelude::Document>::collection_schema().fields
@@ -367,3 +365,225 @@ pub(crate) fn process_field(
         Ok((Some(build_regular_field(field, &field_attrs)), None))
     }
 }
+
+/// Whether Typesense makes a field of this type sortable when `sort` is not set.
+/// Only numeric and geopoint fields are sortable by default.
+fn is_sortable_by_default(field: &Field, field_attrs: &FieldAttributes) -> bool {
+    if let Some(override_str) = &field_attrs.type_override {
+        return matches!(
+            override_str.as_str(),
+            "int32" | "int64" | "float" | "geopoint"
+        );
+    }
+    let ty = ty_inner_type(&field.ty, "Option").unwrap_or(&field.ty);
+    match ty {
+        syn::Type::Path(p) => p.path.get_ident().is_some_and(|i| {
+            matches!(
+                i.to_string().as_str(),
+                "i8" | "u8"
+                    | "i16"
+                    | "u16"
+                    | "i32"
+                    | "u32"
+                    | "i64"
+                    | "u64"
+                    | "isize"
+                    | "usize"
+                    | "f32"
+                    | "f64"
+            )
+        }),
+        _ => false,
+    }
+}
+
+/// Whether a field is a `string` or `string[]` field, the only ones `query_by` can search.
+fn is_text_field(field: &Field, field_attrs: &FieldAttributes) -> bool {
+    if let Some(override_str) = &field_attrs.type_override {
+        return matches!(override_str.as_str(), "string" | "string[]" | "string*");
+    }
+    match get_inner_type(&field.ty) {
+        syn::Type::Path(p) => p.path.is_ident("String"),
+        syn::Type::Reference(r) => {
+            matches!(&*r.elem, syn::Type::Path(p) if p.path.is_ident("str"))
+        }
+        _ => false,
+    }
+}
+
+fn capability(enabled: bool) -> proc_macro2::TokenStream {
+    if enabled {
+        quote!(::typesense::field::Yes)
+    } else {
+        quote!(::typesense::field::No)
+    }
+}
+
+/// The schema name of a field, after `rename`.
+fn field_name(field: &Field, field_attrs: &FieldAttributes) -> String {
+    field_attrs
+        .rename
+        .clone()
+        .unwrap_or_else(|| strip_raw_prefix(&field.ident.as_ref().unwrap().to_string()))
+}
+
+/// The `index`, `facet`, `sort` and text capabilities of a field.
+/// Fields that are not indexed can be neither faceted nor sorted on.
+fn field_capabilities(
+    field: &Field,
+    field_attrs: &FieldAttributes,
+) -> (
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+) {
+    let index = field_attrs.index.unwrap_or(true);
+    let facet = index && field_attrs.facet.unwrap_or(false);
+    let sort = index
+        && field_attrs
+            .sort
+            .unwrap_or_else(|| is_sortable_by_default(field, field_attrs));
+    let text = is_text_field(field, field_attrs);
+    (
+        capability(index),
+        capability(facet),
+        capability(sort),
+        capability(text),
+    )
+}
+
+/// The `{Name}NestedFields` type generated for the type of a flattened field,
+/// with the given generic arguments.
+fn nested_fields_type(
+    field: &Field,
+    args: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let inner_type = get_inner_type(&field.ty);
+    let syn::Type::Path(type_path) = inner_type else {
+        return Err(syn::Error::new_spanned(
+            inner_type,
+            "flattened fields must be of a struct type deriving `Typesense`",
+        ));
+    };
+    let mut path = type_path.path.clone();
+    let last = path.segments.last_mut().unwrap();
+    last.ident = syn::Ident::new(&format!("{}NestedFields", last.ident), last.ident.span());
+    last.arguments = syn::PathArguments::None;
+    Ok(quote!(#path<#args>))
+}
+
+/// Whether a flattened field holds a list of objects, turning its sub-fields into arrays.
+fn is_vec_field(field: &Field) -> bool {
+    ty_inner_type(&field.ty, "Vec").is_some()
+        || ty_inner_type(&field.ty, "Option").is_some_and(|t| ty_inner_type(t, "Vec").is_some())
+}
+
+/// Builds the associated constant holding the typed handle of the `position`-th field of the
+/// struct, with the implementation of `NamedField` giving its name to the handles of its
+/// sub-fields, or `None` if the field is not part of the schema.
+///
+/// Flattened fields get the `{Name}NestedFields` of their type instead, which holds the
+/// handles of the sub-fields.
+pub(crate) fn build_field_handle(
+    field: &Field,
+    position: usize,
+    vis: &syn::Visibility,
+    fields_ident: &syn::Ident,
+) -> syn::Result<Option<(proc_macro2::TokenStream, proc_macro2::TokenStream)>> {
+    let field_attrs = extract_field_attrs(field)?;
+    let ident = field.ident.as_ref().unwrap();
+    let field_name = field_name(field, &field_attrs);
+    let (index, facet, sort, text) = field_capabilities(field, &field_attrs);
+    let named_field = quote! {
+        impl ::typesense::field::NamedField<#position> for #fields_ident {
+            const NAME: &'static str = #field_name;
+        }
+    };
+
+    if field_attrs.flatten {
+        let index = capability(!field_attrs.skip && field_attrs.index.unwrap_or(true));
+        let array = capability(is_vec_field(field));
+        let path = quote!(::typesense::field::FieldAt<#fields_ident, #position>);
+        let nested = nested_fields_type(field, quote!(#fields_ident, #path, #index, #array))?;
+        return Ok(Some((
+            quote! {
+                #vis const #ident: #nested = <#nested>::new();
+            },
+            named_field,
+        )));
+    }
+    if field_attrs.skip {
+        return Ok(None);
+    }
+
+    Ok(Some((
+        quote! {
+            #vis const #ident: ::typesense::field::FieldHandle<
+                #fields_ident,
+                #index,
+                #facet,
+                #sort,
+                #text,
+            > = ::typesense::field::FieldHandle::new(#field_name);
+        },
+        named_field,
+    )))
+}
+
+/// Builds the method of `{Name}NestedFields` returning the typed handle of the `position`-th
+/// field of the struct, or `None` if the field is not part of the schema.
+///
+/// The handle is named `{Path}.{field}`, which is joined at compile time, and belongs to the
+/// collection `P` the object is flattened into. Sub-fields of a list of objects are arrays,
+/// which cannot be sorted on.
+pub(crate) fn build_nested_field_handle(
+    field: &Field,
+    position: usize,
+    vis: &syn::Visibility,
+    fields_ident: &syn::Ident,
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let field_attrs = extract_field_attrs(field)?;
+    let ident = field.ident.as_ref().unwrap();
+    let (index, facet, sort, text) = field_capabilities(field, &field_attrs);
+    let path = quote! {
+        ::typesense::field::Nested<Path, ::typesense::field::FieldAt<#fields_ident, #position>>
+    };
+
+    if field_attrs.flatten {
+        let index = capability(!field_attrs.skip && field_attrs.index.unwrap_or(true));
+        let array = if is_vec_field(field) {
+            quote!(::typesense::field::Yes)
+        } else {
+            quote!(Array)
+        };
+        let nested = nested_fields_type(field, quote!(P, #path, #index, #array))?;
+        return Ok(Some(quote! {
+            #vis const fn #ident(&self) -> #nested {
+                <#nested>::new()
+            }
+        }));
+    }
+    if field_attrs.skip {
+        return Ok(None);
+    }
+
+    Ok(Some(quote! {
+        #vis const fn #ident(
+            &self,
+        ) -> ::typesense::field::FieldHandle<
+            P,
+            #index,
+            #facet,
+            <#sort as ::typesense::field::SortInArray<Array>>::Output,
+            #text,
+        >
+        where
+            #sort: ::typesense::field::SortInArray<Array>,
+        {
+            ::typesense::field::FieldHandle::new(
+                <#path as ::typesense::field::FieldPath>::PATH,
+            )
+        }
+    }))
+}
@@ -1,7 +1,9 @@
 mod field_attributes;
 mod helpers;
 
-use field_attributes::{extract_field_attrs, process_field};
+use field_attributes::{
+    build_field_handle, build_nested_field_handle, extract_field_attrs, process_field,
+};
 use helpers::*;
 
 use proc_macro::TokenStream;
@@ -137,6 +139,25 @@ fn impl_typesense_collection(item: ItemStruct) -> syn::Result<TokenStream> {
 
     let name_partial = Ident::new(&(ident.to_string() + "Partial"), ident.span());
 
+    // Create Fields struct holding a typed handle for every schema field
+    let name_fields = Ident::new(&(ident.to_string() + "Fields"), ident.span());
+    let (field_handles, named_fields): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .enumerate()
+        .map(|(position, field)| build_field_handle(field, position, &vis, &name_fields))
+        .collect::<syn::Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .unzip();
+
+    // Create NestedFields struct holding the handles of the sub-fields when flattened
+    let name_nested_fields = Ident::new(&(ident.to_string() + "NestedFields"), ident.span());
+    let nested_field_handles = fields
+        .iter()
+        .enumerate()
+        .map(|(position, field)| build_nested_field_handle(field, position, &vis, &name_fields))
+        .collect::<syn::Result<Vec<_>>>()?;
+
     let generated_code = quote! {
         #[derive(Default, ::serde::Serialize)]
         #vis struct #name_partial {
@@ -144,6 +165,70 @@ fn impl_typesense_collection(item: ItemStruct) -> syn::Result<TokenStream> {
         }
         impl ::typesense::prelude::DocumentPartial for #name_partial {}
 
+        #[derive(Clone, Copy, Debug)]
+        #vis struct #name_fields;
+
+        #[allow(non_upper_case_globals)]
+        impl #name_fields {
+            #(#field_handles)*
+        }
+
+        #(#named_fields)*
+
+        /// The typed handles of the sub-fields of a flattened object of this type.
+        ///
+        /// `P` is the `{struct_name}Fields` type of the collection the object is flattened
+        /// into, `Path` the path of the object field, `Index` whether the object field itself
+        /// is indexed and `Array` whether the object is part of a list.
+        #vis struct #name_nested_fields<
+            P,
+            Path,
+            Index = ::typesense::field::No,
+            Array = ::typesense::field::No,
+        > {
+            _parent: ::std::marker::PhantomData<fn() -> (P, Path, Index, Array)>,
+        }
+
+        impl<P, Path, Index, Array> ::std::clone::Clone
+            for #name_nested_fields<P, Path, Index, Array>
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<P, Path, Index, Array> ::std::marker::Copy
+            for #name_nested_fields<P, Path, Index, Array>
+        {
+        }
+
+        impl<P, Path: ::typesense::field::FieldPath, Index, Array> ::std::fmt::Debug
+            for #name_nested_fields<P, Path, Index, Array>
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_tuple(stringify!(#name_nested_fields)).field(&Path::PATH).finish()
+            }
+        }
+
+        impl<P, Path: ::typesense::field::FieldPath, Index, Array>
+            #name_nested_fields<P, Path, Index, Array>
+        {
+            /// Creates the handles of the sub-fields of the object field at `Path`.
+            #[doc(hidden)]
+            pub const fn new() -> Self {
+                Self {
+                    _parent: ::std::marker::PhantomData,
+                }
+            }
+
+            /// The handle of the object field itself.
+            #vis const fn object(&self) -> ::typesense::field::FieldHandle<P, Index> {
+                ::typesense::field::FieldHandle::new(Path::PATH)
+            }
+
+            #(#nested_field_handles)*
+        }
+
         impl #impl_generics ::typesense::prelude::Document for #ident #ty_generics #where_clause {
             const COLLECTION_NAME: &str = #collection_name;
 
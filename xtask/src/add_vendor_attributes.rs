@@ -84,5 +84,12 @@ pub fn add_vendor_attributes(doc: &mut OpenAPI) -> Result<(), String> {
         .params_generic_parameter("B")?
         .request_type("B")?;
 
+    // The /config endpoint accepts any subset of the runtime settings
+    attrs
+        .operation("/config", "post")
+        .generic_parameter("B: Serialize")?
+        .params_generic_parameter("B")?
+        .request_type("B")?;
+
     Ok(())
 }